@@ -7,9 +7,9 @@ The schema is generated from `pydantic_core/_types.py`.
 import importlib.util
 import re
 from collections.abc import Callable
-from datetime import date, datetime, time, timedelta
+from datetime import date, datetime, time, timedelta, tzinfo
 from pathlib import Path
-from typing import Any, Dict, ForwardRef, List, Type, Union
+from typing import Any, Dict, ForwardRef, List, Tuple, Type, Union
 
 from black import Mode, TargetVersion, format_file_contents
 from typing_extensions import get_args, is_typeddict
@@ -43,6 +43,9 @@ def get_schema(obj):
         return type_dict_schema(obj)
     elif obj == Any:
         return 'any'
+    elif obj == tzinfo:
+        # the generated schema can't reference classes, the type is checked when the validator is built
+        return 'any'
     elif obj == type:
         # todo
         return 'any'
@@ -65,6 +68,9 @@ def get_schema(obj):
             'keys_schema': get_schema(obj.__args__[0]),
             'values_schema': get_schema(obj.__args__[1]),
         }
+    elif issubclass(origin, Tuple):
+        assert obj.__args__[-1] is Ellipsis, f'only variable length tuples are supported, obj={obj}'
+        return {'type': 'tuple', 'items_schema': get_schema(obj.__args__[0])}
     elif issubclass(origin, Type):
         # can't really use 'is-instance' since this is used for the class_ parameter of
         # 'is-instance' validators
@@ -1,4 +1,34 @@
-from ._pydantic_core import PydanticValueError, SchemaError, SchemaValidator, ValidationError, __version__
+from ._pydantic_core import (
+    JsonLinesIterator,
+    PydanticValueError,
+    SchemaError,
+    SchemaValidator,
+    SecretBytes,
+    SecretStr,
+    ValidationError,
+    ValidationInfo,
+    ValidationResult,
+    ValidatorIterator,
+    __version__,
+    partial_schema,
+    walk_schema,
+)
 from ._types import Config, Schema
 
-__all__ = '__version__', 'Config', 'Schema', 'SchemaValidator', 'SchemaError', 'ValidationError', 'PydanticValueError'
+__all__ = (
+    '__version__',
+    'Config',
+    'Schema',
+    'SchemaValidator',
+    'SchemaError',
+    'ValidationError',
+    'ValidationResult',
+    'ValidatorIterator',
+    'JsonLinesIterator',
+    'ValidationInfo',
+    'PydanticValueError',
+    'SecretStr',
+    'SecretBytes',
+    'walk_schema',
+    'partial_schema',
+)
@@ -1,5 +1,7 @@
+import asyncio
+import os
 import sys
-from typing import Any, TypedDict
+from typing import IO, Any, Callable, Iterable, Literal, TypedDict
 
 from pydantic_core._types import Config, Schema
 
@@ -8,25 +10,116 @@ if sys.version_info < (3, 11):
 else:
     from typing import NotRequired
 
-__all__ = '__version__', 'SchemaValidator', 'SchemaError', 'ValidationError', 'PydanticValueError'
+__all__ = (
+    '__version__',
+    'SchemaValidator',
+    'SchemaError',
+    'ValidationError',
+    'ValidationResult',
+    'ValidatorIterator',
+    'JsonLinesIterator',
+    'ValidationInfo',
+    'PydanticValueError',
+    'SecretStr',
+    'SecretBytes',
+    'walk_schema',
+    'partial_schema',
+)
 __version__: str
 
 class SchemaValidator:
+    on_validate_start: 'Callable[..., Any] | None'
+    on_validate_end: 'Callable[..., Any] | None'
     def __init__(self, schema: Schema, config: 'Config | None' = None) -> None: ...
-    def validate_python(self, input: Any, strict: 'bool | None' = None, context: Any = None) -> Any: ...
+    @staticmethod
+    def from_json(schema_json: 'str | bytes | bytearray', config: 'Config | None' = None) -> 'SchemaValidator': ...
+    def validate_python(
+        self,
+        input: Any,
+        strict: 'bool | None' = None,
+        context: Any = None,
+        timeout: 'float | None' = None,
+        allow_partial: 'bool | None' = None,
+        fail_fast: 'bool | None' = None,
+        max_errors: 'int | None' = None,
+    ) -> Any: ...
+    def validate_python_async(
+        self,
+        input: Any,
+        strict: 'bool | None' = None,
+        context: Any = None,
+        event_loop: 'asyncio.AbstractEventLoop | None' = None,
+    ) -> 'asyncio.Future[Any]': ...
+    def validate_python_result(
+        self, input: Any, strict: 'bool | None' = None, context: Any = None
+    ) -> 'ValidationResult': ...
+    def validate_python_with_coercions(
+        self, input: Any, strict: 'bool | None' = None, context: Any = None
+    ) -> 'tuple[Any, list[CoercionDetails]]': ...
+    def validate_python_salvage(
+        self, input: Any, strict: 'bool | None' = None, context: Any = None
+    ) -> 'tuple[Any, list[ErrorDetails]]': ...
+    def validate_many(
+        self,
+        inputs: Iterable[Any],
+        strict: 'bool | None' = None,
+        context: Any = None,
+        json: bool = False,
+        parallel_parse: bool = False,
+    ) -> 'list[ValidationResult]': ...
     def isinstance_python(self, input: Any, strict: 'bool | None' = None, context: Any = None) -> bool: ...
     def validate_json(
-        self, input: 'str | bytes | bytearray', strict: 'bool | None' = None, context: Any = None
+        self,
+        input: 'str | bytes | bytearray | memoryview',
+        strict: 'bool | None' = None,
+        context: Any = None,
+        timeout: 'float | None' = None,
+        allow_partial: 'bool | None' = None,
+        fail_fast: 'bool | None' = None,
+        max_errors: 'int | None' = None,
+    ) -> Any: ...
+    def validate_json_stream(
+        self,
+        input: 'str | os.PathLike[str] | IO[Any]',
+        strict: 'bool | None' = None,
+        context: Any = None,
+        timeout: 'float | None' = None,
+        allow_partial: 'bool | None' = None,
+        fail_fast: 'bool | None' = None,
+        max_errors: 'int | None' = None,
     ) -> Any: ...
+    def iter_json_lines(
+        self, input: 'str | bytes | IO[Any]', strict: 'bool | None' = None, context: Any = None
+    ) -> 'JsonLinesIterator': ...
+    def validate_strings(self, input: Any, context: Any = None) -> Any: ...
     def isinstance_json(
-        self, input: 'str | bytes | bytearray', strict: 'bool | None' = None, context: Any = None
+        self, input: 'str | bytes | bytearray | memoryview', strict: 'bool | None' = None, context: Any = None
     ) -> bool: ...
     def validate_assignment(
-        self, field: str, input: Any, data: 'dict[str, Any]', strict: 'bool | None' = None, context: Any = None
-    ) -> 'dict[str, Any]': ...
+        self, obj: Any, field: str, input: Any, strict: 'bool | None' = None, context: Any = None
+    ) -> Any: ...
+    def construct(self, data: 'dict[str, Any]', fields_set: 'set[str] | None' = None) -> Any: ...
+    def get_default_value(
+        self, field_path: 'str | tuple[str, ...] | list[str]', validate: bool = False, context: Any = None
+    ) -> 'tuple[Any] | None': ...
+    def replace_field(self, field_path: 'str | tuple[str, ...] | list[str]', schema: Schema) -> None: ...
+    def generate_example(self, seed: 'int | None' = None) -> Any: ...
+    def dump_schema(self, format: "Literal['python', 'json']" = 'python', inline_refs: bool = False) -> Any: ...
+    def resolved_schema(self) -> 'dict[str, Any]': ...
+    def field_metadata(self) -> 'dict[tuple[str, ...], dict[str, Any]]': ...
+    def warmup(self) -> None: ...
+    def schema_hash(self) -> int: ...
+    def __hash__(self) -> int: ...
+    def __eq__(self, other: object) -> bool: ...
 
 class SchemaError(Exception):
-    pass
+    schema_path: 'list[str | int]'
+
+class CoercionDetails(TypedDict):
+    loc: 'list[int | str]'
+    from_type: str
+    to_type: str
+    lossy: 'bool | None'
 
 class ErrorDetails(TypedDict):
     kind: str
@@ -35,11 +128,50 @@ class ErrorDetails(TypedDict):
     input_value: Any
     context: NotRequired['dict[str, Any]']
 
+class StructuredError(TypedDict):
+    type: str
+    loc: 'tuple[int | str, ...]'
+    msg: str
+    input: Any
+    ctx: 'dict[str, Any]'
+
 class ValidationError(ValueError):
     title: str
 
     def error_count(self) -> int: ...
+    def omitted_error_count(self) -> int: ...
     def errors(self) -> 'list[ErrorDetails]': ...
+    def structured_errors(self) -> 'list[StructuredError]': ...
+
+class ValidationResult:
+    ok: bool
+    value: Any
+    errors: 'list[ErrorDetails]'
+    title: str
+
+    def error_count(self) -> int: ...
+    def omitted_error_count(self) -> int: ...
+    def structured_errors(self) -> 'list[StructuredError]': ...
+
+class ValidatorIterator:
+    index: int
+
+    def __iter__(self) -> 'ValidatorIterator': ...
+    def __next__(self) -> Any: ...
+
+class JsonLinesIterator:
+    line_number: int
+
+    def __iter__(self) -> 'JsonLinesIterator': ...
+    def __next__(self) -> Any: ...
+
+class ValidationInfo:
+    data: 'dict[str, Any] | None'
+    config: 'Config | None'
+    context: Any
+    loc: 'list[int | str]'
+    field_name: 'str | None'
+    mode: Literal['python', 'json', 'strings']
 
 class PydanticValueError(ValueError):
     kind: str
@@ -48,3 +180,16 @@ class PydanticValueError(ValueError):
 
     def __init__(self, kind: str, message_template: str, context: 'dict[str, str | int] | None' = None) -> None: ...
     def message(self) -> str: ...
+
+class SecretStr:
+    def __init__(self, value: str) -> None: ...
+    def get_secret_value(self) -> str: ...
+    def __len__(self) -> int: ...
+
+class SecretBytes:
+    def __init__(self, value: bytes) -> None: ...
+    def get_secret_value(self) -> bytes: ...
+    def __len__(self) -> int: ...
+
+def walk_schema(schema: Schema, visitor: 'Callable[[dict[str, Any]], Schema | None]') -> Schema: ...
+def partial_schema(schema: Schema) -> Schema: ...
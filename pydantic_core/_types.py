@@ -1,8 +1,8 @@
 from __future__ import annotations
 
 import sys
-from datetime import date, datetime, time, timedelta
-from typing import Any, Callable, Dict, List, Optional, Type, Union
+from datetime import date, datetime, time, timedelta, tzinfo
+from typing import Any, Callable, Dict, List, Optional, Tuple, Type, Union
 
 if sys.version_info < (3, 11):
     from typing_extensions import NotRequired, Required
@@ -21,11 +21,15 @@ class AnySchema(TypedDict):
 
 class BoolSchema(TypedDict, total=False):
     type: Required[Literal['bool']]
+    # strings accepted in lax mode on top of the built-in ones like 'yes' and 'off', case insensitive
+    true_strings: List[str]
+    false_strings: List[str]
     strict: bool
     ref: str
 
 
 class Config(TypedDict, total=False):
+    title: str  # used as the title of validation errors, defaults to the name of the validator
     strict: bool
     # higher priority configs take precedence of over lower, if priority matches the two configs are merged, default 0
     config_choose_priority: int
@@ -34,9 +38,18 @@ class Config(TypedDict, total=False):
     # settings related to typed_dicts only
     typed_dict_extra_behavior: Literal['allow', 'forbid', 'ignore']
     typed_dict_total: bool  # default: True
+    # fields related to bool fields only
+    bool_true_strings: List[str]
+    bool_false_strings: List[str]
+    typed_dict_error_descriptions: bool  # default: False
+    typed_dict_forbid_multiple_values: bool  # default: False
+    typed_dict_nested_delimiter: str
+    typed_dict_attribute_missing_errors: List[Type[BaseException]]
     # used on typed-dicts and tagged union keys
     from_attributes: bool
     revalidate_models: bool
+    # used on new-class, error when building the validator if instances can't be weakly referenced
+    require_weakref: bool  # default: False
     # used on typed-dicts and arguments
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
     # fields related to string fields only
@@ -45,8 +58,46 @@ class Config(TypedDict, total=False):
     str_strip_whitespace: bool
     str_to_lower: bool
     str_to_upper: bool
+    str_regex_engine: Literal['rust-regex', 'python-re', 'auto']  # default: 'rust-regex'
+    # fields related to int fields only
+    int_allow_radix_prefix: bool  # default: False
+    int_from_float: Literal['error', 'truncate', 'round-if-within-epsilon']  # default: 'error'
+    int_from_float_epsilon: float  # default: 1e-9
+    # fields related to float fields only
+    float_percent_mode: Literal['fraction', 'number']
+    # fields related to int and float fields
+    number_thousands_separator: str
+    number_decimal_point: str  # default: '.', only used with number_thousands_separator
+    number_python_syntax: bool
+    # fields related to bytes fields only
+    bytes_encoding: Literal['utf8', 'ascii', 'latin1', 'base64', 'base64url']  # default: 'utf8'
     # fields related to float fields only
     allow_inf_nan: bool  # default: True
+    # fields related to datetime fields only
+    datetime_assume_tz: tzinfo  # timezone for naive datetimes, by default they're left naive
+    # maximum number of errors included in a `ValidationError`, further errors are only counted
+    max_errors: int
+    # called with `title` and `mode` keyword arguments when validation starts,
+    # and with `title`, `mode`, `duration`, `success` and `error` when it ends
+    on_validate_start: Callable[..., Any]
+    on_validate_end: Callable[..., Any]
+
+
+class DequeSchema(TypedDict, total=False):
+    type: Required[Literal['deque']]
+    items_schema: Schema  # default: AnySchema
+    min_items: int
+    max_items: int
+    maxlen: int  # passed to `deque()`, items beyond maxlen are discarded from the start
+    fail_fast: bool
+    strict: bool
+    ref: str
+
+
+class GeneratorSchema(TypedDict, total=False):
+    type: Required[Literal['generator']]
+    items_schema: Schema  # default: AnySchema, items are validated lazily as they're consumed
+    ref: str
 
 
 class DictSchema(TypedDict, total=False):
@@ -55,6 +106,40 @@ class DictSchema(TypedDict, total=False):
     values_schema: Schema  # default: AnySchema
     min_items: int
     max_items: int
+    fail_fast: bool
+    strict: bool
+    ref: str
+
+
+class DefaultDictSchema(TypedDict, total=False):
+    type: Required[Literal['defaultdict']]
+    default_factory: Required[Callable[[], Any]]
+    keys_schema: Schema  # default: AnySchema
+    values_schema: Schema  # default: AnySchema
+    min_items: int
+    max_items: int
+    fail_fast: bool
+    strict: bool
+    ref: str
+
+
+class OrderedDictSchema(TypedDict, total=False):
+    type: Required[Literal['ordered-dict']]
+    keys_schema: Schema  # default: AnySchema
+    values_schema: Schema  # default: AnySchema
+    min_items: int
+    max_items: int
+    fail_fast: bool
+    strict: bool
+    ref: str
+
+
+class CounterSchema(TypedDict, total=False):
+    type: Required[Literal['counter']]
+    keys_schema: Schema  # default: AnySchema, values are always ints
+    min_items: int
+    max_items: int
+    fail_fast: bool
     strict: bool
     ref: str
 
@@ -67,6 +152,46 @@ class FloatSchema(TypedDict, total=False):
     ge: float
     lt: float
     gt: float
+    # whether '85%' is allowed in lax mode, and whether it means 0.85 or 85.0
+    percent_mode: Literal['fraction', 'number']
+    thousands_separator: str  # e.g. ',' to allow '1,234.5' in lax mode
+    decimal_point: str  # default: '.', only used with thousands_separator
+    # True to allow whitespace and underscores like python's `float()`, False to also forbid a leading '+'
+    python_syntax: bool
+    strict: bool
+    ref: str
+
+
+class FractionSchema(TypedDict, total=False):
+    type: Required[Literal['fraction']]
+    # bounds may be an int, float, str or Fraction, they're converted to `Fraction` when building the validator
+    le: Any
+    ge: Any
+    lt: Any
+    gt: Any
+    strict: bool
+    ref: str
+
+
+class UuidSchema(TypedDict, total=False):
+    type: Required[Literal['uuid']]
+    # in lax mode, strings and exactly 16 bytes are accepted, from JSON the bytes may be base64 encoded
+    strict: bool
+    ref: str
+
+
+class ZoneInfoSchema(TypedDict, total=False):
+    type: Required[Literal['zoneinfo']]
+    # in lax mode, IANA timezone names like 'Europe/London' are accepted, requires python 3.9+
+    strict: bool
+    ref: str
+
+
+class DecimalSchema(TypedDict, total=False):
+    type: Required[Literal['decimal']]
+    # how floats are converted: 'str' uses `Decimal(str(value))`, 'exact' uses `Decimal(value)` which gives the
+    # full binary expansion, 'reject' doesn't allow floats. default: 'str'
+    float_policy: Literal['reject', 'str', 'exact']
     strict: bool
     ref: str
 
@@ -93,6 +218,21 @@ class IntSchema(TypedDict, total=False):
     ge: int
     lt: int
     gt: int
+    allow_radix_prefix: bool  # whether '0x1A', '0o17' and '0b1010' are allowed in lax mode, default: False
+    thousands_separator: str  # e.g. ',' to allow '1,234' in lax mode
+    decimal_point: str  # default: '.', only used with thousands_separator
+    # True to allow whitespace and underscores like python's `int()`, False to also forbid a leading '+'
+    python_syntax: bool
+    # how floats with a fractional part are handled in lax mode, default: 'error'
+    from_float: Literal['error', 'truncate', 'round-if-within-epsilon']
+    from_float_epsilon: float  # default: 1e-9, only used with 'round-if-within-epsilon'
+    strict: bool
+    ref: str
+
+
+class IntFlagSchema(TypedDict, total=False):
+    type: Required[Literal['int-flag']]
+    flag_type: Required[type]  # a subclass of enum.IntFlag
     strict: bool
     ref: str
 
@@ -102,6 +242,9 @@ class ListSchema(TypedDict, total=False):
     items_schema: Schema  # default: AnySchema
     min_items: int
     max_items: int
+    str_delimiter: str  # split str inputs into items in lax mode, e.g. ',' or ' ' for any whitespace
+    unique_items: bool  # compared by python hashing and equality
+    fail_fast: bool  # stop at the first invalid item
     strict: bool
     ref: str
 
@@ -112,6 +255,13 @@ class LiteralSchema(TypedDict):
     ref: NotRequired[str]
 
 
+class LocPrefixSchema(TypedDict):
+    type: Literal['loc-prefix']
+    loc: List[Union[str, int]]  # added to the start of the location of all errors from `schema`
+    schema: Schema
+    ref: NotRequired[str]
+
+
 class NewClassSchema(TypedDict):
     type: Literal['new-class']
     class_type: type
@@ -121,14 +271,31 @@ class NewClassSchema(TypedDict):
     config: NotRequired[Config]
 
 
+class DataclassSchema(TypedDict, total=False):
+    type: Required[Literal['dataclass']]
+    dataclass_type: Required[type]
+    # schemas for the dataclass's fields (including `InitVar`s), other fields are validated as `any`
+    fields: Dict[str, Schema]
+    revalidate_instances: bool  # default: False
+    strict: bool
+    ref: str
+
+
+class AliasRegex(TypedDict):
+    # applied to a string value in an alias path, the first capture group (or the whole match) is used
+    regex: str
+
+
 class TypedDictField(TypedDict, total=False):
     schema: Required[Schema]
     required: bool
     default: Any
     default_factory: Callable[[], Any]
     on_error: Literal['raise', 'omit', 'fallback_on_default']  # default: 'raise'
-    alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
+    alias: Union[str, List[Union[str, int, AliasRegex]], List[List[Union[str, int, AliasRegex]]]]
     frozen: bool
+    description: str  # appended to error messages for the field if `error_descriptions` is set
+    examples: List[Any]  # not used in validation, available via `SchemaValidator.field_metadata()`
 
 
 class TypedDictSchema(TypedDict, total=False):
@@ -138,11 +305,18 @@ class TypedDictSchema(TypedDict, total=False):
     extra_validator: Schema
     return_fields_set: bool
     ref: str
+    title: str  # used as the validator's name in error titles, union locations and reprs
     # all these values can be set via config, equivalent fields have `typed_dict_` prefix
     extra_behavior: Literal['allow', 'forbid', 'ignore']
     total: bool  # default: True
+    error_descriptions: bool  # default: False
+    forbid_multiple_values: bool  # error if a field is provided via more than one alias, default: False
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
     from_attributes: bool
+    # group flat keys like `DB__HOST` into nested dicts for fields missing from python dicts, e.g. for env vars
+    nested_delimiter: str
+    # with `from_attributes`, attributes raising these are treated as missing, like those raising AttributeError
+    attribute_missing_errors: List[Type[BaseException]]
 
 
 class NoneSchema(TypedDict):
@@ -157,16 +331,41 @@ class NullableSchema(TypedDict, total=False):
     ref: str
 
 
+class WithDefaultSchema(TypedDict, total=False):
+    type: Required[Literal['with-default']]
+    schema: Required[Schema]
+    default: Any
+    default_factory: Callable[[], Any]
+    on_error: Literal['raise', 'default']  # default: 'raise'
+    validate_default: bool  # default: False
+    ref: str
+
+
 class RecursiveReferenceSchema(TypedDict):
     type: Literal['recursive-ref']
     schema_ref: str
 
 
+class DefinitionsSchema(TypedDict):
+    type: Literal['definitions']
+    schema: Schema
+    # each definition must have a `ref`, definitions can reference each other in any order
+    definitions: List[Schema]
+    ref: NotRequired[str]
+
+
+class DefinitionReferenceSchema(TypedDict):
+    type: Literal['definition-ref']
+    schema_ref: str
+
+
 class SetSchema(TypedDict, total=False):
     type: Required[Literal['set']]
     items_schema: Schema  # default: AnySchema
     min_items: int
     max_items: int
+    str_delimiter: str  # split str inputs into items in lax mode, e.g. ',' or ' ' for any whitespace
+    fail_fast: bool
     strict: bool
     ref: str
 
@@ -176,6 +375,8 @@ class FrozenSetSchema(TypedDict, total=False):
     items_schema: Schema  # default: AnySchema
     min_items: int
     max_items: int
+    str_delimiter: str  # split str inputs into items in lax mode, e.g. ',' or ' ' for any whitespace
+    fail_fast: bool
     strict: bool
     ref: str
 
@@ -183,6 +384,20 @@ class FrozenSetSchema(TypedDict, total=False):
 class StringSchema(TypedDict, total=False):
     type: Required[Literal['str']]
     pattern: str
+    regex_engine: Literal['rust-regex', 'python-re', 'auto']  # default: 'rust-regex'
+    max_length: int
+    min_length: int
+    strip_whitespace: bool
+    to_lower: bool
+    to_upper: bool
+    strict: bool
+    ref: str
+
+
+class SecretStrSchema(TypedDict, total=False):
+    type: Required[Literal['secret-str']]
+    pattern: str
+    regex_engine: Literal['rust-regex', 'python-re', 'auto']  # default: 'rust-regex'
     max_length: int
     min_length: int
     strip_whitespace: bool
@@ -192,9 +407,37 @@ class StringSchema(TypedDict, total=False):
     ref: str
 
 
+class SecretBytesSchema(TypedDict, total=False):
+    type: Required[Literal['secret-bytes']]
+    max_length: int
+    min_length: int
+    encoding: Literal['utf8', 'ascii', 'latin1', 'base64', 'base64url']  # default: 'utf8'
+    strict: bool
+    ref: str
+
+
+class PathSchema(TypedDict, total=False):
+    type: Required[Literal['path']]
+    path_type: Literal['path', 'pure-posix', 'pure-windows']  # default: 'path'
+    # filesystem checks, only allowed with path_type 'path'
+    exists: bool
+    is_file: bool
+    is_dir: bool
+    strict: bool
+    ref: str
+
+
+class PatternSchema(TypedDict, total=False):
+    type: Required[Literal['pattern']]
+    pattern_type: Literal['str', 'bytes']  # default: allow both
+    ref: str
+
+
 class UnionSchema(TypedDict, total=False):
     type: Required[Literal['union']]
     choices: Required[List[Schema]]
+    # 'smart' tries all choices in strict mode before coercing, default: 'smart'
+    mode: Literal['smart', 'left_to_right']
     strict: bool
     ref: str
 
@@ -202,7 +445,9 @@ class UnionSchema(TypedDict, total=False):
 class TaggedUnionSchema(TypedDict):
     type: Literal['tagged-union']
     choices: Dict[str, Schema]
-    discriminator: Union[str, List[Union[str, int]], List[List[Union[str, int]]], Callable[[Any], Optional[str]]]
+    discriminator: Union[
+        str, List[Union[str, int, AliasRegex]], List[List[Union[str, int, AliasRegex]]], Callable[[Any], Optional[str]]
+    ]
     strict: NotRequired[bool]
     ref: NotRequired[str]
 
@@ -211,6 +456,7 @@ class BytesSchema(TypedDict, total=False):
     type: Required[Literal['bytes']]
     max_length: int
     min_length: int
+    encoding: Literal['utf8', 'ascii', 'latin1', 'base64', 'base64url']  # default: 'utf8'
     strict: bool
     ref: str
 
@@ -242,6 +488,7 @@ class DatetimeSchema(TypedDict, total=False):
     ge: datetime
     lt: datetime
     gt: datetime
+    assume_tz: tzinfo  # timezone for naive datetimes, overrides the `datetime_assume_tz` config
     ref: str
 
 
@@ -260,6 +507,8 @@ class TuplePositionalSchema(TypedDict, total=False):
     mode: Required[Literal['positional']]
     items_schema: Required[List[Schema]]
     extra_schema: Schema
+    unique_items: bool
+    fail_fast: bool
     strict: bool
     ref: str
 
@@ -270,17 +519,36 @@ class TupleVariableSchema(TypedDict, total=False):
     items_schema: Schema
     min_items: int
     max_items: int
+    str_delimiter: str  # split str inputs into items in lax mode, e.g. ',' or ' ' for any whitespace
+    unique_items: bool
+    fail_fast: bool
     strict: bool
     ref: str
 
 
 class IsInstanceSchema(TypedDict):
     type: Literal['is-instance']
+    class_: Union[Type[Any], Tuple[Type[Any], ...]]
+    # called with inputs from JSON to create an instance, by default they're invalid
+    json_function: NotRequired[Callable[[Any], Any]]
+
+
+class JsonSchema(TypedDict, total=False):
+    type: Required[Literal['json']]
+    schema: Schema
+    ref: str
+
+
+class IsSubclassSchema(TypedDict):
+    type: Literal['is-subclass']
     class_: Type[Any]
 
 
-class CallableSchema(TypedDict):
-    type: Literal['callable']
+class CallableSchema(TypedDict, total=False):
+    type: Required[Literal['callable']]
+    reject_classes: bool  # in strict mode, classes aren't accepted even though they're callable, default: False
+    strict: bool
+    ref: str
 
 
 class Parameter(TypedDict, total=False):
@@ -289,7 +557,7 @@ class Parameter(TypedDict, total=False):
     schema: Required[Schema]
     default: Any
     default_factory: Callable[[], Any]
-    alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
+    alias: Union[str, List[Union[str, int, AliasRegex]], List[List[Union[str, int, AliasRegex]]]]
 
 
 class ArgumentsSchema(TypedDict, total=False):
@@ -309,6 +577,22 @@ class CallSchema(TypedDict):
     ref: NotRequired[str]
 
 
+class ChainSchema(TypedDict):
+    type: Literal['chain']
+    steps: List[Schema]  # each step validates the output of the previous one
+    ref: NotRequired[str]
+
+
+class PredicateSchema(TypedDict, total=False):
+    type: Required[Literal['predicate']]
+    predicate: Required[Callable[[Any], bool]]  # called with the output of `schema`
+    error_kind: Required[str]
+    message_template: Required[str]  # formatted with `context` as with `PydanticValueError`
+    context: Dict[str, Any]
+    schema: Schema  # default: AnySchema
+    ref: str
+
+
 # pydantic allows types to be defined via a simple string instead of dict with just `type`, e.g.
 # 'int' is equivalent to {'type': 'int'}, this only applies to schema types which do not have other required fields
 BareType = Literal[
@@ -316,20 +600,33 @@ BareType = Literal[
     'none',
     'str',
     'bytes',
+    'secret-str',
+    'secret-bytes',
+    'path',
+    'pattern',
     'dict',
     'int',
     'bool',
     'float',
+    'fraction',
+    'decimal',
+    'uuid',
+    'zoneinfo',
     'dict',
     'list',
     'tuple',
     'set',
     'frozenset',
+    'deque',
+    'generator',
+    'ordered-dict',
+    'counter',
     'date',
     'time',
     'datetime',
     'timedelta',
     'callable',
+    'json',
 ]
 
 # generate_self_schema.py is hard coded to convert this Union[BareType, Union[...rest]] where the second union is tagged
@@ -340,20 +637,39 @@ Schema = Union[
     BoolSchema,
     BytesSchema,
     DictSchema,
+    DefaultDictSchema,
+    OrderedDictSchema,
+    CounterSchema,
     FloatSchema,
+    FractionSchema,
+    UuidSchema,
+    ZoneInfoSchema,
+    DecimalSchema,
     FunctionSchema,
     FunctionPlainSchema,
     IntSchema,
+    IntFlagSchema,
     ListSchema,
     LiteralSchema,
+    LocPrefixSchema,
     TypedDictSchema,
     NewClassSchema,
+    DataclassSchema,
     NoneSchema,
     NullableSchema,
+    WithDefaultSchema,
     RecursiveReferenceSchema,
+    DefinitionsSchema,
+    DefinitionReferenceSchema,
     SetSchema,
     FrozenSetSchema,
+    DequeSchema,
+    GeneratorSchema,
     StringSchema,
+    SecretStrSchema,
+    SecretBytesSchema,
+    PathSchema,
+    PatternSchema,
     TuplePositionalSchema,
     TupleVariableSchema,
     UnionSchema,
@@ -363,7 +679,11 @@ Schema = Union[
     DatetimeSchema,
     TimedeltaSchema,
     IsInstanceSchema,
+    IsSubclassSchema,
     CallableSchema,
     ArgumentsSchema,
     CallSchema,
+    ChainSchema,
+    PredicateSchema,
+    JsonSchema,
 ]
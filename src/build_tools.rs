@@ -102,6 +102,8 @@ pub fn is_strict(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
 #[pyclass(extends=PyException, module="pydantic_core._pydantic_core")]
 pub struct SchemaError {
     message: String,
+    // keys from the root schema to the schema which failed to build, empty if unknown or the root schema failed
+    schema_path: Vec<PyObject>,
 }
 
 impl fmt::Debug for SchemaError {
@@ -145,8 +147,16 @@ impl SchemaError {
 #[pymethods]
 impl SchemaError {
     #[new]
-    fn py_new(message: String) -> Self {
-        Self { message }
+    fn py_new(message: String, schema_path: Option<Vec<PyObject>>) -> Self {
+        Self {
+            message,
+            schema_path: schema_path.unwrap_or_default(),
+        }
+    }
+
+    #[getter]
+    fn schema_path(&self, py: Python) -> PyObject {
+        self.schema_path.to_object(py)
     }
 
     fn __repr__(&self) -> String {
@@ -0,0 +1,99 @@
+use std::cell::RefCell;
+use std::fmt;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyList, PyLong, PyString};
+use pyo3::{intern, PyTypeInfo};
+
+use crate::errors::{LocItem, Location};
+
+/// Record of a value whose type was changed by validation, e.g. `'123'` -> `123`
+#[derive(Clone)]
+struct Coercion {
+    loc: Location,
+    from_type: String,
+    to_type: String,
+    // `None` if the output couldn't be converted back to the input type
+    lossy: Option<bool>,
+}
+
+/// Collects `Coercion`s while validating, used by `SchemaValidator.validate_python_with_coercions`.
+/// Coercions are only recorded for typed-dict fields, nested fields get their full location via
+/// `with_outer_location` in the same way as line errors, and coercions made by a union choice which then
+/// failed are discarded via `truncate`.
+#[derive(Default)]
+pub struct Coercions {
+    records: RefCell<Vec<Coercion>>,
+}
+
+impl fmt::Debug for Coercions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Coercions({})", self.len())
+    }
+}
+
+impl Coercions {
+    pub fn len(&self) -> usize {
+        self.records.borrow().len()
+    }
+
+    /// Discard coercions recorded after `len`, e.g. those from a union choice which failed
+    pub fn truncate(&self, len: usize) {
+        self.records.borrow_mut().truncate(len);
+    }
+
+    /// Record a coercion if the type of `output` differs from the type of `input`, the coercion is "lossy" if
+    /// converting `output` back to the type of `input` doesn't give a value equal to `input`.
+    /// Converting back is only attempted between builtin scalar types, other types could run arbitrary code
+    /// in their constructor so `lossy` is `None`
+    pub fn record(&self, loc_item: LocItem, input: &PyAny, output: &PyAny) -> PyResult<()> {
+        let input_type = input.get_type();
+        let output_type = output.get_type();
+        if input_type.is(output_type) {
+            return Ok(());
+        }
+        let lossy = if is_builtin_scalar(input) && is_builtin_scalar(output) {
+            match input_type.call1((output,)) {
+                Ok(round_trip) => Some(!round_trip.eq(input)?),
+                Err(_) => None,
+            }
+        } else {
+            None
+        };
+        self.records.borrow_mut().push(Coercion {
+            loc: Location::new_some(loc_item),
+            from_type: input_type.name()?.to_string(),
+            to_type: output_type.name()?.to_string(),
+            lossy,
+        });
+        Ok(())
+    }
+
+    /// Add an outer location item to all coercions recorded since `start`, see `ValLineError::with_outer_location`
+    pub fn with_outer_location(&self, start: usize, loc_item: &LocItem) {
+        for coercion in self.records.borrow_mut()[start..].iter_mut() {
+            coercion.loc.with_outer(loc_item.clone());
+        }
+    }
+
+    pub fn to_py_list(&self, py: Python) -> PyResult<PyObject> {
+        let list = PyList::empty(py);
+        for coercion in self.records.borrow().iter() {
+            let dict = PyDict::new(py);
+            dict.set_item(intern!(py, "loc"), coercion.loc.to_object(py))?;
+            dict.set_item(intern!(py, "from_type"), &coercion.from_type)?;
+            dict.set_item(intern!(py, "to_type"), &coercion.to_type)?;
+            dict.set_item(intern!(py, "lossy"), coercion.lossy)?;
+            list.append(dict)?;
+        }
+        Ok(list.into_py(py))
+    }
+}
+
+fn is_builtin_scalar(value: &PyAny) -> bool {
+    PyBool::is_exact_type_of(value)
+        || PyLong::is_exact_type_of(value)
+        || PyFloat::is_exact_type_of(value)
+        || PyString::is_exact_type_of(value)
+        || PyBytes::is_exact_type_of(value)
+}
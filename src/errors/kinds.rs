@@ -20,6 +20,10 @@ pub enum ErrorKind {
     InvalidJson {
         error: String,
     },
+    #[strum(message = "JSON input should be string, bytes, bytearray or memoryview")]
+    JsonType,
+    #[strum(message = "String data should only contain str values, and dicts, lists, tuples or mappings of them")]
+    StringDataType,
     // ---------------------
     // recursion error
     #[strum(message = "Recursion error - cyclic reference detected")]
@@ -30,6 +34,10 @@ pub enum ErrorKind {
     DictAttributesType,
     #[strum(message = "Field required")]
     Missing,
+    #[strum(message = "Multiple values provided for the field, via {keys}")]
+    MultipleValues {
+        keys: String,
+    },
     #[strum(message = "Field is frozen")]
     Frozen,
     #[strum(message = "Extra inputs are not permitted")]
@@ -46,6 +54,10 @@ pub enum ErrorKind {
     ModelClassType {
         class_name: String,
     },
+    #[strum(message = "Input should be an instance of {class_name}")]
+    DataclassType {
+        class_name: String,
+    },
     // ---------------------
     // None errors
     #[strum(message = "Input should be None/null")]
@@ -88,6 +100,10 @@ pub enum ErrorKind {
         max_length: usize,
         input_length: usize,
     },
+    #[strum(message = "Items should be unique, this item is a duplicate of item {first_index}")]
+    DuplicateItem {
+        first_index: usize,
+    },
     // ---------------------
     // string errors
     #[strum(message = "Input should be a valid string")]
@@ -136,6 +152,8 @@ pub enum ErrorKind {
     // set errors
     #[strum(message = "Input should be a valid set")]
     SetType,
+    #[strum(message = "Set items should be hashable")]
+    SetItemNotHashable,
     // ---------------------
     // bool errors
     #[strum(message = "Input should be a valid boolean")]
@@ -215,6 +233,58 @@ pub enum ErrorKind {
         le: f64,
     },
     // ---------------------
+    // fraction errors
+    #[strum(message = "Input should be a valid fraction")]
+    FractionType,
+    #[strum(message = "Input should be a valid fraction, unable to parse string as a fraction")]
+    FractionParsing,
+    #[strum(serialize = "greater_than", message = "Input should be greater than {gt}")]
+    FractionGreaterThan {
+        gt: String,
+    },
+    #[strum(
+        serialize = "greater_than_equal",
+        message = "Input should be greater than or equal to {ge}"
+    )]
+    FractionGreaterThanEqual {
+        ge: String,
+    },
+    #[strum(serialize = "less_than", message = "Input should be less than {lt}")]
+    FractionLessThan {
+        lt: String,
+    },
+    #[strum(
+        serialize = "less_than_equal",
+        message = "Input should be less than or equal to {le}"
+    )]
+    FractionLessThanEqual {
+        le: String,
+    },
+    // ---------------------
+    // decimal errors
+    #[strum(message = "Input should be a valid decimal")]
+    DecimalType,
+    #[strum(message = "Input should be a valid decimal, unable to parse string as a decimal")]
+    DecimalParsing,
+    #[strum(message = "Input should be a valid decimal, floats are not accepted with float_policy '{float_policy}'")]
+    DecimalFromFloat {
+        float_policy: &'static str,
+    },
+    // ---------------------
+    // timezone errors
+    #[strum(message = "Input should be a valid timezone, a ZoneInfo instance or timezone name")]
+    TimezoneType,
+    #[strum(message = "Input should be a valid IANA timezone name, unable to find timezone")]
+    TimezoneNotFound,
+    // ---------------------
+    // uuid errors
+    #[strum(message = "Input should be a valid UUID, a string or 16 bytes")]
+    UuidType,
+    #[strum(message = "Input should be a valid UUID, {error}")]
+    UuidParsing {
+        error: String,
+    },
+    // ---------------------
     // bytes errors
     #[strum(message = "Input should be a valid bytes")]
     BytesType,
@@ -226,6 +296,41 @@ pub enum ErrorKind {
     BytesTooLong {
         max_length: usize,
     },
+    #[strum(message = "Data should be valid {encoding}, {error}")]
+    BytesInvalidEncoding {
+        encoding: &'static str,
+        error: String,
+    },
+    // ---------------------
+    // secret errors
+    #[strum(message = "Input should be a valid secret value")]
+    SecretInvalid,
+    // ---------------------
+    // path errors
+    #[strum(message = "Input should be a valid path")]
+    PathType,
+    #[strum(message = "Path \"{path}\" does not exist")]
+    PathNotExists {
+        path: String,
+    },
+    #[strum(message = "Path \"{path}\" does not point to a file")]
+    PathNotFile {
+        path: String,
+    },
+    #[strum(message = "Path \"{path}\" does not point to a directory")]
+    PathNotDirectory {
+        path: String,
+    },
+    // ---------------------
+    // regex pattern errors
+    #[strum(message = "Input should be a {pattern_type} regular expression pattern")]
+    PatternType {
+        pattern_type: &'static str,
+    },
+    #[strum(message = "Input should be a valid regular expression, {error}")]
+    PatternRegex {
+        error: String,
+    },
     // ---------------------
     // python errors from functions
     #[strum(message = "Value error, {error}")]
@@ -241,6 +346,22 @@ pub enum ErrorKind {
         value_error: PydanticValueError,
     },
     // ---------------------
+    // int flags
+    #[strum(message = "Input should be a valid {class_name}, an integer, a member name or a list of member names")]
+    IntFlagType {
+        class_name: String,
+    },
+    #[strum(message = "Input should be a member name of {class_name}: {expected}")]
+    IntFlagName {
+        class_name: String,
+        expected: String,
+    },
+    #[strum(message = "Input should be a valid {class_name} value, {error}")]
+    IntFlagValue {
+        class_name: String,
+        error: String,
+    },
+    // ---------------------
     // literals
     #[strum(serialize = "literal_error", message = "Input should be {expected}")]
     LiteralSingleError {
@@ -303,11 +424,21 @@ pub enum ErrorKind {
     #[strum(message = "Input should be a valid frozenset")]
     FrozenSetType,
     // ---------------------
+    // deque errors
+    #[strum(message = "Input should be a valid deque")]
+    DequeType,
+    #[strum(message = "Input should be iterable")]
+    IterableType,
+    // ---------------------
     // introspection types - e.g. isinstance, callable
     #[strum(message = "Input should be an instance of {class}")]
     IsInstanceOf {
         class: String,
     },
+    #[strum(message = "Input should be a subclass of {class}")]
+    IsSubclassOf {
+        class: String,
+    },
     #[strum(message = "Input should be callable")]
     CallableType,
     // ---------------------
@@ -392,7 +523,9 @@ impl ErrorKind {
         match self {
             Self::InvalidJson { error } => render!(self, error),
             Self::GetAttributeError { error } => render!(self, error),
+            Self::MultipleValues { keys } => render!(self, keys),
             Self::ModelClassType { class_name } => render!(self, class_name),
+            Self::DataclassType { class_name } => render!(self, class_name),
             Self::GreaterThan { gt } => render!(self, gt),
             Self::GreaterThanEqual { ge } => render!(self, ge),
             Self::LessThan { lt } => render!(self, lt),
@@ -413,6 +546,7 @@ impl ErrorKind {
                 let input_plural = plural_s(input_length);
                 to_string_render!(self, max_length, input_length, expected_plural, input_plural)
             }
+            Self::DuplicateItem { first_index } => to_string_render!(self, first_index),
             Self::StrTooShort { min_length } => to_string_render!(self, min_length),
             Self::StrTooLong { max_length } => to_string_render!(self, max_length),
             Self::StrPatternMismatch { pattern } => render!(self, pattern),
@@ -428,11 +562,26 @@ impl ErrorKind {
             Self::FloatGreaterThanEqual { ge } => to_string_render!(self, ge),
             Self::FloatLessThan { lt } => to_string_render!(self, lt),
             Self::FloatLessThanEqual { le } => to_string_render!(self, le),
+            Self::FractionGreaterThan { gt } => render!(self, gt),
+            Self::FractionGreaterThanEqual { ge } => render!(self, ge),
+            Self::FractionLessThan { lt } => render!(self, lt),
+            Self::FractionLessThanEqual { le } => render!(self, le),
+            Self::DecimalFromFloat { float_policy } => render!(self, float_policy),
+            Self::UuidParsing { error } => render!(self, error),
             Self::BytesTooShort { min_length } => to_string_render!(self, min_length),
             Self::BytesTooLong { max_length } => to_string_render!(self, max_length),
+            Self::BytesInvalidEncoding { encoding, error } => render!(self, encoding, error),
+            Self::PathNotExists { path } => render!(self, path),
+            Self::PathNotFile { path } => render!(self, path),
+            Self::PathNotDirectory { path } => render!(self, path),
+            Self::PatternType { pattern_type } => render!(self, pattern_type),
+            Self::PatternRegex { error } => render!(self, error),
             Self::ValueError { error } => render!(self, error),
             Self::AssertionError { error } => render!(self, error),
             Self::CustomError { value_error } => value_error.message(py),
+            Self::IntFlagType { class_name } => render!(self, class_name),
+            Self::IntFlagName { class_name, expected } => render!(self, class_name, expected),
+            Self::IntFlagValue { class_name, error } => render!(self, class_name, error),
             Self::LiteralSingleError { expected } => render!(self, expected),
             Self::LiteralMultipleError { expected } => render!(self, expected),
             Self::DateParsing { error } => render!(self, error),
@@ -442,6 +591,7 @@ impl ErrorKind {
             Self::DateTimeObjectInvalid { error } => render!(self, error),
             Self::TimeDeltaParsing { error } => render!(self, error),
             Self::IsInstanceOf { class } => render!(self, class),
+            Self::IsSubclassOf { class } => render!(self, class),
             Self::UnionTagInvalid {
                 discriminator,
                 tag,
@@ -456,7 +606,9 @@ impl ErrorKind {
         match self {
             Self::InvalidJson { error } => py_dict!(py, error),
             Self::GetAttributeError { error } => py_dict!(py, error),
+            Self::MultipleValues { keys } => py_dict!(py, keys),
             Self::ModelClassType { class_name } => py_dict!(py, class_name),
+            Self::DataclassType { class_name } => py_dict!(py, class_name),
             Self::GreaterThan { gt } => py_dict!(py, gt),
             Self::GreaterThanEqual { ge } => py_dict!(py, ge),
             Self::LessThan { lt } => py_dict!(py, lt),
@@ -469,6 +621,7 @@ impl ErrorKind {
                 max_length,
                 input_length,
             } => py_dict!(py, max_length, input_length),
+            Self::DuplicateItem { first_index } => py_dict!(py, first_index),
             Self::StrTooShort { min_length } => py_dict!(py, min_length),
             Self::StrTooLong { max_length } => py_dict!(py, max_length),
             Self::StrPatternMismatch { pattern } => py_dict!(py, pattern),
@@ -484,11 +637,26 @@ impl ErrorKind {
             Self::FloatGreaterThanEqual { ge } => py_dict!(py, ge),
             Self::FloatLessThan { lt } => py_dict!(py, lt),
             Self::FloatLessThanEqual { le } => py_dict!(py, le),
+            Self::FractionGreaterThan { gt } => py_dict!(py, gt),
+            Self::FractionGreaterThanEqual { ge } => py_dict!(py, ge),
+            Self::FractionLessThan { lt } => py_dict!(py, lt),
+            Self::FractionLessThanEqual { le } => py_dict!(py, le),
+            Self::DecimalFromFloat { float_policy } => py_dict!(py, float_policy),
+            Self::UuidParsing { error } => py_dict!(py, error),
             Self::BytesTooShort { min_length } => py_dict!(py, min_length),
             Self::BytesTooLong { max_length } => py_dict!(py, max_length),
+            Self::BytesInvalidEncoding { encoding, error } => py_dict!(py, encoding, error),
+            Self::PathNotExists { path } => py_dict!(py, path),
+            Self::PathNotFile { path } => py_dict!(py, path),
+            Self::PathNotDirectory { path } => py_dict!(py, path),
+            Self::PatternType { pattern_type } => py_dict!(py, pattern_type),
+            Self::PatternRegex { error } => py_dict!(py, error),
             Self::ValueError { error } => py_dict!(py, error),
             Self::AssertionError { error } => py_dict!(py, error),
             Self::CustomError { value_error } => Ok(value_error.context(py)),
+            Self::IntFlagType { class_name } => py_dict!(py, class_name),
+            Self::IntFlagName { class_name, expected } => py_dict!(py, class_name, expected),
+            Self::IntFlagValue { class_name, error } => py_dict!(py, class_name, error),
             Self::LiteralSingleError { expected } => py_dict!(py, expected),
             Self::LiteralMultipleError { expected } => py_dict!(py, expected),
             Self::DateParsing { error } => py_dict!(py, error),
@@ -498,6 +666,7 @@ impl ErrorKind {
             Self::DateTimeObjectInvalid { error } => py_dict!(py, error),
             Self::TimeDeltaParsing { error } => py_dict!(py, error),
             Self::IsInstanceOf { class } => py_dict!(py, class),
+            Self::IsSubclassOf { class } => py_dict!(py, class),
             Self::UnionTagInvalid {
                 discriminator,
                 tag,
@@ -0,0 +1,44 @@
+use std::cell::Cell;
+
+use super::line_error::ValLineError;
+
+/// Caps the number of line errors collections hold while validating, set from `max_errors`.
+/// Once a list, dict, typed-dict etc. holds `max_errors` errors further errors are only counted, so a huge invalid
+/// input doesn't build a huge list of errors which would mostly be thrown away when the `ValidationError` is created.
+#[derive(Debug)]
+pub struct ErrorLimit {
+    max_errors: usize,
+    omitted: Cell<usize>,
+}
+
+impl ErrorLimit {
+    pub fn new(max_errors: usize) -> Self {
+        Self {
+            max_errors,
+            omitted: Cell::new(0),
+        }
+    }
+
+    pub fn max_errors(&self) -> usize {
+        self.max_errors
+    }
+
+    /// Number of errors counted but not kept so far
+    pub fn omitted(&self) -> usize {
+        self.omitted.get()
+    }
+
+    /// Forget errors omitted after `omitted` were counted, used where errors are discarded rather than returned,
+    /// e.g. the errors of a union choice which failed when another choice succeeds
+    pub fn reset(&self, omitted: usize) {
+        self.omitted.set(omitted);
+    }
+
+    pub fn push<'a>(&self, errors: &mut Vec<ValLineError<'a>>, error: ValLineError<'a>) {
+        if errors.len() < self.max_errors {
+            errors.push(error);
+        } else {
+            self.omitted.set(self.omitted.get() + 1);
+        }
+    }
+}
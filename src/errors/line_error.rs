@@ -59,6 +59,17 @@ impl<'a> ValError<'a> {
             Self::InternalErr(err) => Self::InternalErr(err),
         }
     }
+
+    /// Convert the error so it no longer borrows the input, used where the input only exists during validation,
+    /// e.g. values parsed by the `json` validator
+    pub fn into_owned(self, py: Python) -> ValError<'static> {
+        match self {
+            Self::LineErrors(line_errors) => {
+                ValError::LineErrors(line_errors.into_iter().map(|e| e.into_owned(py)).collect())
+            }
+            Self::InternalErr(err) => ValError::InternalErr(err),
+        }
+    }
 }
 
 pub fn pretty_line_errors(py: Python, line_errors: Vec<ValLineError>) -> String {
@@ -75,6 +86,8 @@ pub struct ValLineError<'a> {
     // location is reversed so that adding an "outer" location item is pushing, it's reversed before showing to the user
     pub location: Location,
     pub input_value: InputValue<'a>,
+    // description of the field where the error occurred, appended to the message if set
+    pub field_description: Option<String>,
 }
 
 impl<'a> ValLineError<'a> {
@@ -83,6 +96,7 @@ impl<'a> ValLineError<'a> {
             kind,
             input_value: input.as_error_value(),
             location: Location::default(),
+            field_description: None,
         }
     }
 
@@ -91,6 +105,7 @@ impl<'a> ValLineError<'a> {
             kind,
             input_value: input.as_error_value(),
             location: Location::new_some(loc.into()),
+            field_description: None,
         }
     }
 
@@ -99,6 +114,7 @@ impl<'a> ValLineError<'a> {
             kind,
             input_value,
             location: Location::default(),
+            field_description: None,
         }
     }
 
@@ -109,11 +125,29 @@ impl<'a> ValLineError<'a> {
         self
     }
 
+    /// set the description of the field where the error occurred, unless the error already has one
+    /// from a field closer to where it occurred
+    pub fn with_field_description(mut self, description: &str) -> Self {
+        if self.field_description.is_none() {
+            self.field_description = Some(description.to_string());
+        }
+        self
+    }
+
     // change the kind on a error in place
     pub fn with_kind(mut self, kind: ErrorKind) -> Self {
         self.kind = kind;
         self
     }
+
+    pub fn into_owned(self, py: Python) -> ValLineError<'static> {
+        ValLineError {
+            kind: self.kind,
+            location: self.location,
+            input_value: self.input_value.to_object(py).into(),
+            field_description: self.field_description,
+        }
+    }
 }
 
 #[cfg_attr(debug_assertions, derive(Debug))]
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{PyList, PyTuple};
 
 /// Used to store individual items of the error location, e.g. a string for key/field names
 /// or a number for array indices.
@@ -94,6 +94,14 @@ impl fmt::Display for Location {
 }
 
 impl Location {
+    /// Like `to_object` but as a tuple, used by `ValidationError.structured_errors()`
+    pub fn to_tuple(&self, py: Python) -> PyObject {
+        match self {
+            Self::List(loc) => PyTuple::new(py, loc.iter().rev()).to_object(py),
+            Self::Empty => PyTuple::empty(py).to_object(py),
+        }
+    }
+
     /// create a new location vec with a value, 3 is plucked out of thin air, should it just be 1?
     pub fn new_some(item: LocItem) -> Self {
         let mut loc = Vec::with_capacity(3);
@@ -110,3 +118,78 @@ impl Location {
         };
     }
 }
+
+/// The location of the value currently being validated, as opposed to `Location` which is built up as errors
+/// are returned. Each item borrows its parent so validators can extend the location without allocating,
+/// it's used to tell function validators where they are.
+pub struct CurrentLoc<'a> {
+    item: CurrentLocItem<'a>,
+    parent: Option<&'a CurrentLoc<'a>>,
+}
+
+enum CurrentLocItem<'a> {
+    // typed-dict field names are borrowed from the validator, the name is also used as `field_name`
+    Field(&'a str),
+    Index(usize),
+    // the field name is only set when restoring a location, see `restore`
+    Items(Vec<LocItem>, Option<&'a str>),
+}
+
+impl<'a> CurrentLoc<'a> {
+    pub fn field(name: &'a str, parent: Option<&'a CurrentLoc<'a>>) -> Self {
+        Self {
+            item: CurrentLocItem::Field(name),
+            parent,
+        }
+    }
+
+    pub fn index(index: usize, parent: Option<&'a CurrentLoc<'a>>) -> Self {
+        Self {
+            item: CurrentLocItem::Index(index),
+            parent,
+        }
+    }
+
+    pub fn items(items: Vec<LocItem>, parent: Option<&'a CurrentLoc<'a>>) -> Self {
+        Self {
+            item: CurrentLocItem::Items(items, None),
+            parent,
+        }
+    }
+
+    /// Rebuild a location from `to_vec` and `field_name`, used where the location has to outlive the validator call
+    pub fn restore(items: Vec<LocItem>, field_name: Option<&'a str>) -> Self {
+        Self {
+            item: CurrentLocItem::Items(items, field_name),
+            parent: None,
+        }
+    }
+
+    /// name of the innermost typed-dict field
+    pub fn field_name(&self) -> Option<&str> {
+        match self.item {
+            CurrentLocItem::Field(name) | CurrentLocItem::Items(_, Some(name)) => Some(name),
+            _ => self.parent.and_then(|parent| parent.field_name()),
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<LocItem> {
+        let mut loc = match self.parent {
+            Some(parent) => parent.to_vec(),
+            None => Vec::new(),
+        };
+        match self.item {
+            CurrentLocItem::Field(name) => loc.push(name.into()),
+            CurrentLocItem::Index(index) => loc.push(index.into()),
+            CurrentLocItem::Items(ref items, _) => loc.extend(items.iter().cloned()),
+        }
+        loc
+    }
+}
+
+impl fmt::Debug for CurrentLoc<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let loc_str = self.to_vec().iter().map(|i| i.to_string()).collect::<Vec<_>>();
+        write!(f, "CurrentLoc({})", loc_str.join(" -> "))
+    }
+}
@@ -1,15 +1,17 @@
 use pyo3::prelude::*;
 
 mod kinds;
+mod limit;
 mod line_error;
 mod location;
 mod validation_exception;
 mod value_exception;
 
 pub use self::kinds::ErrorKind;
+pub use self::limit::ErrorLimit;
 pub use self::line_error::{pretty_line_errors, InputValue, ValError, ValLineError, ValResult};
-pub use self::location::LocItem;
-pub use self::validation_exception::ValidationError;
+pub use self::location::{CurrentLoc, LocItem, Location};
+pub use self::validation_exception::{PyLineError, ValidationError, ValidationResult};
 pub use self::value_exception::PydanticValueError;
 
 pub fn py_err_string(py: Python, err: PyErr) -> String {
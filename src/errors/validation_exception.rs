@@ -8,9 +8,10 @@ use pyo3::types::PyDict;
 use crate::input::repr_string;
 
 use super::kinds::ErrorKind;
+use super::limit::ErrorLimit;
 use super::line_error::ValLineError;
-use super::location::Location;
-use super::ValError;
+use super::location::{LocItem, Location};
+use super::{ValError, ValResult};
 
 #[pyclass(extends=PyValueError, module="pydantic_core._pydantic_core")]
 #[derive(Clone)]
@@ -18,24 +19,41 @@ use super::ValError;
 pub struct ValidationError {
     line_errors: Vec<PyLineError>,
     title: PyObject,
+    // number of errors dropped because of `max_errors`
+    omitted_errors: usize,
 }
 
 impl ValidationError {
     pub fn from_val_error(py: Python, title: PyObject, error: ValError) -> PyErr {
+        Self::from_val_error_limited(py, title, error, None)
+    }
+
+    /// Like `from_val_error` but only the first `max_errors` line errors are kept, `error_limit` is the limit
+    /// the errors were collected with, errors it omitted and errors beyond `max_errors` are just counted
+    pub fn from_val_error_limited(
+        py: Python,
+        title: PyObject,
+        error: ValError,
+        error_limit: Option<&ErrorLimit>,
+    ) -> PyErr {
         match error {
             ValError::LineErrors(raw_errors) => {
-                let line_errors: Vec<PyLineError> = raw_errors.into_iter().map(|e| e.into_py(py)).collect();
-                PyErr::new::<ValidationError, _>((line_errors, title))
+                let (line_errors, omitted_errors) = limit_line_errors(py, raw_errors, error_limit);
+                PyErr::new::<ValidationError, _>((line_errors, title, omitted_errors))
             }
             ValError::InternalErr(err) => err,
         }
     }
 
     fn display(&self, py: Python) -> String {
-        let count = self.line_errors.len();
+        let count = self.line_errors.len() + self.omitted_errors;
         let plural = if count == 1 { "" } else { "s" };
         let title: &str = self.title.extract(py).unwrap();
-        let line_errors = pretty_py_line_errors(py, self.line_errors.iter());
+        let mut line_errors = pretty_py_line_errors(py, self.line_errors.iter());
+        if self.omitted_errors > 0 {
+            let plural = if self.omitted_errors == 1 { "" } else { "s" };
+            write!(line_errors, "\n[{} more error{} omitted]", self.omitted_errors, plural).unwrap();
+        }
         format!("{} validation error{} for {}\n{}", count, plural, title, line_errors)
     }
 }
@@ -54,8 +72,12 @@ impl<'a> IntoPy<ValError<'a>> for ValidationError {
 #[pymethods]
 impl ValidationError {
     #[new]
-    fn py_new(line_errors: Vec<PyLineError>, title: PyObject) -> Self {
-        Self { line_errors, title }
+    fn py_new(line_errors: Vec<PyLineError>, title: PyObject, omitted_errors: Option<usize>) -> Self {
+        Self {
+            line_errors,
+            title,
+            omitted_errors: omitted_errors.unwrap_or(0),
+        }
     }
 
     #[getter]
@@ -67,6 +89,11 @@ impl ValidationError {
         self.line_errors.len()
     }
 
+    /// Number of errors not included in `errors()` because of `max_errors`
+    fn omitted_error_count(&self) -> usize {
+        self.omitted_errors
+    }
+
     fn errors(&self, py: Python) -> PyResult<PyObject> {
         Ok(self
             .line_errors
@@ -76,6 +103,12 @@ impl ValidationError {
             .into_py(py))
     }
 
+    /// Like `errors()` but with the keys `type`, `loc` as a tuple, `msg`, `input` and `ctx`, the constraint
+    /// parameters of the error, which is always included
+    fn structured_errors(&self, py: Python) -> PyResult<PyObject> {
+        structured_errors(py, &self.line_errors)
+    }
+
     fn __repr__(&self, py: Python) -> String {
         self.display(py)
     }
@@ -85,6 +118,102 @@ impl ValidationError {
     }
 }
 
+/// Returned by `validate_python_result`, holds either the validated value or the validation errors, so invalid
+/// input can be handled without the cost of raising and catching a `ValidationError`
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct ValidationResult {
+    value: Option<PyObject>,
+    line_errors: Vec<PyLineError>,
+    title: PyObject,
+    // number of errors dropped because of `max_errors`
+    omitted_errors: usize,
+}
+
+impl ValidationResult {
+    /// `error_limit` is as for `ValidationError::from_val_error_limited`
+    pub fn from_val_result(
+        py: Python,
+        title: PyObject,
+        result: ValResult<PyObject>,
+        error_limit: Option<&ErrorLimit>,
+    ) -> PyResult<Self> {
+        match result {
+            Ok(value) => Ok(Self {
+                value: Some(value),
+                line_errors: Vec::new(),
+                title,
+                omitted_errors: 0,
+            }),
+            Err(ValError::LineErrors(raw_errors)) => {
+                let (line_errors, omitted_errors) = limit_line_errors(py, raw_errors, error_limit);
+                Ok(Self {
+                    value: None,
+                    line_errors,
+                    title,
+                    omitted_errors,
+                })
+            }
+            Err(ValError::InternalErr(err)) => Err(err),
+        }
+    }
+}
+
+#[pymethods]
+impl ValidationResult {
+    #[getter]
+    pub fn ok(&self) -> bool {
+        self.value.is_some()
+    }
+
+    #[getter]
+    fn value(&self, py: Python) -> PyObject {
+        match self.value {
+            Some(ref value) => value.clone_ref(py),
+            None => py.None(),
+        }
+    }
+
+    #[getter]
+    fn errors(&self, py: Python) -> PyResult<PyObject> {
+        Ok(self
+            .line_errors
+            .iter()
+            .map(|e| e.as_dict(py))
+            .collect::<PyResult<Vec<PyObject>>>()?
+            .into_py(py))
+    }
+
+    #[getter]
+    fn title(&self, py: Python) -> PyObject {
+        self.title.clone_ref(py)
+    }
+
+    fn error_count(&self) -> usize {
+        self.line_errors.len()
+    }
+
+    /// See `ValidationError.omitted_error_count()`
+    fn omitted_error_count(&self) -> usize {
+        self.omitted_errors
+    }
+
+    /// See `ValidationError.structured_errors()`
+    fn structured_errors(&self, py: Python) -> PyResult<PyObject> {
+        structured_errors(py, &self.line_errors)
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        match self.value {
+            Some(ref value) => Ok(format!("ValidationResult(ok=True, value={})", value.as_ref(py).repr()?)),
+            None => Ok(format!(
+                "ValidationResult(ok=False, error_count={})",
+                self.line_errors.len()
+            )),
+        }
+    }
+}
+
 macro_rules! truncate_input_value {
     ($out:expr, $value:expr) => {
         if $value.len() > 50 {
@@ -100,6 +229,33 @@ macro_rules! truncate_input_value {
     };
 }
 
+/// Keep the first `max_errors` of `raw_errors`, returns the errors kept and the number of errors omitted, including
+/// those `error_limit` omitted while the errors were collected
+fn limit_line_errors(
+    py: Python,
+    mut raw_errors: Vec<ValLineError>,
+    error_limit: Option<&ErrorLimit>,
+) -> (Vec<PyLineError>, usize) {
+    let omitted_errors = match error_limit {
+        Some(error_limit) => {
+            let truncated = raw_errors.len().saturating_sub(error_limit.max_errors());
+            raw_errors.truncate(error_limit.max_errors());
+            error_limit.omitted() + truncated
+        }
+        None => 0,
+    };
+    let line_errors = raw_errors.into_iter().map(|e| e.into_py(py)).collect();
+    (line_errors, omitted_errors)
+}
+
+fn structured_errors(py: Python, line_errors: &[PyLineError]) -> PyResult<PyObject> {
+    Ok(line_errors
+        .iter()
+        .map(|e| e.as_structured_dict(py))
+        .collect::<PyResult<Vec<PyObject>>>()?
+        .into_py(py))
+}
+
 pub fn pretty_py_line_errors<'a>(py: Python, line_errors_iter: impl Iterator<Item = &'a PyLineError>) -> String {
     line_errors_iter
         .map(|i| i.pretty(py))
@@ -116,6 +272,7 @@ pub struct PyLineError {
     kind: ErrorKind,
     location: Location,
     input_value: PyObject,
+    field_description: Option<String>,
 }
 
 impl<'a> IntoPy<PyLineError> for ValLineError<'a> {
@@ -124,6 +281,7 @@ impl<'a> IntoPy<PyLineError> for ValLineError<'a> {
             kind: self.kind,
             location: self.location,
             input_value: self.input_value.to_object(py),
+            field_description: self.field_description,
         }
     }
 }
@@ -135,16 +293,22 @@ impl<'a> IntoPy<ValLineError<'a>> for PyLineError {
             kind: self.kind,
             location: self.location,
             input_value: self.input_value.into(),
+            field_description: self.field_description,
         }
     }
 }
 
 impl PyLineError {
+    /// Add an outer location item, see `ValLineError::with_outer_location`
+    pub fn with_outer_location(&mut self, loc_item: LocItem) {
+        self.location.with_outer(loc_item);
+    }
+
     pub fn as_dict(&self, py: Python) -> PyResult<PyObject> {
         let dict = PyDict::new(py);
         dict.set_item("kind", self.kind.kind())?;
         dict.set_item("loc", self.location.to_object(py))?;
-        dict.set_item("message", self.kind.render_message(py)?)?;
+        dict.set_item("message", self.message(py)?)?;
         dict.set_item("input_value", &self.input_value)?;
         if let Some(context) = self.kind.py_dict(py)? {
             dict.set_item("context", context)?;
@@ -152,11 +316,34 @@ impl PyLineError {
         Ok(dict.into_py(py))
     }
 
+    /// Like `as_dict` but with the keys used by `structured_errors()`, `loc` is a tuple and `ctx` is always set,
+    /// empty if the error kind has no context
+    pub fn as_structured_dict(&self, py: Python) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("type", self.kind.kind())?;
+        dict.set_item("loc", self.location.to_tuple(py))?;
+        dict.set_item("msg", self.message(py)?)?;
+        dict.set_item("input", &self.input_value)?;
+        match self.kind.py_dict(py)? {
+            Some(context) => dict.set_item("ctx", context)?,
+            None => dict.set_item("ctx", PyDict::new(py))?,
+        }
+        Ok(dict.into_py(py))
+    }
+
+    fn message(&self, py: Python) -> PyResult<String> {
+        let message = self.kind.render_message(py)?;
+        match self.field_description {
+            Some(ref description) => Ok(format!("{} ({})", message, description)),
+            None => Ok(message),
+        }
+    }
+
     fn pretty(&self, py: Python) -> Result<String, fmt::Error> {
         let mut output = String::with_capacity(200);
         write!(output, "{}", self.location)?;
 
-        let message = match self.kind.render_message(py) {
+        let message = match self.message(py) {
             Ok(message) => message,
             Err(err) => format!("(error rendering message: {})", err),
         };
@@ -73,6 +73,14 @@ impl PydanticValueError {
 }
 
 impl PydanticValueError {
+    pub fn new(kind: String, message_template: String, context: Option<Py<PyDict>>) -> Self {
+        Self {
+            kind,
+            message_template,
+            context,
+        }
+    }
+
     pub fn into_val_error<'a>(self, input: &'a impl Input<'a>) -> ValError<'a> {
         let kind = ErrorKind::CustomError { value_error: self };
         ValError::new(kind, input)
@@ -0,0 +1,533 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyFrozenSet, PyList, PySet, PyString, PyTuple};
+
+use crate::build_tools::{py_error, SchemaDict};
+
+/// Beyond this depth, containers get their minimum number of items, nullable schemas are `None` and optional
+/// typed-dict fields are omitted, so recursive schemas produce finite examples
+const SHALLOW_DEPTH: usize = 4;
+/// Recursive schemas which can't get shallower than this, e.g. a required field referencing its own model,
+/// can't have a finite example
+const MAX_DEPTH: usize = 50;
+/// Maximum number of items added to containers beyond `min_items`
+const EXTRA_ITEMS: usize = 5;
+const ALPHANUMERIC: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Generates random input values for a schema as normalized by `dump_schema` with `inline_refs`, values of
+/// (non-recursive) refs are inlined so only references to parent schemas need resolving.
+/// Values aren't guaranteed to be valid, e.g. function validators or set items which happen to be equal
+/// can make them invalid, so they should be validated and regenerated if that fails.
+pub struct ExampleGenerator<'py> {
+    rng: SplitMix64,
+    // parent schemas with a `ref`, used to resolve `recursive-ref` and `definition-ref` schemas
+    ref_stack: Vec<(String, &'py PyDict)>,
+    depth: usize,
+}
+
+impl<'py> ExampleGenerator<'py> {
+    pub fn new(seed: Option<u64>) -> Self {
+        let seed = seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or_default()
+        });
+        Self {
+            rng: SplitMix64(seed),
+            ref_stack: Vec::new(),
+            depth: 0,
+        }
+    }
+
+    pub fn generate(&mut self, schema: &'py PyDict) -> PyResult<&'py PyAny> {
+        if self.depth > MAX_DEPTH {
+            return py_error!(PyValueError; "Unable to generate an example, the schema is infinitely recursive");
+        }
+        let py = schema.py();
+        let schema_ref: Option<String> = schema.get_as(intern!(py, "ref"))?;
+        if let Some(ref schema_ref) = schema_ref {
+            self.ref_stack.push((schema_ref.clone(), schema));
+        }
+        self.depth += 1;
+        let result = self.generate_type(schema);
+        self.depth -= 1;
+        if schema_ref.is_some() {
+            self.ref_stack.pop();
+        }
+        result
+    }
+
+    fn generate_type(&mut self, schema: &'py PyDict) -> PyResult<&'py PyAny> {
+        let py = schema.py();
+        let schema_type: &str = schema.get_as_req(intern!(py, "type"))?;
+        match schema_type {
+            "any" => Ok(match self.rng.below(4) {
+                0 => py.None().into_ref(py),
+                1 => self.rng.bool().into_py(py).into_ref(py),
+                2 => self.rng.range(-1000, 1000).into_py(py).into_ref(py),
+                _ => self.string(0, 10, py),
+            }),
+            "none" => Ok(py.None().into_ref(py)),
+            "bool" => Ok(self.rng.bool().into_py(py).into_ref(py)),
+            "int" => self.int(schema),
+            "float" => self.float(schema).map(|f| f.into_py(py).into_ref(py)),
+            "decimal" => self.number_from_str(schema, "decimal", "Decimal"),
+            "fraction" => self.number_from_str(schema, "fractions", "Fraction"),
+            "int-flag" => {
+                let members: Vec<&PyAny> = schema
+                    .get_as_req::<&PyAny>(intern!(py, "flag_type"))?
+                    .iter()?
+                    .collect::<PyResult<_>>()?;
+                self.choose(&members, "int-flag")
+            }
+            "str" | "secret-str" => {
+                if schema.contains(intern!(py, "pattern"))? {
+                    return py_error!(PyValueError; "Unable to generate examples for strings with a pattern");
+                }
+                let (min_length, max_length) = self.lengths(schema, "min_length", "max_length")?;
+                Ok(self.string(min_length, max_length, py))
+            }
+            "bytes" | "secret-bytes" => {
+                let (min_length, max_length) = self.lengths(schema, "min_length", "max_length")?;
+                let length = self.rng.range(min_length as i64, max_length as i64) as usize;
+                let bytes: Vec<u8> = (0..length).map(|_| self.alphanumeric()).collect();
+                Ok(PyBytes::new(py, &bytes))
+            }
+            "path" => Ok(PyString::new(py, &format!("{}/example.txt", self.string(1, 8, py)))),
+            "pattern" => Ok(PyString::new(py, "^[a-z]+$")),
+            "zoneinfo" => py.import("zoneinfo")?.getattr(intern!(py, "ZoneInfo"))?.call1(("UTC",)),
+            "uuid" => {
+                let bytes: Vec<u8> = (0..16).map(|_| self.rng.below(256) as u8).collect();
+                let kwargs = PyDict::new(py);
+                kwargs.set_item(intern!(py, "bytes"), PyBytes::new(py, &bytes))?;
+                py.import("uuid")?.getattr(intern!(py, "UUID"))?.call((), Some(kwargs))
+            }
+            "literal" => {
+                let expected: Vec<&PyAny> = schema.get_as_req::<&PyList>(intern!(py, "expected"))?.iter().collect();
+                self.choose(&expected, "literal")
+            }
+            "date" | "datetime" | "timedelta" => self.between(schema, schema_type),
+            "time" => self.time(schema),
+            "list" | "generator" => Ok(PyList::new(py, self.items(schema)?)),
+            "deque" => {
+                let items = PyList::new(py, self.items(schema)?);
+                py.import("collections")?.call_method1(intern!(py, "deque"), (items,))
+            }
+            "set" => Ok(PySet::new(py, &self.items(schema)?)?),
+            "frozenset" => Ok(PyFrozenSet::new(py, &self.items(schema)?)?),
+            "tuple" => match schema.get_as_req::<&str>(intern!(py, "mode"))? {
+                "positional" => {
+                    let mut items: Vec<&PyAny> = Vec::new();
+                    for item_schema in schema.get_as_req::<&PyList>(intern!(py, "items_schema"))? {
+                        items.push(self.generate(item_schema.cast_as()?)?);
+                    }
+                    if let Some(extra_schema) = schema.get_as::<&PyDict>(intern!(py, "extra_schema"))? {
+                        for _ in 0..self.extra_items() {
+                            items.push(self.generate(extra_schema)?);
+                        }
+                    }
+                    Ok(PyTuple::new(py, items))
+                }
+                _ => Ok(PyTuple::new(py, self.items(schema)?)),
+            },
+            "dict" | "ordered-dict" | "defaultdict" | "counter" => {
+                let (min_items, max_items) = self.lengths(schema, "min_items", "max_items")?;
+                let count = self.rng.range(min_items as i64, max_items as i64);
+                let keys_schema: &PyDict = schema.get_as_req(intern!(py, "keys_schema"))?;
+                let values_schema: Option<&PyDict> = schema.get_as(intern!(py, "values_schema"))?;
+                let dict = PyDict::new(py);
+                for _ in 0..count {
+                    let key = self.generate(keys_schema)?;
+                    match values_schema {
+                        Some(values_schema) => dict.set_item(key, self.generate(values_schema)?)?,
+                        // counter values are always ints
+                        None => dict.set_item(key, self.rng.range(0, 100))?,
+                    }
+                }
+                let collections = py.import("collections")?;
+                match schema_type {
+                    "ordered-dict" => collections.call_method1(intern!(py, "OrderedDict"), (dict,)),
+                    "defaultdict" => {
+                        let default_factory = schema.get_as_req::<&PyAny>(intern!(py, "default_factory"))?;
+                        collections.call_method1(intern!(py, "defaultdict"), (default_factory, dict))
+                    }
+                    "counter" => collections.call_method1(intern!(py, "Counter"), (dict,)),
+                    _ => Ok(dict),
+                }
+            }
+            "typed-dict" => self.typed_dict(schema),
+            "dataclass" => {
+                let dict = PyDict::new(py);
+                if let Some(fields) = schema.get_as::<&PyDict>(intern!(py, "fields"))? {
+                    for (name, field_schema) in fields {
+                        dict.set_item(name, self.generate(field_schema.cast_as()?)?)?;
+                    }
+                }
+                Ok(dict)
+            }
+            "union" => {
+                let choices: Vec<&PyAny> = schema.get_as_req::<&PyList>(intern!(py, "choices"))?.iter().collect();
+                let choice = self.choose(&choices, "union")?;
+                self.generate(choice.cast_as()?)
+            }
+            "tagged-union" => {
+                let choices: Vec<(&PyAny, &PyAny)> =
+                    schema.get_as_req::<&PyDict>(intern!(py, "choices"))?.iter().collect();
+                if choices.is_empty() {
+                    return py_error!(PyValueError; "Unable to generate an example, tagged-union has no choices");
+                }
+                let (tag, choice) = choices[self.rng.below(choices.len())];
+                let value = self.generate(choice.cast_as()?)?;
+                // with a simple discriminator, make sure the tag matches the choice
+                if let Ok(discriminator) = schema.get_as_req::<&PyString>(intern!(py, "discriminator")) {
+                    if let Ok(dict) = value.cast_as::<PyDict>() {
+                        dict.set_item(discriminator, tag)?;
+                    }
+                }
+                Ok(value)
+            }
+            "nullable" => {
+                if self.depth > SHALLOW_DEPTH || self.rng.below(4) == 0 {
+                    Ok(py.None().into_ref(py))
+                } else {
+                    self.generate(schema.get_as_req(intern!(py, "schema"))?)
+                }
+            }
+            "function" => match schema.get_as::<&PyDict>(intern!(py, "schema"))? {
+                Some(sub_schema) => self.generate(sub_schema),
+                None => py_error!(PyValueError; "Unable to generate examples for plain function schemas"),
+            },
+            // there's no guarantee the example satisfies the predicate
+            "predicate" => match schema.get_as::<&PyDict>(intern!(py, "schema"))? {
+                Some(sub_schema) => self.generate(sub_schema),
+                None => Ok(py.None().into_ref(py)),
+            },
+            // later steps generally accept the output of the first step
+            "chain" => self.generate(
+                schema
+                    .get_as_req::<&PyList>(intern!(py, "steps"))?
+                    .get_item(0)?
+                    .cast_as()?,
+            ),
+            "new-class" | "loc-prefix" | "with-default" => self.generate(schema.get_as_req(intern!(py, "schema"))?),
+            "json" => {
+                let value = match schema.get_as::<&PyDict>(intern!(py, "schema"))? {
+                    Some(sub_schema) => self.generate(sub_schema)?,
+                    None => py.None().into_ref(py),
+                };
+                let kwargs = PyDict::new(py);
+                kwargs.set_item(
+                    intern!(py, "default"),
+                    py.import("builtins")?.getattr(intern!(py, "str"))?,
+                )?;
+                py.import("json")?.call_method("dumps", (value,), Some(kwargs))
+            }
+            "definitions" => self.generate(schema.get_as_req(intern!(py, "schema"))?),
+            "recursive-ref" | "definition-ref" => {
+                let schema_ref: &str = schema.get_as_req(intern!(py, "schema_ref"))?;
+                match self.ref_stack.iter().rev().find(|(r, _)| r == schema_ref) {
+                    Some((_, ref_schema)) => {
+                        let ref_schema = *ref_schema;
+                        self.generate(ref_schema)
+                    }
+                    None => py_error!(PyValueError; "Unable to generate an example, unknown ref \"{}\"", schema_ref),
+                }
+            }
+            _ => py_error!(PyValueError; "Unable to generate examples for \"{}\" schemas", schema_type),
+        }
+    }
+
+    fn typed_dict(&mut self, schema: &'py PyDict) -> PyResult<&'py PyAny> {
+        let py = schema.py();
+        let total: bool = schema.get_as(intern!(py, "total"))?.unwrap_or(true);
+        let output = PyDict::new(py);
+        for (name, field) in schema.get_as_req::<&PyDict>(intern!(py, "fields"))? {
+            let field: &PyDict = field.cast_as()?;
+            let required = field.get_as(intern!(py, "required"))?.unwrap_or(total)
+                && !field.contains(intern!(py, "default"))?
+                && !field.contains(intern!(py, "default_factory"))?;
+            if !required && (self.depth > SHALLOW_DEPTH || self.rng.bool()) {
+                continue;
+            }
+            let value = self.generate(field.get_as_req(intern!(py, "schema"))?)?;
+
+            // find where the value goes, fields with an alias can only be populated via the alias by default
+            let path: Vec<&PyAny> = match field.get_as::<&PyAny>(intern!(py, "alias"))? {
+                None => vec![name],
+                Some(alias) => match alias.cast_as::<PyList>() {
+                    Ok(paths) => {
+                        let first = paths.get_item(0)?;
+                        match first.cast_as::<PyList>() {
+                            Ok(first_path) => first_path.iter().collect(),
+                            Err(_) => paths.iter().collect(),
+                        }
+                    }
+                    Err(_) => vec![alias],
+                },
+            };
+            let mut dict = output;
+            for (index, key) in path.iter().enumerate() {
+                if !key.is_instance_of::<PyString>()? {
+                    return py_error!(PyValueError; "Unable to generate examples for alias paths with non-string items");
+                }
+                if index + 1 == path.len() {
+                    dict.set_item(key, value)?;
+                } else {
+                    dict = match dict.get_item(key) {
+                        Some(sub_dict) => sub_dict.cast_as()?,
+                        None => {
+                            let sub_dict = PyDict::new(py);
+                            dict.set_item(key, sub_dict)?;
+                            sub_dict
+                        }
+                    };
+                }
+            }
+        }
+        Ok(output)
+    }
+
+    fn int(&mut self, schema: &'py PyDict) -> PyResult<&'py PyAny> {
+        let py = schema.py();
+        let lo = match (
+            schema.get_as::<i64>(intern!(py, "ge"))?,
+            schema.get_as::<i64>(intern!(py, "gt"))?,
+        ) {
+            (Some(ge), Some(gt)) => Some(ge.max(gt.saturating_add(1))),
+            (ge, gt) => ge.or_else(|| gt.map(|gt| gt.saturating_add(1))),
+        };
+        let hi = match (
+            schema.get_as::<i64>(intern!(py, "le"))?,
+            schema.get_as::<i64>(intern!(py, "lt"))?,
+        ) {
+            (Some(le), Some(lt)) => Some(le.min(lt.saturating_sub(1))),
+            (le, lt) => le.or_else(|| lt.map(|lt| lt.saturating_sub(1))),
+        };
+        let (lo, hi) = match (lo, hi) {
+            (Some(lo), Some(hi)) => (lo, hi),
+            (Some(lo), None) => (lo, lo.saturating_add(1000)),
+            (None, Some(hi)) => (hi.saturating_sub(1000), hi),
+            (None, None) => (-1000, 1000),
+        };
+        let value = match schema.get_as::<i64>(intern!(py, "multiple_of"))? {
+            Some(multiple_of) if multiple_of > 0 => {
+                let lo_k = (lo as f64 / multiple_of as f64).ceil() as i64;
+                let hi_k = (hi as f64 / multiple_of as f64).floor() as i64;
+                self.checked_range(lo_k, hi_k, "int")?.saturating_mul(multiple_of)
+            }
+            _ => self.checked_range(lo, hi, "int")?,
+        };
+        Ok(value.into_py(py).into_ref(py))
+    }
+
+    fn float(&mut self, schema: &'py PyDict) -> PyResult<f64> {
+        let py = schema.py();
+        let lo = schema
+            .get_as::<f64>(intern!(py, "ge"))?
+            .or(schema.get_as::<f64>(intern!(py, "gt"))?);
+        let hi = schema
+            .get_as::<f64>(intern!(py, "le"))?
+            .or(schema.get_as::<f64>(intern!(py, "lt"))?);
+        let (lo, hi) = match (lo, hi) {
+            (Some(lo), Some(hi)) => (lo, hi),
+            (Some(lo), None) => (lo, lo + 1000.0),
+            (None, Some(hi)) => (hi - 1000.0, hi),
+            (None, None) => (-1000.0, 1000.0),
+        };
+        if lo > hi {
+            return py_error!(PyValueError; "Unable to generate an example, float constraints can't be satisfied");
+        }
+        match schema.get_as::<f64>(intern!(py, "multiple_of"))? {
+            Some(multiple_of) if multiple_of > 0.0 => {
+                let lo_k = (lo / multiple_of).ceil() as i64;
+                let hi_k = (hi / multiple_of).floor() as i64;
+                Ok(self.checked_range(lo_k, hi_k, "float")? as f64 * multiple_of)
+            }
+            // the value is rounded so the output is readable, exclusive bounds may be hit in which case the
+            // value is invalid and regenerated
+            _ => Ok(((lo + self.rng.float() * (hi - lo)) * 1000.0).round() / 1000.0),
+        }
+    }
+
+    /// `Decimal` or `Fraction` from a float within the bounds, constructed from the float's string so it's exact
+    fn number_from_str(&mut self, schema: &'py PyDict, module: &str, class_name: &str) -> PyResult<&'py PyAny> {
+        let py = schema.py();
+        let bounds = PyDict::new(py);
+        for key in ["ge", "gt", "le", "lt"] {
+            if let Some(bound) = schema.get_item(key) {
+                let float_bound: f64 = py.import("builtins")?.getattr("float")?.call1((bound,))?.extract()?;
+                bounds.set_item(key, float_bound)?;
+            }
+        }
+        let value = self.float(bounds)?;
+        py.import(module)?.call_method1(class_name, (value.to_string(),))
+    }
+
+    /// A `date`, `datetime` or `timedelta` between the bounds, using python arithmetic so bounds with a timezone
+    /// are respected
+    fn between(&mut self, schema: &'py PyDict, schema_type: &str) -> PyResult<&'py PyAny> {
+        let py = schema.py();
+        let datetime = py.import("datetime")?;
+        let timedelta = datetime.getattr(intern!(py, "timedelta"))?;
+        let lo = schema
+            .get_as::<&PyAny>(intern!(py, "ge"))?
+            .or(schema.get_as::<&PyAny>(intern!(py, "gt"))?);
+        let hi = schema
+            .get_as::<&PyAny>(intern!(py, "le"))?
+            .or(schema.get_as::<&PyAny>(intern!(py, "lt"))?);
+        let default_span = match schema_type {
+            "timedelta" => timedelta.call1((30,))?,
+            _ => timedelta.call1((3650,))?,
+        };
+        let (lo, hi) = match (lo, hi) {
+            (Some(lo), Some(hi)) => (lo, hi),
+            (Some(lo), None) => (lo, lo.call_method1(intern!(py, "__add__"), (default_span,))?),
+            (None, Some(hi)) => (hi.call_method1(intern!(py, "__sub__"), (default_span,))?, hi),
+            (None, None) => {
+                let lo = match schema_type {
+                    "date" => datetime.getattr(intern!(py, "date"))?.call1((2000, 1, 1))?,
+                    "datetime" => datetime.getattr(intern!(py, "datetime"))?.call1((2000, 1, 1))?,
+                    _ => timedelta.call0()?,
+                };
+                (lo, lo.call_method1(intern!(py, "__add__"), (default_span,))?)
+            }
+        };
+        let span = hi.call_method1(intern!(py, "__sub__"), (lo,))?;
+        let offset = span.call_method1(intern!(py, "__mul__"), (self.rng.float(),))?;
+        lo.call_method1(intern!(py, "__add__"), (offset,))
+    }
+
+    fn time(&mut self, schema: &'py PyDict) -> PyResult<&'py PyAny> {
+        let py = schema.py();
+        let seconds = |key: &PyString| -> PyResult<Option<f64>> {
+            match schema.get_as::<&PyAny>(key)? {
+                Some(t) => {
+                    let hour: f64 = t.getattr(intern!(py, "hour"))?.extract()?;
+                    let minute: f64 = t.getattr(intern!(py, "minute"))?.extract()?;
+                    let second: f64 = t.getattr(intern!(py, "second"))?.extract()?;
+                    let microsecond: f64 = t.getattr(intern!(py, "microsecond"))?.extract()?;
+                    Ok(Some(hour * 3600.0 + minute * 60.0 + second + microsecond / 1_000_000.0))
+                }
+                None => Ok(None),
+            }
+        };
+        let lo = seconds(intern!(py, "ge"))?
+            .or(seconds(intern!(py, "gt"))?)
+            .unwrap_or(0.0);
+        let hi = seconds(intern!(py, "le"))?
+            .or(seconds(intern!(py, "lt"))?)
+            .unwrap_or(86399.0);
+        let value = lo + self.rng.float() * (hi - lo).max(0.0);
+        let total_seconds = value as u32;
+        py.import("datetime")?.getattr(intern!(py, "time"))?.call1((
+            total_seconds / 3600,
+            total_seconds % 3600 / 60,
+            total_seconds % 60,
+        ))
+    }
+
+    fn items(&mut self, schema: &'py PyDict) -> PyResult<Vec<&'py PyAny>> {
+        let py = schema.py();
+        let (min_items, max_items) = self.lengths(schema, "min_items", "max_items")?;
+        let count = self.rng.range(min_items as i64, max_items as i64);
+        let items_schema = match schema.get_as::<&PyDict>(intern!(py, "items_schema"))? {
+            Some(items_schema) => items_schema,
+            // `dump_schema` doesn't fill in `items_schema` for all schema types, e.g. `deque`
+            None => {
+                let any_schema = PyDict::new(py);
+                any_schema.set_item(intern!(py, "type"), "any")?;
+                any_schema
+            }
+        };
+        (0..count).map(|_| self.generate(items_schema)).collect()
+    }
+
+    /// min and max lengths for strings or containers, containers nested deeper than `SHALLOW_DEPTH` get the
+    /// minimum length
+    fn lengths(&mut self, schema: &PyDict, min_key: &str, max_key: &str) -> PyResult<(usize, usize)> {
+        let min_length: usize = schema.get_as(PyString::new(schema.py(), min_key))?.unwrap_or(0);
+        let max_length: Option<usize> = schema.get_as(PyString::new(schema.py(), max_key))?;
+        let max_length = match max_length {
+            Some(max_length) if max_length < min_length => {
+                return py_error!(PyValueError; "Unable to generate an example, {} is less than {}", max_key, min_key);
+            }
+            Some(max_length) => max_length.min(min_length + self.extra_items()),
+            None => min_length + self.extra_items(),
+        };
+        Ok((min_length, max_length))
+    }
+
+    fn extra_items(&self) -> usize {
+        if self.depth > SHALLOW_DEPTH {
+            0
+        } else {
+            EXTRA_ITEMS
+        }
+    }
+
+    fn string(&mut self, min_length: usize, max_length: usize, py: Python<'py>) -> &'py PyAny {
+        let length = self.rng.range(min_length as i64, max_length as i64) as usize;
+        let s: String = (0..length).map(|_| self.alphanumeric() as char).collect();
+        PyString::new(py, &s)
+    }
+
+    fn alphanumeric(&mut self) -> u8 {
+        ALPHANUMERIC[self.rng.below(ALPHANUMERIC.len())]
+    }
+
+    fn choose(&mut self, options: &[&'py PyAny], schema_type: &str) -> PyResult<&'py PyAny> {
+        if options.is_empty() {
+            py_error!(PyValueError; "Unable to generate an example, {} has no choices", schema_type)
+        } else {
+            Ok(options[self.rng.below(options.len())])
+        }
+    }
+
+    fn checked_range(&mut self, lo: i64, hi: i64, schema_type: &str) -> PyResult<i64> {
+        if lo > hi {
+            py_error!(PyValueError; "Unable to generate an example, {} constraints can't be satisfied", schema_type)
+        } else {
+            Ok(self.rng.range(lo, hi))
+        }
+    }
+}
+
+/// Small, fast and seedable pseudo-random number generator, see https://prng.di.unimi.it/splitmix64.c
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// random integer in `0..n`, `n` must not be zero
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    /// random integer in `lo..=hi`
+    fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        let span = hi.wrapping_sub(lo) as u64;
+        match span.checked_add(1) {
+            Some(n) => lo.wrapping_add((self.next() % n) as i64),
+            None => self.next() as i64,
+        }
+    }
+
+    /// random float in `0.0..1.0`
+    fn float(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn bool(&mut self) -> bool {
+        self.next() & 1 == 1
+    }
+}
@@ -395,6 +395,14 @@ pub fn float_as_duration(total_seconds: f64) -> Duration {
     Duration::new(positive, days, seconds as u32, microsecond.round() as u32).unwrap()
 }
 
+/// Import the datetime C-API and create the `TzInfo` type object, both of which would otherwise happen
+/// on first use, see `SchemaValidator.warmup`
+pub fn datetime_warmup(py: Python) -> PyResult<()> {
+    PyDelta::new(py, 0, 0, 0, false)?;
+    py.get_type::<TzInfo>();
+    Ok(())
+}
+
 #[pyclass(module = "pydantic_core._pydantic_core", extends = PyTzInfo)]
 #[derive(Clone)]
 #[cfg_attr(debug_assertions, derive(Debug))]
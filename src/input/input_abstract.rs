@@ -8,7 +8,8 @@ use crate::input::datetime::EitherTime;
 
 use super::datetime::{EitherDate, EitherDateTime, EitherTimedelta};
 use super::return_enums::{EitherBytes, EitherString};
-use super::{GenericArguments, GenericCollection, GenericMapping};
+use super::shared::{BytesEncoding, NumberParsing};
+use super::{GenericArguments, GenericCollection, GenericIterator, GenericMapping, JsonInput};
 
 /// all types have three methods: `validate_*`, `strict_*`, `lax_*`
 /// the convention is to either implement:
@@ -25,6 +26,11 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
 
     fn is_none(&self) -> bool;
 
+    /// whether the input is a python object rather than parsed from JSON
+    fn is_python(&self) -> bool {
+        false
+    }
+
     fn is_type(&self, _class: &PyType) -> ValResult<bool> {
         Ok(false)
     }
@@ -34,7 +40,12 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         None
     }
 
-    fn is_instance(&self, _class: &PyType) -> PyResult<bool> {
+    /// `class` may be a type or a tuple of types, as with python's `isinstance`
+    fn is_instance(&self, _class: &PyAny) -> PyResult<bool> {
+        Ok(false)
+    }
+
+    fn is_subclass(&self, _class: &PyType) -> PyResult<bool> {
         Ok(false)
     }
 
@@ -44,6 +55,9 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
 
     fn validate_args(&'a self) -> ValResult<'a, GenericArguments<'a>>;
 
+    /// parse a JSON string (or bytes from python) contained in the input, used by the `json` validator
+    fn parse_json(&'a self) -> ValResult<'a, JsonInput>;
+
     fn validate_str(&'a self, strict: bool) -> ValResult<EitherString<'a>> {
         if strict {
             self.strict_str()
@@ -57,17 +71,17 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         self.strict_str()
     }
 
-    fn validate_bytes(&'a self, strict: bool) -> ValResult<EitherBytes<'a>> {
+    fn validate_bytes(&'a self, strict: bool, encoding: BytesEncoding) -> ValResult<EitherBytes<'a>> {
         if strict {
-            self.strict_bytes()
+            self.strict_bytes(encoding)
         } else {
-            self.lax_bytes()
+            self.lax_bytes(encoding)
         }
     }
-    fn strict_bytes(&'a self) -> ValResult<EitherBytes<'a>>;
+    fn strict_bytes(&'a self, encoding: BytesEncoding) -> ValResult<EitherBytes<'a>>;
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn lax_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
-        self.strict_bytes()
+    fn lax_bytes(&'a self, encoding: BytesEncoding) -> ValResult<EitherBytes<'a>> {
+        self.strict_bytes(encoding)
     }
 
     fn validate_bool(&self, strict: bool) -> ValResult<bool> {
@@ -83,29 +97,29 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         self.strict_bool()
     }
 
-    fn validate_int(&self, strict: bool) -> ValResult<i64> {
+    fn validate_int(&self, strict: bool, parsing: NumberParsing) -> ValResult<i64> {
         if strict {
             self.strict_int()
         } else {
-            self.lax_int()
+            self.lax_int(parsing)
         }
     }
     fn strict_int(&self) -> ValResult<i64>;
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn lax_int(&self) -> ValResult<i64> {
+    fn lax_int(&self, _parsing: NumberParsing) -> ValResult<i64> {
         self.strict_int()
     }
 
-    fn validate_float(&self, strict: bool) -> ValResult<f64> {
+    fn validate_float(&self, strict: bool, parsing: NumberParsing) -> ValResult<f64> {
         if strict {
             self.strict_float()
         } else {
-            self.lax_float()
+            self.lax_float(parsing)
         }
     }
     fn strict_float(&self) -> ValResult<f64>;
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn lax_float(&self) -> ValResult<f64> {
+    fn lax_float(&self, _parsing: NumberParsing) -> ValResult<f64> {
         self.strict_float()
     }
 
@@ -178,6 +192,21 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         self.strict_frozenset()
     }
 
+    fn validate_deque(&'a self, strict: bool) -> ValResult<GenericCollection<'a>> {
+        if strict {
+            self.strict_deque()
+        } else {
+            self.lax_deque()
+        }
+    }
+    fn strict_deque(&'a self) -> ValResult<GenericCollection<'a>>;
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn lax_deque(&'a self) -> ValResult<GenericCollection<'a>> {
+        self.strict_deque()
+    }
+
+    fn validate_iter(&self) -> ValResult<GenericIterator>;
+
     fn validate_date(&self, strict: bool) -> ValResult<EitherDate> {
         if strict {
             self.strict_date()
@@ -4,10 +4,13 @@ use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, float_as_datetime, float_as_duration,
     float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime, EitherTime,
 };
-use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_int};
+use super::shared::{
+    float_as_int, int_as_bool, prefixed_str_as_int, str_as_bool, str_as_bytes, str_as_float, str_as_int, BytesEncoding,
+    FloatToInt, NumberParsing,
+};
 use super::{
-    EitherBytes, EitherString, EitherTimedelta, GenericArguments, GenericCollection, GenericMapping, Input, JsonArgs,
-    JsonInput,
+    EitherBytes, EitherString, EitherTimedelta, GenericArguments, GenericCollection, GenericIterator, GenericMapping,
+    Input, JsonArgs, JsonInput,
 };
 
 impl<'a> Input<'a> for JsonInput {
@@ -53,6 +56,13 @@ impl<'a> Input<'a> for JsonInput {
         }
     }
 
+    fn parse_json(&'a self) -> ValResult<'a, JsonInput> {
+        match self {
+            JsonInput::String(s) => serde_json::from_str(s.as_str()).map_err(|e| map_json_err(self, e)),
+            _ => Err(ValError::new(ErrorKind::JsonType, self)),
+        }
+    }
+
     fn strict_str(&'a self) -> ValResult<EitherString<'a>> {
         match self {
             JsonInput::String(s) => Ok(s.as_str().into()),
@@ -66,15 +76,15 @@ impl<'a> Input<'a> for JsonInput {
         }
     }
 
-    fn validate_bytes(&'a self, _strict: bool) -> ValResult<EitherBytes<'a>> {
+    fn validate_bytes(&'a self, _strict: bool, encoding: BytesEncoding) -> ValResult<EitherBytes<'a>> {
         match self {
-            JsonInput::String(s) => Ok(s.as_bytes().into()),
+            JsonInput::String(s) => str_as_bytes(self, s, encoding),
             _ => Err(ValError::new(ErrorKind::BytesType, self)),
         }
     }
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn strict_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
-        self.validate_bytes(false)
+    fn strict_bytes(&'a self, encoding: BytesEncoding) -> ValResult<EitherBytes<'a>> {
+        self.validate_bytes(false, encoding)
     }
 
     fn strict_bool(&self) -> ValResult<bool> {
@@ -88,7 +98,7 @@ impl<'a> Input<'a> for JsonInput {
             JsonInput::Bool(b) => Ok(*b),
             JsonInput::String(s) => str_as_bool(self, s),
             JsonInput::Int(int) => int_as_bool(self, *int),
-            JsonInput::Float(float) => match float_as_int(self, *float) {
+            JsonInput::Float(float) => match float_as_int(self, *float, FloatToInt::Error) {
                 Ok(int) => int_as_bool(self, int),
                 _ => Err(ValError::new(ErrorKind::BoolType, self)),
             },
@@ -102,15 +112,15 @@ impl<'a> Input<'a> for JsonInput {
             _ => Err(ValError::new(ErrorKind::IntType, self)),
         }
     }
-    fn lax_int(&self) -> ValResult<i64> {
+    fn lax_int(&self, parsing: NumberParsing) -> ValResult<i64> {
         match self {
             JsonInput::Bool(b) => match *b {
                 true => Ok(1),
                 false => Ok(0),
             },
             JsonInput::Int(i) => Ok(*i),
-            JsonInput::Float(f) => float_as_int(self, *f),
-            JsonInput::String(str) => str_as_int(self, str),
+            JsonInput::Float(f) => float_as_int(self, *f, parsing.from_float),
+            JsonInput::String(str) => str_as_int(self, str, parsing),
             _ => Err(ValError::new(ErrorKind::IntType, self)),
         }
     }
@@ -122,7 +132,7 @@ impl<'a> Input<'a> for JsonInput {
             _ => Err(ValError::new(ErrorKind::FloatType, self)),
         }
     }
-    fn lax_float(&self) -> ValResult<f64> {
+    fn lax_float(&self, parsing: NumberParsing) -> ValResult<f64> {
         match self {
             JsonInput::Bool(b) => match *b {
                 true => Ok(1.0),
@@ -130,10 +140,7 @@ impl<'a> Input<'a> for JsonInput {
             },
             JsonInput::Float(f) => Ok(*f),
             JsonInput::Int(i) => Ok(*i as f64),
-            JsonInput::String(str) => match str.parse::<f64>() {
-                Ok(i) => Ok(i),
-                Err(_) => Err(ValError::new(ErrorKind::FloatParsing, self)),
-            },
+            JsonInput::String(str) => str_as_float(self, str, parsing),
             _ => Err(ValError::new(ErrorKind::FloatType, self)),
         }
     }
@@ -196,6 +203,25 @@ impl<'a> Input<'a> for JsonInput {
         self.validate_frozenset(false)
     }
 
+    fn validate_deque(&'a self, _strict: bool) -> ValResult<GenericCollection<'a>> {
+        // as with sets, arrays are the only way to create a deque from JSON
+        match self {
+            JsonInput::Array(a) => Ok(a.into()),
+            _ => Err(ValError::new(ErrorKind::DequeType, self)),
+        }
+    }
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn strict_deque(&'a self) -> ValResult<GenericCollection<'a>> {
+        self.validate_deque(false)
+    }
+
+    fn validate_iter(&self) -> ValResult<GenericIterator> {
+        match self {
+            JsonInput::Array(a) => Ok(GenericIterator::JsonArray(a.clone())),
+            _ => Err(ValError::new(ErrorKind::IterableType, self)),
+        }
+    }
+
     fn validate_date(&self, _strict: bool) -> ValResult<EitherDate> {
         match self {
             JsonInput::String(v) => bytes_as_date(self, v.as_bytes()),
@@ -275,6 +301,10 @@ impl<'a> Input<'a> for String {
         Err(ValError::new(ErrorKind::ArgumentsType, self))
     }
 
+    fn parse_json(&'a self) -> ValResult<'a, JsonInput> {
+        serde_json::from_str(self.as_str()).map_err(|e| map_json_err(self, e))
+    }
+
     fn validate_str(&'a self, _strict: bool) -> ValResult<EitherString<'a>> {
         Ok(self.as_str().into())
     }
@@ -282,12 +312,12 @@ impl<'a> Input<'a> for String {
         self.validate_str(false)
     }
 
-    fn validate_bytes(&'a self, _strict: bool) -> ValResult<EitherBytes<'a>> {
-        Ok(self.as_bytes().into())
+    fn validate_bytes(&'a self, _strict: bool, encoding: BytesEncoding) -> ValResult<EitherBytes<'a>> {
+        str_as_bytes(self, self, encoding)
     }
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn strict_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
-        self.validate_bytes(false)
+    fn strict_bytes(&'a self, encoding: BytesEncoding) -> ValResult<EitherBytes<'a>> {
+        self.validate_bytes(false, encoding)
     }
 
     fn strict_bool(&self) -> ValResult<bool> {
@@ -300,10 +330,13 @@ impl<'a> Input<'a> for String {
     fn strict_int(&self) -> ValResult<i64> {
         Err(ValError::new(ErrorKind::IntType, self))
     }
-    fn lax_int(&self) -> ValResult<i64> {
-        match self.parse() {
-            Ok(i) => Ok(i),
-            Err(_) => Err(ValError::new(ErrorKind::IntParsing, self)),
+    fn lax_int(&self, parsing: NumberParsing) -> ValResult<i64> {
+        if let Some(Ok(i)) = parsing.normalize(self).map(|str| str.parse()) {
+            Ok(i)
+        } else if let Some(i) = parsing.radix_prefix.then(|| prefixed_str_as_int(self)).flatten() {
+            Ok(i)
+        } else {
+            Err(ValError::new(ErrorKind::IntParsing, self))
         }
     }
 
@@ -311,11 +344,8 @@ impl<'a> Input<'a> for String {
     fn strict_float(&self) -> ValResult<f64> {
         Err(ValError::new(ErrorKind::FloatType, self))
     }
-    fn lax_float(&self) -> ValResult<f64> {
-        match self.parse() {
-            Ok(i) => Ok(i),
-            Err(_) => Err(ValError::new(ErrorKind::FloatParsing, self)),
-        }
+    fn lax_float(&self, parsing: NumberParsing) -> ValResult<f64> {
+        str_as_float(self, self, parsing)
     }
 
     #[cfg_attr(has_no_coverage, no_coverage)]
@@ -363,6 +393,20 @@ impl<'a> Input<'a> for String {
         self.validate_frozenset(false)
     }
 
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn validate_deque(&'a self, _strict: bool) -> ValResult<GenericCollection<'a>> {
+        Err(ValError::new(ErrorKind::DequeType, self))
+    }
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn strict_deque(&'a self) -> ValResult<GenericCollection<'a>> {
+        self.validate_deque(false)
+    }
+
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn validate_iter(&self) -> ValResult<GenericIterator> {
+        Err(ValError::new(ErrorKind::IterableType, self))
+    }
+
     fn validate_date(&self, _strict: bool) -> ValResult<EitherDate> {
         bytes_as_date(self, self.as_bytes())
     }
@@ -395,3 +439,13 @@ impl<'a> Input<'a> for String {
         self.validate_timedelta(false)
     }
 }
+
+/// Convert a JSON parsing error to a `ValError`, used by `Input::parse_json`
+pub fn map_json_err<'a>(input: &'a impl Input<'a>, error: serde_json::Error) -> ValError<'a> {
+    ValError::new(
+        ErrorKind::InvalidJson {
+            error: error.to_string(),
+        },
+        input,
+    )
+}
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::str::from_utf8;
 
 use pyo3::exceptions::PyAttributeError;
+use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
 use pyo3::types::{
     PyBool, PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFrozenSet, PyIterator, PyList, PyMapping,
@@ -18,10 +19,15 @@ use super::datetime::{
     float_as_duration, float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime,
     EitherTime,
 };
-use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_int};
+use super::input_json::map_json_err;
+use super::parse_json::parse_json_buffer;
+use super::shared::{
+    float_as_int, int_as_bool, str_as_bool, str_as_bytes, str_as_float, str_as_int, BytesEncoding, FloatToInt,
+    NumberParsing,
+};
 use super::{
     py_string_str, repr_string, EitherBytes, EitherString, EitherTimedelta, GenericArguments, GenericCollection,
-    GenericMapping, Input, PyArgs,
+    GenericIterator, GenericMapping, Input, JsonInput, PyArgs,
 };
 
 #[cfg(not(PyPy))]
@@ -80,14 +86,41 @@ impl<'a> Input<'a> for PyAny {
         self.getattr(name).ok()
     }
 
-    fn is_instance(&self, class: &PyType) -> PyResult<bool> {
+    fn is_python(&self) -> bool {
+        true
+    }
+
+    fn is_instance(&self, class: &PyAny) -> PyResult<bool> {
         self.is_instance(class)
     }
 
+    fn is_subclass(&self, class: &PyType) -> PyResult<bool> {
+        match self.cast_as::<PyType>() {
+            Ok(py_type) => py_type.is_subclass(class),
+            Err(_) => Ok(false),
+        }
+    }
+
     fn callable(&self) -> bool {
         self.is_callable()
     }
 
+    fn parse_json(&'a self) -> ValResult<'a, JsonInput> {
+        let bytes = if let Ok(py_bytes) = self.cast_as::<PyBytes>() {
+            py_bytes.as_bytes()
+        } else if let Ok(py_str) = self.cast_as::<PyString>() {
+            py_str.to_str()?.as_bytes()
+        } else if let Ok(py_byte_array) = self.cast_as::<PyByteArray>() {
+            // Safety: no python code is run while the bytes are parsed, so the bytearray can't be modified
+            unsafe { py_byte_array.as_bytes() }
+        } else if let Some(result) = parse_json_buffer(self) {
+            return result.map_err(|e| map_json_err(self, e));
+        } else {
+            return Err(ValError::new(ErrorKind::JsonType, self));
+        };
+        serde_json::from_slice(bytes).map_err(|e| map_json_err(self, e))
+    }
+
     fn validate_args(&'a self) -> ValResult<'a, GenericArguments<'a>> {
         if let Ok(kwargs) = self.cast_as::<PyDict>() {
             Ok(PyArgs::new(None, Some(kwargs)).into())
@@ -142,7 +175,7 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn strict_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
+    fn strict_bytes(&'a self, _encoding: BytesEncoding) -> ValResult<EitherBytes<'a>> {
         if let Ok(py_bytes) = self.cast_as::<PyBytes>() {
             Ok(py_bytes.into())
         } else {
@@ -150,12 +183,12 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn lax_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
+    fn lax_bytes(&'a self, encoding: BytesEncoding) -> ValResult<EitherBytes<'a>> {
         if let Ok(py_bytes) = self.cast_as::<PyBytes>() {
             Ok(py_bytes.into())
         } else if let Ok(py_str) = self.cast_as::<PyString>() {
             let str = py_string_str(py_str)?;
-            Ok(str.as_bytes().into())
+            str_as_bytes(self, str, encoding)
         } else if let Ok(py_byte_array) = self.cast_as::<PyByteArray>() {
             Ok(py_byte_array.to_vec().into())
         } else {
@@ -179,7 +212,7 @@ impl<'a> Input<'a> for PyAny {
         } else if let Ok(int) = self.extract::<i64>() {
             int_as_bool(self, int)
         } else if let Ok(float) = self.extract::<f64>() {
-            match float_as_int(self, float) {
+            match float_as_int(self, float, FloatToInt::Error) {
                 Ok(int) => int_as_bool(self, int),
                 _ => Err(ValError::new(ErrorKind::BoolType, self)),
             }
@@ -199,13 +232,13 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn lax_int(&self) -> ValResult<i64> {
+    fn lax_int(&self, parsing: NumberParsing) -> ValResult<i64> {
         if let Ok(int) = self.extract::<i64>() {
             Ok(int)
         } else if let Some(cow_str) = maybe_as_string(self, ErrorKind::IntParsing)? {
-            str_as_int(self, &cow_str)
-        } else if let Ok(float) = self.lax_float() {
-            float_as_int(self, float)
+            str_as_int(self, &cow_str, parsing)
+        } else if let Ok(float) = self.lax_float(parsing) {
+            float_as_int(self, float, parsing.from_float)
         } else {
             Err(ValError::new(ErrorKind::IntType, self))
         }
@@ -221,14 +254,11 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn lax_float(&self) -> ValResult<f64> {
+    fn lax_float(&self, parsing: NumberParsing) -> ValResult<f64> {
         if let Ok(float) = self.extract::<f64>() {
             Ok(float)
         } else if let Some(cow_str) = maybe_as_string(self, ErrorKind::FloatParsing)? {
-            match cow_str.as_ref().parse::<f64>() {
-                Ok(i) => Ok(i),
-                Err(_) => Err(ValError::new(ErrorKind::FloatParsing, self)),
-            }
+            str_as_float(self, &cow_str, parsing)
         } else {
             Err(ValError::new(ErrorKind::FloatType, self))
         }
@@ -441,6 +471,49 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
+    fn strict_deque(&'a self) -> ValResult<GenericCollection<'a>> {
+        if self.is_instance(deque_type(self.py()))? {
+            Ok(iterate_to_list(self)?.into())
+        } else {
+            Err(ValError::new(ErrorKind::DequeType, self))
+        }
+    }
+
+    fn lax_deque(&'a self) -> ValResult<GenericCollection<'a>> {
+        if let Ok(list) = self.cast_as::<PyList>() {
+            Ok(list.into())
+        } else if let Ok(tuple) = self.cast_as::<PyTuple>() {
+            Ok(tuple.into())
+        } else if self.cast_as::<PyString>().is_ok()
+            || self.cast_as::<PyBytes>().is_ok()
+            || self.cast_as::<PyByteArray>().is_ok()
+            || self.cast_as::<PyDict>().is_ok()
+        {
+            // strings and dicts are iterable, but almost certainly not meant to be treated as a sequence here
+            Err(ValError::new(ErrorKind::DequeType, self))
+        } else if self.iter().is_ok() {
+            // any other iterable, including deques, sets and generators
+            Ok(iterate_to_list(self)?.into())
+        } else {
+            Err(ValError::new(ErrorKind::DequeType, self))
+        }
+    }
+
+    fn validate_iter(&self) -> ValResult<GenericIterator> {
+        if self.cast_as::<PyString>().is_ok()
+            || self.cast_as::<PyBytes>().is_ok()
+            || self.cast_as::<PyByteArray>().is_ok()
+            || self.cast_as::<PyDict>().is_ok()
+        {
+            // as with deques, strings and dicts are iterable but almost certainly not meant to be iterated here
+            return Err(ValError::new(ErrorKind::IterableType, self));
+        }
+        match self.iter() {
+            Ok(iter) => Ok(GenericIterator::PyIterator(iter.into_py(self.py()))),
+            Err(_) => Err(ValError::new(ErrorKind::IterableType, self)),
+        }
+    }
+
     fn strict_date(&self) -> ValResult<EitherDate> {
         if self.cast_as::<PyDateTime>().is_ok() {
             // have to check if it's a datetime first, otherwise the line below converts to a date
@@ -623,3 +696,28 @@ fn maybe_as_string(v: &PyAny, unicode_error: ErrorKind) -> ValResult<Option<Cow<
         Ok(None)
     }
 }
+
+static DEQUE_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+/// Look up the `deque` type which would otherwise happen the first time a python input is checked for it,
+/// see `SchemaValidator.warmup`
+pub fn input_python_warmup(py: Python) {
+    deque_type(py);
+}
+
+fn deque_type(py: Python) -> &PyType {
+    DEQUE_TYPE
+        .get_or_init(py, || {
+            let deque = py.import("collections").and_then(|m| m.getattr("deque")).unwrap();
+            deque.cast_as::<PyType>().unwrap().into()
+        })
+        .as_ref(py)
+}
+
+fn iterate_to_list<'a>(obj: &'a PyAny) -> ValResult<'a, &'a PyList> {
+    let vec = obj
+        .iter()
+        .and_then(|iter| iter.collect::<PyResult<Vec<_>>>())
+        .map_err(|_| ValError::new(ErrorKind::IterationError, obj))?;
+    Ok(PyList::new(obj.py(), vec))
+}
@@ -8,12 +8,18 @@ mod parse_json;
 mod return_enums;
 mod shared;
 
-pub use datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta};
+pub use datetime::{datetime_warmup, EitherDate, EitherDateTime, EitherTime, EitherTimedelta};
 pub use input_abstract::Input;
-pub use parse_json::{JsonInput, JsonObject};
+pub use input_python::input_python_warmup;
+pub use parse_json::{
+    parse_json_buffer, parse_json_parallel, parse_json_slice, parse_json_stream, string_data_as_json, JsonInput,
+    JsonObject,
+};
 pub use return_enums::{
-    py_string_str, EitherBytes, EitherString, GenericArguments, GenericCollection, GenericMapping, JsonArgs, PyArgs,
+    py_string_str, EitherBytes, EitherString, GenericArguments, GenericCollection, GenericIterator, GenericMapping,
+    JsonArgs, PyArgs,
 };
+pub use shared::{BytesEncoding, NumberParsing};
 
 pub fn repr_string(v: &PyAny) -> PyResult<String> {
     v.repr()?.extract()
@@ -1,10 +1,24 @@
+use std::borrow::Cow;
 use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
 
 use indexmap::IndexMap;
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::{PyRuntimeError, PyTypeError};
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyList, PyMapping, PyString, PyTuple};
+use rayon::prelude::*;
 use serde::de::{Deserialize, DeserializeSeed, Error as SerdeError, MapAccess, SeqAccess, Visitor};
 
+use crate::build_tools::py_error;
+use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
+
+use super::Input;
+
 /// similar to serde `Value` but with int and float split
 #[derive(Clone, Debug)]
 pub enum JsonInput {
@@ -171,3 +185,231 @@ impl<'de> Visitor<'de> for KeyDeserializer {
         unreachable!()
     }
 }
+
+/// Parse JSON directly from any object exposing a contiguous buffer of bytes, e.g. `memoryview`, without
+/// copying the data first, `None` if the object doesn't expose such a buffer
+pub fn parse_json_buffer(input: &PyAny) -> Option<serde_json::Result<JsonInput>> {
+    let buffer = PyBuffer::<u8>::get(input).ok()?;
+    if !buffer.is_c_contiguous() {
+        return None;
+    }
+    // Safety: the buffer is held until parsing is complete and no python code is run while the bytes are parsed,
+    // so the data can't be modified or released
+    let bytes = unsafe { std::slice::from_raw_parts(buffer.buf_ptr() as *const u8, buffer.len_bytes()) };
+    Some(serde_json::from_slice(bytes))
+}
+
+/// JSON `bytes` and `str` at least this long are parsed with the GIL released, for shorter inputs releasing and
+/// reacquiring the GIL would cost more than it saves
+const RELEASE_GIL_LENGTH: usize = 16 * 1024;
+
+/// Parse the contents of an immutable python object, e.g. `bytes` or `str`, the GIL is released while parsing long
+/// inputs so other threads can run. Validation of the parsed input still holds the GIL since validators build
+/// python objects as they go
+pub fn parse_json_slice(py: Python, bytes: &[u8]) -> serde_json::Result<JsonInput> {
+    match bytes.len() >= RELEASE_GIL_LENGTH {
+        true => py.allow_threads(|| serde_json::from_slice(bytes)),
+        false => serde_json::from_slice(bytes),
+    }
+}
+
+/// Parse many JSON inputs with the GIL released, in parallel on rayon's global thread pool which is reused
+/// between calls so each batch doesn't pay for starting threads.
+/// `bytes` and `str` are immutable so they're read in place, other inputs are copied first since they could
+/// be modified by python code while the GIL is released.
+/// Items which aren't `str`, `bytes`, `bytearray` or a buffer give `None` rather than failing the whole batch
+pub fn parse_json_parallel(py: Python, inputs: &[&PyAny]) -> PyResult<Vec<Option<serde_json::Result<JsonInput>>>> {
+    let payloads = inputs
+        .iter()
+        .map(|input| json_payload(input))
+        .collect::<PyResult<Vec<_>>>()?;
+    let parsed = py.allow_threads(|| {
+        // rayon resumes a panic from a worker thread in this thread, it's caught so it can be raised as an error
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            payloads
+                .par_iter()
+                .map(|p| p.as_ref().map(|p| serde_json::from_slice(p)))
+                .collect::<Vec<_>>()
+        }))
+    });
+    match parsed {
+        Ok(parsed) => Ok(parsed),
+        Err(_) => py_error!(PyRuntimeError; "JSON parsing thread panicked"),
+    }
+}
+
+fn json_payload(input: &PyAny) -> PyResult<Option<Cow<[u8]>>> {
+    if let Ok(py_bytes) = input.cast_as::<PyBytes>() {
+        Ok(Some(Cow::Borrowed(py_bytes.as_bytes())))
+    } else if let Ok(py_str) = input.cast_as::<PyString>() {
+        Ok(Some(Cow::Borrowed(py_str.to_str()?.as_bytes())))
+    } else if let Ok(py_byte_array) = input.cast_as::<PyByteArray>() {
+        Ok(Some(Cow::Owned(py_byte_array.to_vec())))
+    } else if let Ok(buffer) = PyBuffer::<u8>::get(input) {
+        Ok(Some(Cow::Owned(buffer.to_vec(input.py())?)))
+    } else {
+        Ok(None)
+    }
+}
+
+/// the number of bytes requested by each `read()` call when parsing JSON from a python file-like object
+const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Parse JSON incrementally from a path (`str` or `os.PathLike`) or a file-like object with a `read()` method,
+/// so the whole document never needs to be held in memory as text, file-like objects may be opened in text or
+/// binary mode
+pub fn parse_json_stream(input: &PyAny) -> PyResult<serde_json::Result<JsonInput>> {
+    let py = input.py();
+    if input.hasattr(intern!(py, "read"))? {
+        let mut reader = PyFileReader::new(input);
+        let result = serde_json::from_reader(&mut reader);
+        // errors raised by `read()` take precedence over the resulting parsing error
+        match reader.error {
+            Some(err) => Err(err),
+            None => Ok(result),
+        }
+    } else if let Ok(path) = input.extract::<PathBuf>() {
+        let file = File::open(path)?;
+        Ok(serde_json::from_reader(BufReader::new(file)))
+    } else {
+        let input_type = input.get_type().name().unwrap_or("unknown");
+        py_error!(PyTypeError; "JSON stream input should be a path or a file-like object, not {}", input_type)
+    }
+}
+
+struct PyFileReader<'py> {
+    file: &'py PyAny,
+    chunk: Vec<u8>,
+    position: usize,
+    error: Option<PyErr>,
+}
+
+impl<'py> PyFileReader<'py> {
+    fn new(file: &'py PyAny) -> Self {
+        Self {
+            file,
+            chunk: Vec::new(),
+            position: 0,
+            error: None,
+        }
+    }
+
+    /// Read the next chunk from the file, returns false at the end of the file
+    fn read_chunk(&mut self) -> PyResult<bool> {
+        let py = self.file.py();
+        let data = self.file.call_method1(intern!(py, "read"), (STREAM_CHUNK_SIZE,))?;
+        self.chunk.clear();
+        self.position = 0;
+        if let Ok(py_bytes) = data.cast_as::<PyBytes>() {
+            self.chunk.extend_from_slice(py_bytes.as_bytes());
+        } else if let Ok(py_str) = data.cast_as::<PyString>() {
+            self.chunk.extend_from_slice(py_str.to_str()?.as_bytes());
+        } else {
+            let data_type = data.get_type().name().unwrap_or("unknown");
+            return py_error!(PyTypeError; "read() should return str or bytes, not {}", data_type);
+        }
+        Ok(!self.chunk.is_empty())
+    }
+}
+
+impl<'py> Read for PyFileReader<'py> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position >= self.chunk.len() {
+            match self.read_chunk() {
+                Ok(true) => (),
+                Ok(false) => return Ok(0),
+                Err(err) => {
+                    self.error = Some(err);
+                    return Err(io::Error::other("error reading from file"));
+                }
+            }
+        }
+        let remaining = &self.chunk[self.position..];
+        let length = remaining.len().min(buf.len());
+        buf[..length].copy_from_slice(&remaining[..length]);
+        self.position += length;
+        Ok(length)
+    }
+}
+
+/// Convert python data where every leaf value is a string, e.g. form data or environment variables, into
+/// `JsonInput` so it can be validated with the same string coercions as JSON. Other leaf values, e.g. `None`,
+/// and keys which aren't strings are reported as validation errors at their location
+pub fn string_data_as_json(input: &PyAny) -> ValResult<JsonInput> {
+    if let Ok(py_str) = input.cast_as::<PyString>() {
+        Ok(JsonInput::String(py_str.to_str()?.to_string()))
+    } else if let Ok(dict) = input.cast_as::<PyDict>() {
+        string_items_as_json(dict.iter(), dict.len())
+    } else if let Ok(list) = input.cast_as::<PyList>() {
+        string_sequence_as_json(list.iter(), list.len())
+    } else if let Ok(tuple) = input.cast_as::<PyTuple>() {
+        string_sequence_as_json(tuple.iter(), tuple.len())
+    } else if let Ok(mapping) = input.cast_as::<PyMapping>() {
+        // e.g. `os.environ`
+        let items = mapping.items()?;
+        let items = items
+            .iter()?
+            .map(|item| item?.extract::<(&PyAny, &PyAny)>())
+            .collect::<PyResult<Vec<_>>>()?;
+        let length = items.len();
+        string_items_as_json(items.into_iter(), length)
+    } else {
+        Err(ValError::new(ErrorKind::StringDataType, input))
+    }
+}
+
+fn string_sequence_as_json<'py>(items: impl Iterator<Item = &'py PyAny>, length: usize) -> ValResult<'py, JsonInput> {
+    let mut array = JsonArray::with_capacity(length);
+    let mut errors: Vec<ValLineError> = Vec::new();
+    for (index, item) in items.enumerate() {
+        match string_data_as_json(item) {
+            Ok(item) => array.push(item),
+            Err(ValError::LineErrors(line_errors)) => {
+                errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    match errors.is_empty() {
+        true => Ok(JsonInput::Array(array)),
+        false => Err(ValError::LineErrors(errors)),
+    }
+}
+
+fn string_items_as_json<'py>(
+    items: impl Iterator<Item = (&'py PyAny, &'py PyAny)>,
+    length: usize,
+) -> ValResult<'py, JsonInput> {
+    let mut object = JsonObject::with_capacity(length);
+    let mut errors: Vec<ValLineError> = Vec::new();
+    for (key, value) in items {
+        let key = match key.cast_as::<PyString>() {
+            Ok(key) => key.to_str()?.to_string(),
+            Err(_) => {
+                errors.push(ValLineError::new_with_loc(
+                    ErrorKind::InvalidKey,
+                    key,
+                    key.as_loc_item(),
+                ));
+                continue;
+            }
+        };
+        match string_data_as_json(value) {
+            Ok(value) => {
+                object.insert(key, value);
+            }
+            Err(ValError::LineErrors(line_errors)) => {
+                errors.extend(
+                    line_errors
+                        .into_iter()
+                        .map(|err| err.with_outer_location(key.as_str().into())),
+                );
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    match errors.is_empty() {
+        true => Ok(JsonInput::Object(object)),
+        false => Err(ValError::LineErrors(errors)),
+    }
+}
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyDict, PyFrozenSet, PyList, PySet, PyString, PyTuple};
 
-use crate::errors::{ErrorKind, InputValue, ValError, ValLineError, ValResult};
+use crate::errors::{CurrentLoc, ErrorKind, InputValue, ValError, ValLineError, ValResult};
 use crate::recursion_guard::RecursionGuard;
 use crate::validators::{CombinedValidator, Extra, Validator};
 
@@ -22,6 +22,14 @@ pub enum GenericCollection<'a> {
     JsonArray(&'a [JsonInput]),
 }
 
+/// An owned iterator over the items of an input, used where items are validated as they're consumed,
+/// after validation of the input itself has returned
+#[derive(Debug, Clone)]
+pub enum GenericIterator {
+    PyIterator(PyObject),
+    JsonArray(JsonArray),
+}
+
 macro_rules! derive_from {
     ($enum:ident, $key:ident, $type:ty) => {
         impl<'a> From<&'a $type> for $enum<'a> {
@@ -50,10 +58,33 @@ fn validate_iter_to_vec<'a, 's>(
     let mut output: Vec<PyObject> = Vec::with_capacity(length);
     let mut errors: Vec<ValLineError> = Vec::new();
     for (index, item) in iter.enumerate() {
-        match validator.validate(py, item, extra, slots, recursion_guard) {
+        let salvage_start = extra.salvage.map(|s| s.len());
+        let coercions_start = extra.coercions.map(|c| c.len());
+        let loc = CurrentLoc::index(index, extra.loc);
+        let item_extra = Extra {
+            loc: Some(&loc),
+            ..*extra
+        };
+        let result = validator.validate(py, item, &item_extra, slots, recursion_guard);
+        if let (Some(salvage), Some(start)) = (extra.salvage, salvage_start) {
+            salvage.with_outer_location(start, &index.into());
+        }
+        if let (Some(coercions), Some(start)) = (extra.coercions, coercions_start) {
+            coercions.with_outer_location(start, &index.into());
+        }
+        match result {
             Ok(item) => output.push(item),
             Err(ValError::LineErrors(line_errors)) => {
-                errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                extra.extend_errors(
+                    &mut errors,
+                    line_errors.into_iter().map(|err| err.with_outer_location(index.into())),
+                );
+                if extra.salvage.is_some() {
+                    output.push(py.None());
+                }
+                if extra.fail_fast {
+                    break;
+                }
             }
             Err(err) => return Err(err),
         }
@@ -61,6 +92,9 @@ fn validate_iter_to_vec<'a, 's>(
 
     if errors.is_empty() {
         Ok(output)
+    } else if let Some(salvage) = extra.salvage {
+        salvage.record(py, errors);
+        Ok(output)
     } else {
         Err(ValError::LineErrors(errors))
     }
@@ -112,16 +146,23 @@ impl<'a> GenericCollection<'a> {
         Ok(length)
     }
 
+    /// With `fail_fast` from the schema or `extra`, validation stops at the first invalid item
+    #[allow(clippy::too_many_arguments)]
     pub fn validate_to_vec<'s>(
         &self,
         py: Python<'a>,
         length: Option<usize>,
         validator: &'s CombinedValidator,
+        fail_fast: bool,
         extra: &Extra,
         slots: &'a [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'a, Vec<PyObject>> {
         let length = length.unwrap_or_else(|| self.generic_len());
+        let extra = &Extra {
+            fail_fast: fail_fast || extra.fail_fast,
+            ..*extra
+        };
         match self {
             Self::List(collection) => {
                 validate_iter_to_vec(py, collection.iter(), length, validator, extra, slots, recursion_guard)
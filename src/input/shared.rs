@@ -1,6 +1,13 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::{py_error, schema_or_config};
 use crate::errors::{ErrorKind, ValError, ValResult};
 
-use super::Input;
+use super::{EitherBytes, Input};
 
 #[inline]
 pub fn str_as_bool<'a>(input: &'a impl Input<'a>, str: &str) -> ValResult<'a, bool> {
@@ -36,18 +43,243 @@ pub fn int_as_bool<'a>(input: &'a impl Input<'a>, int: i64) -> ValResult<'a, boo
     }
 }
 
+const DEFAULT_FLOAT_TO_INT_EPSILON: f64 = 1e-9;
+
+/// Options controlling how numbers are parsed from strings in lax mode, all are off by default
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NumberParsing {
+    /// whether ints may have a `0x`, `0o` or `0b` prefix to denote hexadecimal, octal or binary
+    pub radix_prefix: bool,
+    /// `(thousands_separator, decimal_point)` if thousands separators are allowed, e.g. `(',', '.')` for `"1,234.5"`
+    pub separators: Option<(char, char)>,
+    /// whether floats may have a `%` suffix, and if so whether `"85%"` means `0.85` or `85.0`
+    pub percent: Option<PercentMode>,
+    /// `Some(true)` to follow python's `int()`/`float()` rules: surrounding whitespace and underscores between
+    /// digits are allowed, `Some(false)` to also reject a leading `+`
+    pub python_syntax: Option<bool>,
+    /// how floats with a fractional part are converted to ints
+    pub from_float: FloatToInt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FloatToInt {
+    /// floats with a fractional part are an error
+    #[default]
+    Error,
+    /// the fractional part is dropped, `2.7` -> `2`
+    Truncate,
+    /// floats within epsilon of an int are rounded, e.g. `2.0000000001` from a lossy JSON encoder, others are an error
+    RoundWithin(f64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PercentMode {
+    /// `"85%"` -> `0.85`
+    Fraction,
+    /// `"85%"` -> `85.0`
+    Number,
+}
+
+impl NumberParsing {
+    pub fn from_schema(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        let radix_prefix = schema_or_config(
+            schema,
+            config,
+            intern!(py, "allow_radix_prefix"),
+            intern!(py, "int_allow_radix_prefix"),
+        )?
+        .unwrap_or(false);
+
+        let get_char = |schema_key, config_key| -> PyResult<Option<char>> {
+            match schema_or_config::<&str>(schema, config, schema_key, config_key)? {
+                Some(s) => {
+                    let mut chars = s.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => Ok(Some(c)),
+                        _ => py_error!(r#""{}" must be a single character, not "{}""#, schema_key, s),
+                    }
+                }
+                None => Ok(None),
+            }
+        };
+        let thousands_separator = get_char(
+            intern!(py, "thousands_separator"),
+            intern!(py, "number_thousands_separator"),
+        )?;
+        let decimal_point = get_char(intern!(py, "decimal_point"), intern!(py, "number_decimal_point"))?;
+        let separators = match (thousands_separator, decimal_point) {
+            (Some(t), Some(d)) if t == d => return py_error!("thousands_separator and decimal_point must differ"),
+            (Some(t), d) => Some((t, d.unwrap_or('.'))),
+            (None, Some(_)) => return py_error!("decimal_point may only be used with thousands_separator"),
+            (None, None) => None,
+        };
+        let percent = match schema_or_config(
+            schema,
+            config,
+            intern!(py, "percent_mode"),
+            intern!(py, "float_percent_mode"),
+        )? {
+            Some("fraction") => Some(PercentMode::Fraction),
+            Some("number") => Some(PercentMode::Number),
+            Some(s) => return py_error!(r#"Invalid percent_mode: "{}""#, s),
+            None => None,
+        };
+        let python_syntax = schema_or_config(
+            schema,
+            config,
+            intern!(py, "python_syntax"),
+            intern!(py, "number_python_syntax"),
+        )?;
+        let from_float =
+            match schema_or_config(schema, config, intern!(py, "from_float"), intern!(py, "int_from_float"))? {
+                Some("truncate") => FloatToInt::Truncate,
+                Some("round-if-within-epsilon") => {
+                    let epsilon = schema_or_config(
+                        schema,
+                        config,
+                        intern!(py, "from_float_epsilon"),
+                        intern!(py, "int_from_float_epsilon"),
+                    )?
+                    .unwrap_or(DEFAULT_FLOAT_TO_INT_EPSILON);
+                    if !(0.0..0.5).contains(&epsilon) {
+                        return py_error!(
+                            "from_float_epsilon must be at least 0 and less than 0.5, not {}",
+                            epsilon
+                        );
+                    }
+                    FloatToInt::RoundWithin(epsilon)
+                }
+                Some("error") | None => FloatToInt::Error,
+                Some(s) => return py_error!(r#"Invalid from_float: "{}""#, s),
+            };
+        Ok(Self {
+            radix_prefix,
+            separators,
+            percent,
+            python_syntax,
+            from_float,
+        })
+    }
+
+    /// Apply `python_syntax`, then remove thousands separators and replace the decimal point with `.`, returns
+    /// `None` if the separators aren't in groups of three digits, so e.g. `"1,5"` isn't accepted as `15`
+    pub fn normalize<'s>(&self, str: &'s str) -> Option<Cow<'s, str>> {
+        let str = match self.python_syntax {
+            Some(true) => python_literal(str)?,
+            Some(false) if str.starts_with('+') => return None,
+            _ => Cow::Borrowed(str),
+        };
+        let (thousands_separator, decimal_point) = match self.separators {
+            Some(separators) => separators,
+            None => return Some(str),
+        };
+        let str = str.as_ref();
+        let (int_part, fract_part) = match str.split_once(decimal_point) {
+            Some((int_part, fract_part)) => (int_part, Some(fract_part)),
+            None => (str, None),
+        };
+        let mut groups = int_part.split(thousands_separator);
+        let mut normalized = String::with_capacity(str.len());
+        let first = groups.next()?;
+        normalized.push_str(first);
+        let mut grouped = false;
+        for group in groups {
+            if group.len() != 3 || !group.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            normalized.push_str(group);
+            grouped = true;
+        }
+        if grouped {
+            let first_digits = first.trim_start_matches(['+', '-']);
+            if first_digits.is_empty() || first_digits.len() > 3 || !first_digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+        }
+        if let Some(fract_part) = fract_part {
+            normalized.push('.');
+            normalized.push_str(fract_part);
+        }
+        Some(Cow::Owned(normalized))
+    }
+}
+
+/// Strip surrounding whitespace and remove underscores as python's `int()` and `float()` do,
+/// `None` if an underscore isn't between two digits, e.g. `"_1"` or `"1__000"`
+fn python_literal(str: &str) -> Option<Cow<str>> {
+    let str = str.trim();
+    if !str.contains('_') {
+        return Some(Cow::Borrowed(str));
+    }
+    let bytes = str.as_bytes();
+    let is_digit = |index: Option<usize>| index.and_then(|i| bytes.get(i)).is_some_and(u8::is_ascii_digit);
+    let mut normalized = String::with_capacity(str.len());
+    for (index, c) in str.char_indices() {
+        if c != '_' {
+            normalized.push(c);
+        } else if !is_digit(index.checked_sub(1)) || !is_digit(Some(index + 1)) {
+            return None;
+        }
+    }
+    Some(Cow::Owned(normalized))
+}
+
 #[inline]
-pub fn str_as_int<'s, 'l>(input: &'s impl Input<'s>, str: &'l str) -> ValResult<'s, i64> {
+pub fn str_as_int<'s, 'l>(input: &'s impl Input<'s>, str: &'l str, parsing: NumberParsing) -> ValResult<'s, i64> {
+    let str = match parsing.normalize(str) {
+        Some(str) => str,
+        None => return Err(ValError::new(ErrorKind::IntParsing, input)),
+    };
+    let str = str.as_ref();
     if let Ok(i) = str.parse::<i64>() {
         Ok(i)
+    } else if let Some(i) = parsing.radix_prefix.then(|| prefixed_str_as_int(str)).flatten() {
+        Ok(i)
     } else if let Ok(f) = str.parse::<f64>() {
-        float_as_int(input, f)
+        float_as_int(input, f, parsing.from_float)
     } else {
         Err(ValError::new(ErrorKind::IntParsing, input))
     }
 }
 
-pub fn float_as_int<'a>(input: &'a impl Input<'a>, float: f64) -> ValResult<'a, i64> {
+#[inline]
+pub fn str_as_float<'s, 'l>(input: &'s impl Input<'s>, str: &'l str, parsing: NumberParsing) -> ValResult<'s, f64> {
+    let (str, percent) = match parsing.percent.and_then(|mode| Some((str.strip_suffix('%')?, mode))) {
+        Some((number_str, mode)) => (number_str, Some(mode)),
+        None => (str, None),
+    };
+    match parsing.normalize(str).map(|str| str.parse::<f64>()) {
+        Some(Ok(f)) => match percent {
+            Some(PercentMode::Fraction) => Ok(f / 100.0),
+            _ => Ok(f),
+        },
+        _ => Err(ValError::new(ErrorKind::FloatParsing, input)),
+    }
+}
+
+/// Parse strings like `"0x1A"`, `"-0o17"` or `"0b1010"`, `None` if the string isn't a valid prefixed int
+pub fn prefixed_str_as_int(str: &str) -> Option<i64> {
+    let (negative, unsigned) = match str.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, str.strip_prefix('+').unwrap_or(str)),
+    };
+    let radix = match unsigned.get(..2)? {
+        "0x" | "0X" => 16,
+        "0o" | "0O" => 8,
+        "0b" | "0B" => 2,
+        _ => return None,
+    };
+    let digits = &unsigned[2..];
+    // `from_str_radix` would otherwise accept a second sign
+    if digits.starts_with(['+', '-']) {
+        return None;
+    }
+    let int = i64::from_str_radix(digits, radix).ok()?;
+    Some(if negative { -int } else { int })
+}
+
+pub fn float_as_int<'a>(input: &'a impl Input<'a>, float: f64, from_float: FloatToInt) -> ValResult<'a, i64> {
     if float == f64::INFINITY {
         Err(ValError::new(ErrorKind::IntNan { nan_value: "infinity" }, input))
     } else if float == f64::NEG_INFINITY {
@@ -59,9 +291,131 @@ pub fn float_as_int<'a>(input: &'a impl Input<'a>, float: f64) -> ValResult<'a,
         ))
     } else if float.is_nan() {
         Err(ValError::new(ErrorKind::IntNan { nan_value: "NaN" }, input))
-    } else if float % 1.0 != 0.0 {
-        Err(ValError::new(ErrorKind::IntFromFloat, input))
-    } else {
+    } else if float % 1.0 == 0.0 {
         Ok(float as i64)
+    } else {
+        match from_float {
+            FloatToInt::Truncate => Ok(float.trunc() as i64),
+            FloatToInt::RoundWithin(epsilon) if (float - float.round()).abs() <= epsilon => Ok(float.round() as i64),
+            _ => Err(ValError::new(ErrorKind::IntFromFloat, input)),
+        }
+    }
+}
+
+/// How strings are converted to bytes when validating bytes, used for python `str` inputs in lax mode
+/// and for JSON strings in all modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BytesEncoding {
+    Utf8,
+    Ascii,
+    Latin1,
+    Base64,
+    Base64Url,
+}
+
+impl BytesEncoding {
+    pub fn from_str_opt(encoding: Option<&str>) -> PyResult<Self> {
+        match encoding {
+            None | Some("utf8") => Ok(Self::Utf8),
+            Some("ascii") => Ok(Self::Ascii),
+            Some("latin1") => Ok(Self::Latin1),
+            Some("base64") => Ok(Self::Base64),
+            Some("base64url") => Ok(Self::Base64Url),
+            Some(s) => py_error!(r#"Invalid bytes encoding: "{}""#, s),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Utf8 => "utf8",
+            Self::Ascii => "ascii",
+            Self::Latin1 => "latin1",
+            Self::Base64 => "base64",
+            Self::Base64Url => "base64url",
+        }
+    }
+}
+
+pub fn str_as_bytes<'a>(
+    input: &'a impl Input<'a>,
+    str: &'a str,
+    encoding: BytesEncoding,
+) -> ValResult<'a, EitherBytes<'a>> {
+    let encoding_error = |error: String| {
+        Err(ValError::new(
+            ErrorKind::BytesInvalidEncoding {
+                encoding: encoding.name(),
+                error,
+            },
+            input,
+        ))
+    };
+    let invalid_char = |(index, c): (usize, char)| encoding_error(invalid_char_msg(index, c));
+    match encoding {
+        BytesEncoding::Utf8 => Ok(str.as_bytes().into()),
+        BytesEncoding::Ascii => match str.chars().enumerate().find(|(_, c)| !c.is_ascii()) {
+            Some(invalid) => invalid_char(invalid),
+            None => Ok(str.as_bytes().into()),
+        },
+        BytesEncoding::Latin1 => {
+            let mut bytes: Vec<u8> = Vec::with_capacity(str.len());
+            for (index, c) in str.chars().enumerate() {
+                match u8::try_from(c) {
+                    Ok(b) => bytes.push(b),
+                    Err(_) => return invalid_char((index, c)),
+                }
+            }
+            Ok(bytes.into())
+        }
+        BytesEncoding::Base64 => match base64_decode(str, false) {
+            Ok(bytes) => Ok(bytes.into()),
+            Err(error) => encoding_error(error),
+        },
+        BytesEncoding::Base64Url => match base64_decode(str, true) {
+            Ok(bytes) => Ok(bytes.into()),
+            Err(error) => encoding_error(error),
+        },
+    }
+}
+
+fn invalid_char_msg(index: usize, c: char) -> String {
+    format!("invalid character {:?} at index {}", c, index)
+}
+
+/// Decode standard or url-safe base64, padding is optional but if present must be correct,
+/// errors include the index of the offending character
+fn base64_decode(str: &str, url_safe: bool) -> Result<Vec<u8>, String> {
+    let data_len = str.find('=').unwrap_or(str.len());
+    let mut bytes: Vec<u8> = Vec::with_capacity(data_len * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+    for (index, c) in str[..data_len].chars().enumerate() {
+        let sextet = match c {
+            'A'..='Z' => c as u32 - 'A' as u32,
+            'a'..='z' => c as u32 - 'a' as u32 + 26,
+            '0'..='9' => c as u32 - '0' as u32 + 52,
+            '+' if !url_safe => 62,
+            '/' if !url_safe => 63,
+            '-' if url_safe => 62,
+            '_' if url_safe => 63,
+            _ => return Err(invalid_char_msg(index, c)),
+        };
+        buffer = (buffer << 6 | sextet) & 0xfff;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+
+    // all characters before `data_len` are ascii, so byte indexes are the same as character indexes
+    let padding = &str[data_len..];
+    if let Some(index) = padding.find(|c| c != '=') {
+        return Err(format!("invalid padding at index {}", data_len + index));
+    }
+    // padding, if present, must be exactly the characters needed to complete the last group of 4
+    if data_len % 4 == 1 || (!padding.is_empty() && padding.len() != (4 - data_len % 4) % 4) {
+        return Err(format!("invalid padding at index {}", data_len));
     }
+    Ok(bytes)
 }
@@ -8,16 +8,21 @@ use pyo3::prelude::*;
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
 mod build_tools;
+mod coercions;
 mod errors;
+mod example_gen;
 mod input;
 mod lookup_key;
 mod recursion_guard;
+mod salvage;
+mod schema_dump;
+mod schema_visitor;
 mod validators;
 
 // required for benchmarks
 pub use build_tools::SchemaError;
-pub use errors::{PydanticValueError, ValidationError};
-pub use validators::SchemaValidator;
+pub use errors::{PydanticValueError, ValidationError, ValidationResult};
+pub use validators::{JsonLinesIterator, SchemaValidator, SecretBytes, SecretStr, ValidationInfo, ValidatorIterator};
 
 pub fn get_version() -> String {
     let version = env!("CARGO_PKG_VERSION").to_string();
@@ -34,7 +39,15 @@ fn _pydantic_core(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add("__version__", get_version())?;
     m.add_class::<SchemaValidator>()?;
     m.add_class::<ValidationError>()?;
+    m.add_class::<ValidationResult>()?;
     m.add_class::<SchemaError>()?;
     m.add_class::<PydanticValueError>()?;
+    m.add_class::<SecretStr>()?;
+    m.add_class::<SecretBytes>()?;
+    m.add_class::<ValidatorIterator>()?;
+    m.add_class::<JsonLinesIterator>()?;
+    m.add_class::<ValidationInfo>()?;
+    m.add_function(wrap_pyfunction!(schema_visitor::walk_schema, m)?)?;
+    m.add_function(wrap_pyfunction!(schema_visitor::partial_schema, m)?)?;
     Ok(())
 }
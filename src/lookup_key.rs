@@ -1,8 +1,10 @@
 use std::fmt;
 
 use pyo3::exceptions::{PyAttributeError, PyTypeError};
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
+use regex::Regex;
 
 use crate::build_tools::py_error;
 use crate::input::{JsonInput, JsonObject};
@@ -80,6 +82,32 @@ impl LookupKey {
         LookupKey::Simple(key.to_string(), py_string!(py, key))
     }
 
+    /// The paths tried in order to find a value, in the same form as alias paths in the schema,
+    /// e.g. `[['foo'], ['bar', 0, {'regex': '(\\d+)'}]]`
+    pub fn py_paths(&self, py: Python) -> PyResult<PyObject> {
+        let paths: Vec<Vec<PyObject>> = match self {
+            Self::Simple(_, py_key) => vec![vec![py_key.to_object(py)]],
+            Self::Choice(_, _, py_key1, py_key2) => vec![vec![py_key1.to_object(py)], vec![py_key2.to_object(py)]],
+            Self::PathChoices(path_choices) => path_choices
+                .iter()
+                .map(|path| path.iter().map(|item| item.py_path_item(py)).collect())
+                .collect::<PyResult<_>>()?,
+        };
+        Ok(paths.to_object(py))
+    }
+
+    /// Split the key into one key per choice, used to find inputs which provide a value via more than one choice
+    pub fn split_choices(&self) -> Vec<Self> {
+        match self {
+            Self::Simple(..) => vec![self.clone()],
+            Self::Choice(key1, key2, py_key1, py_key2) => vec![
+                Self::Simple(key1.clone(), py_key1.clone()),
+                Self::Simple(key2.clone(), py_key2.clone()),
+            ],
+            Self::PathChoices(paths) => paths.iter().map(|path| Self::PathChoices(vec![path.clone()])).collect(),
+        }
+    }
+
     fn path_choice(obj: &PyAny) -> PyResult<Path> {
         let path = obj
             .extract::<&PyList>()?
@@ -124,15 +152,55 @@ impl LookupKey {
         }
     }
 
-    pub fn py_get_attr<'data, 's>(&'s self, obj: &'data PyAny) -> PyResult<Option<(&'s str, &'data PyAny)>> {
+    /// Collect the items of a flat dict whose keys are this key followed by `delimiter` into a new dict with that
+    /// prefix removed, e.g. `{'DB__HOST': 'x'}` gives `{'HOST': 'x'}` for the key `DB` and the delimiter `__`.
+    /// Only keys which are looked up directly in the dict are used, not paths of more than one item
+    pub fn py_get_nested<'data, 's>(
+        &'s self,
+        dict: &'data PyDict,
+        delimiter: &str,
+    ) -> PyResult<Option<(&'s str, &'data PyDict)>> {
+        let keys: Vec<&str> = match self {
+            LookupKey::Simple(key, _) => vec![key],
+            LookupKey::Choice(key1, key2, _, _) => vec![key1, key2],
+            LookupKey::PathChoices(path_choices) => path_choices
+                .iter()
+                .filter(|path| path.len() == 1)
+                .map(|path| path[0].get_key())
+                .collect(),
+        };
+        for key in keys {
+            let prefix = format!("{}{}", key, delimiter);
+            let nested = PyDict::new(dict.py());
+            for (raw_key, value) in dict.iter() {
+                if let Ok(py_str) = raw_key.cast_as::<PyString>() {
+                    if let Some(sub_key) = py_str.to_str()?.strip_prefix(&prefix) {
+                        nested.set_item(sub_key, value)?;
+                    }
+                }
+            }
+            if !nested.is_empty() {
+                return Ok(Some((key, nested)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// `missing_errors` are the exception types (a type or tuple of types) which mean the attribute is treated
+    /// as missing when raised by `getattr`, as well as `AttributeError`
+    pub fn py_get_attr<'data, 's>(
+        &'s self,
+        obj: &'data PyAny,
+        missing_errors: Option<&PyAny>,
+    ) -> PyResult<Option<(&'s str, &'data PyAny)>> {
         match self {
-            LookupKey::Simple(key, py_key) => match py_get_attrs(obj, py_key)? {
+            LookupKey::Simple(key, py_key) => match py_get_attrs(obj, py_key, missing_errors)? {
                 Some(value) => Ok(Some((key, value))),
                 None => Ok(None),
             },
-            LookupKey::Choice(key1, key2, py_key1, py_key2) => match py_get_attrs(obj, py_key1)? {
+            LookupKey::Choice(key1, key2, py_key1, py_key2) => match py_get_attrs(obj, py_key1, missing_errors)? {
                 Some(value) => Ok(Some((key1, value))),
-                None => match py_get_attrs(obj, py_key2)? {
+                None => match py_get_attrs(obj, py_key2, missing_errors)? {
                     Some(value) => Ok(Some((key2, value))),
                     None => Ok(None),
                 },
@@ -143,7 +211,7 @@ impl LookupKey {
                     // so we have to loop manually
                     let mut v = obj;
                     for loc in path {
-                        v = match loc.py_get_attrs(v) {
+                        v = match loc.py_get_attrs(v, missing_errors) {
                             Ok(Some(v)) => v,
                             Ok(None) => {
                                 continue 'outer;
@@ -176,6 +244,9 @@ impl LookupKey {
             },
             LookupKey::PathChoices(path_choices) => {
                 for path in path_choices {
+                    if path.iter().any(|loc| matches!(loc, PathItem::Regex(_))) {
+                        return py_error!(PyTypeError; "Regex alias path items are only supported for python input");
+                    }
                     let mut path_iter = path.iter();
 
                     // first step is different from the rest as we already know dict is JsonObject
@@ -208,6 +279,9 @@ pub enum PathItem {
     S(String, Py<PyString>),
     /// integer key, used to get items from a list, tuple OR a dict with int keys `Dict[int, ...]` (python only)
     I(usize),
+    /// regex applied to a string, the first capture group (or the whole match if there are no groups) is used,
+    /// e.g. to get `'1234'` from `'user:1234'` (python only)
+    Regex(Regex),
 }
 
 impl fmt::Display for PathItem {
@@ -215,6 +289,7 @@ impl fmt::Display for PathItem {
         match self {
             Self::S(key, _) => write!(f, "'{}'", key),
             Self::I(key) => write!(f, "{}", key),
+            Self::Regex(regex) => write!(f, "/{}/", regex),
         }
     }
 }
@@ -224,6 +299,7 @@ impl ToPyObject for PathItem {
         match self {
             Self::S(_, val) => val.to_object(py),
             Self::I(val) => val.to_object(py),
+            Self::Regex(regex) => regex.as_str().to_object(py),
         }
     }
 }
@@ -235,10 +311,33 @@ fn path_to_string(path: &Path) -> String {
 }
 
 impl PathItem {
+    fn py_path_item(&self, py: Python) -> PyResult<PyObject> {
+        match self {
+            Self::Regex(regex) => {
+                let dict = PyDict::new(py);
+                dict.set_item(intern!(py, "regex"), regex.as_str())?;
+                Ok(dict.to_object(py))
+            }
+            _ => Ok(self.to_object(py)),
+        }
+    }
+
     pub fn from_py(index: usize, obj: &PyAny) -> PyResult<Self> {
         if let Ok(py_str_key) = obj.cast_as::<PyString>() {
             let str_key = py_str_key.to_str()?.to_string();
             Ok(Self::S(str_key, py_str_key.into()))
+        } else if let Ok(dict) = obj.cast_as::<PyDict>() {
+            if index == 0 {
+                return py_error!(PyTypeError; "The first item in an alias path should be a string");
+            }
+            let pattern: &str = match dict.get_item(intern!(obj.py(), "regex")) {
+                Some(pattern) => pattern.extract()?,
+                None => return py_error!(PyTypeError; "Alias path items which are dicts should have a 'regex' key"),
+            };
+            match Regex::new(pattern) {
+                Ok(regex) => Ok(Self::Regex(regex)),
+                Err(e) => py_error!("Invalid regex in alias path: {}", e),
+            }
         } else {
             let int_key = obj.extract::<usize>()?;
             if index == 0 {
@@ -250,8 +349,12 @@ impl PathItem {
     }
 
     pub fn py_get_item<'a>(&self, py_any: &'a PyAny) -> Option<&'a PyAny> {
-        // we definitely don't want to index strings, so explicitly omit this case
-        if py_any.cast_as::<PyString>().is_ok() {
+        if let Self::Regex(regex) = self {
+            let py_str = py_any.cast_as::<PyString>().ok()?;
+            let captured = regex_capture(regex, py_str.to_str().ok()?)?;
+            Some(PyString::new(py_any.py(), captured))
+        } else if py_any.cast_as::<PyString>().is_ok() {
+            // we definitely don't want to index strings, so explicitly omit this case
             None
         } else {
             // otherwise, blindly try getitem on v since no better logic is realistic
@@ -262,22 +365,22 @@ impl PathItem {
     pub fn get_key(&self) -> &str {
         match self {
             Self::S(key, _) => key.as_str(),
-            Self::I(_) => unreachable!(),
+            _ => unreachable!(),
         }
     }
 
-    pub fn py_get_attrs<'a>(&self, obj: &'a PyAny) -> PyResult<Option<&'a PyAny>> {
+    pub fn py_get_attrs<'a>(&self, obj: &'a PyAny, missing_errors: Option<&PyAny>) -> PyResult<Option<&'a PyAny>> {
         match self {
             Self::S(_, py_key) => {
                 // if obj is a dict, we want to use get_item, not getattr
                 if obj.cast_as::<PyDict>().is_ok() {
                     Ok(self.py_get_item(obj))
                 } else {
-                    py_get_attrs(obj, py_key)
+                    py_get_attrs(obj, py_key, missing_errors)
                 }
             }
             // int, we fall back to py_get_item - e.g. we want to use get_item for a list, tuple, dict, etc.
-            Self::I(_) => Ok(self.py_get_item(obj)),
+            Self::I(_) | Self::Regex(_) => Ok(self.py_get_item(obj)),
         }
     }
 
@@ -300,13 +403,26 @@ impl PathItem {
     }
 }
 
-/// wrapper around `getattr` that returns `Ok(None)` for attribute errors, but returns other errors
+fn regex_capture<'s>(regex: &Regex, value: &'s str) -> Option<&'s str> {
+    let captures = regex.captures(value)?;
+    let group = if regex.captures_len() > 1 { 1 } else { 0 };
+    captures.get(group).map(|m| m.as_str())
+}
+
+/// wrapper around `getattr` that returns `Ok(None)` for attribute errors and `missing_errors`, but returns other errors
 /// We dont check `try_from_attributes` because that check was performed on the top level object before we got here
-fn py_get_attrs<'a, 'b>(obj: &'a PyAny, attr_name: &'b Py<PyString>) -> PyResult<Option<&'a PyAny>> {
-    match obj.getattr(attr_name.extract::<&PyString>(obj.py())?) {
+fn py_get_attrs<'a, 'b>(
+    obj: &'a PyAny,
+    attr_name: &'b Py<PyString>,
+    missing_errors: Option<&PyAny>,
+) -> PyResult<Option<&'a PyAny>> {
+    let py = obj.py();
+    match obj.getattr(attr_name.extract::<&PyString>(py)?) {
         Ok(attr) => Ok(Some(attr)),
         Err(err) => {
-            if err.get_type(obj.py()).is_subclass_of::<PyAttributeError>()? {
+            if err.get_type(py).is_subclass_of::<PyAttributeError>()?
+                || missing_errors.is_some_and(|errors| err.is_instance(py, errors))
+            {
                 Ok(None)
             } else {
                 Err(err)
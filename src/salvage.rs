@@ -0,0 +1,50 @@
+use std::cell::RefCell;
+use std::fmt;
+
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+
+use crate::errors::{LocItem, PyLineError, ValLineError};
+
+/// Collects the errors of invalid items while validating in "salvage" mode, used by
+/// `SchemaValidator.validate_python_salvage`.
+/// Containers which find invalid items record the errors here and return a best-effort value with the invalid items
+/// replaced by a default or `None`, errors from nested containers get their full location via `with_outer_location`
+/// in the same way as line errors.
+#[derive(Default)]
+pub struct Salvage {
+    errors: RefCell<Vec<PyLineError>>,
+}
+
+impl fmt::Debug for Salvage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Salvage({})", self.len())
+    }
+}
+
+impl Salvage {
+    pub fn len(&self) -> usize {
+        self.errors.borrow().len()
+    }
+
+    pub fn record(&self, py: Python, errors: Vec<ValLineError>) {
+        self.errors
+            .borrow_mut()
+            .extend(errors.into_iter().map(|e| e.into_py(py)));
+    }
+
+    /// Add an outer location item to all errors recorded since `start`, see `ValLineError::with_outer_location`
+    pub fn with_outer_location(&self, start: usize, loc_item: &LocItem) {
+        for error in self.errors.borrow_mut()[start..].iter_mut() {
+            error.with_outer_location(loc_item.clone());
+        }
+    }
+
+    pub fn to_py_list(&self, py: Python) -> PyResult<PyObject> {
+        let list = PyList::empty(py);
+        for error in self.errors.borrow().iter() {
+            list.append(error.as_dict(py)?)?;
+        }
+        Ok(list.into_py(py))
+    }
+}
@@ -0,0 +1,318 @@
+use std::collections::HashMap;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyFrozenSet, PyList, PyLong, PySet, PyString, PyTuple};
+use pyo3::{intern, AsPyPointer, PyTypeInfo};
+
+use crate::build_tools::{is_strict, py_error, schema_or_config, schema_or_config_same, SchemaDict};
+use crate::lookup_key::LookupKey;
+use crate::schema_visitor::map_sub_schemas;
+use crate::validators::{build_config, resolve_regex_engine};
+
+/// Export a schema (as returned by the self-schema validator) in a normalized form: string schemas
+/// are expanded to dicts and omitted values with defaults (e.g. `items_schema`) are filled in.
+/// With `inline_refs`, `recursive-ref` and `definition-ref` schemas are replaced by the schema they reference
+/// where that's possible without recursing forever.
+pub fn dump_schema(py: Python, schema: &PyAny, format: &str, inline_refs: bool) -> PyResult<PyObject> {
+    if format != "python" && format != "json" {
+        return py_error!(PyValueError; r#"Invalid format "{}", should be "python" or "json""#, format);
+    }
+    let mut dumper = SchemaDumper::default();
+    let mut output = dumper.normalize(schema)?;
+    if inline_refs {
+        output = dumper.inline(output)?;
+    }
+
+    if format == "json" {
+        let kwargs = PyDict::new(py);
+        kwargs.set_item(
+            intern!(py, "default"),
+            py.import("builtins")?.getattr(intern!(py, "repr"))?,
+        )?;
+        kwargs.set_item(intern!(py, "sort_keys"), true)?;
+        let json = py.import("json")?.call_method("dumps", (output,), Some(kwargs))?;
+        Ok(json.into_py(py))
+    } else {
+        Ok(output.into_py(py))
+    }
+}
+
+/// The schema normalized as with `dump_schema`, then resolved as the validators are built: typed-dict options are
+/// taken from the schema, the config (which models can override) or their default, every field gets `required`,
+/// and `lookup`, the alias compiled to the list of paths tried in order. Refs are kept as they are.
+pub fn resolved_schema(py: Python, schema: &PyAny, config: Option<&PyDict>) -> PyResult<PyObject> {
+    let mut dumper = SchemaDumper::default();
+    let output = dumper.normalize(schema)?;
+    resolve(output, config)?;
+    Ok(output.into_py(py))
+}
+
+/// A canonical representation of the normalized schema and config, validators built from schemas with the same
+/// fingerprint behave the same. Dict keys are sorted, values keep their type so e.g. `1`, `1.0` and `True` differ,
+/// and other python objects like functions and classes are compared by identity.
+pub fn schema_fingerprint(schema: &PyAny, config: Option<&PyDict>) -> PyResult<String> {
+    let mut dumper = SchemaDumper::default();
+    let mut output = String::new();
+    write_canonical(dumper.normalize(schema)?, &mut output)?;
+    output.push('|');
+    if let Some(config) = config {
+        write_canonical(config, &mut output)?;
+    }
+    Ok(output)
+}
+
+fn write_canonical(value: &PyAny, output: &mut String) -> PyResult<()> {
+    if value.is_none() {
+        output.push('N');
+    } else if let Ok(py_bool) = value.cast_as::<PyBool>() {
+        output.push(if py_bool.is_true() { 'T' } else { 'F' });
+    } else if PyString::is_exact_type_of(value) {
+        let s = value.cast_as::<PyString>()?.to_str()?;
+        output.push_str(&format!("s{}:{}", s.len(), s));
+    } else if PyLong::is_exact_type_of(value) || PyFloat::is_exact_type_of(value) || PyBytes::is_exact_type_of(value) {
+        // the repr tells these types apart, e.g. `1`, `1.0` and `b'1'`
+        let repr = value.repr()?.to_str()?;
+        output.push_str(&format!("r{}:{}", repr.len(), repr));
+    } else if let Ok(dict) = value.cast_as::<PyDict>() {
+        let items = dict
+            .iter()
+            .map(|(k, v)| {
+                let mut item = String::new();
+                write_canonical(k, &mut item)?;
+                write_canonical(v, &mut item)?;
+                Ok(item)
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        write_canonical_items('d', items, true, output);
+    } else if let Ok(list) = value.cast_as::<PyList>() {
+        write_canonical_items('l', canonical_items(list.iter())?, false, output);
+    } else if let Ok(tuple) = value.cast_as::<PyTuple>() {
+        write_canonical_items('t', canonical_items(tuple.iter())?, false, output);
+    } else if let Ok(set) = value.cast_as::<PySet>() {
+        write_canonical_items('e', canonical_items(set.iter())?, true, output);
+    } else if let Ok(frozenset) = value.cast_as::<PyFrozenSet>() {
+        write_canonical_items('f', canonical_items(frozenset.iter())?, true, output);
+    } else {
+        output.push_str(&format!("o{:x}", value.as_ptr() as usize));
+    }
+    Ok(())
+}
+
+fn canonical_items<'py>(iter: impl Iterator<Item = &'py PyAny>) -> PyResult<Vec<String>> {
+    iter.map(|item| {
+        let mut output = String::new();
+        write_canonical(item, &mut output)?;
+        Ok(output)
+    })
+    .collect()
+}
+
+fn write_canonical_items(tag: char, mut items: Vec<String>, sort: bool, output: &mut String) {
+    if sort {
+        items.sort();
+    }
+    output.push_str(&format!("{}{}[", tag, items.len()));
+    items.iter().for_each(|item| output.push_str(item));
+    output.push(']');
+}
+
+fn resolve<'py>(schema: &'py PyDict, config: Option<&'py PyDict>) -> PyResult<&'py PyDict> {
+    let py = schema.py();
+    let config = match schema.get_as_req(intern!(py, "type"))? {
+        "new-class" => build_config(py, schema, config)?,
+        "typed-dict" => {
+            resolve_typed_dict(schema, config)?;
+            config
+        }
+        "str" | "secret-str" => {
+            resolve_str(schema, config)?;
+            config
+        }
+        _ => config,
+    };
+    map_sub_schemas(schema, |sub_schema| {
+        resolve(sub_schema.cast_as()?, config).map(|d| d.as_ref())
+    })?;
+    Ok(schema)
+}
+
+/// The `pattern` and the engine chosen to match it, e.g. "auto" resolves to "rust-regex" or "python-re"
+fn resolve_str(schema: &PyDict, config: Option<&PyDict>) -> PyResult<()> {
+    let py = schema.py();
+    let pattern: Option<&str> = schema_or_config(schema, config, intern!(py, "pattern"), intern!(py, "str_pattern"))?;
+    if let Some(pattern) = pattern {
+        let regex_engine = schema_or_config(
+            schema,
+            config,
+            intern!(py, "regex_engine"),
+            intern!(py, "str_regex_engine"),
+        )?;
+        schema.set_item(intern!(py, "pattern"), pattern)?;
+        schema.set_item(
+            intern!(py, "regex_engine"),
+            resolve_regex_engine(pattern, regex_engine)?,
+        )?;
+    }
+    Ok(())
+}
+
+fn resolve_typed_dict(schema: &PyDict, config: Option<&PyDict>) -> PyResult<()> {
+    let py = schema.py();
+    let extra_behavior = schema_or_config(
+        schema,
+        config,
+        intern!(py, "extra_behavior"),
+        intern!(py, "typed_dict_extra_behavior"),
+    )?
+    .unwrap_or("ignore");
+    let total =
+        schema_or_config(schema, config, intern!(py, "total"), intern!(py, "typed_dict_total"))?.unwrap_or(true);
+    let populate_by_name = schema_or_config_same(schema, config, intern!(py, "populate_by_name"))?.unwrap_or(false);
+    let forbid_multiple_values = schema_or_config(
+        schema,
+        config,
+        intern!(py, "forbid_multiple_values"),
+        intern!(py, "typed_dict_forbid_multiple_values"),
+    )?
+    .unwrap_or(false);
+    let error_descriptions = schema_or_config(
+        schema,
+        config,
+        intern!(py, "error_descriptions"),
+        intern!(py, "typed_dict_error_descriptions"),
+    )?
+    .unwrap_or(false);
+    let nested_delimiter: Option<&str> = schema_or_config(
+        schema,
+        config,
+        intern!(py, "nested_delimiter"),
+        intern!(py, "typed_dict_nested_delimiter"),
+    )?;
+
+    schema.set_item(intern!(py, "strict"), is_strict(schema, config)?)?;
+    schema.set_item(intern!(py, "extra_behavior"), extra_behavior)?;
+    schema.set_item(intern!(py, "total"), total)?;
+    schema.set_item(
+        intern!(py, "from_attributes"),
+        schema_or_config_same(schema, config, intern!(py, "from_attributes"))?.unwrap_or(false),
+    )?;
+    schema.set_item(intern!(py, "populate_by_name"), populate_by_name)?;
+    schema.set_item(intern!(py, "forbid_multiple_values"), forbid_multiple_values)?;
+    schema.set_item(intern!(py, "error_descriptions"), error_descriptions)?;
+    if let Some(nested_delimiter) = nested_delimiter {
+        schema.set_item(intern!(py, "nested_delimiter"), nested_delimiter)?;
+    }
+
+    let fields: &PyDict = schema.get_as_req(intern!(py, "fields"))?;
+    for (field_name, field) in fields {
+        let field_name: &str = field_name.extract()?;
+        let field: &PyDict = field.cast_as()?;
+        if !field.contains(intern!(py, "required"))? {
+            // missing fields with a default aren't an error even if the typed-dict is total
+            let field_schema: &PyDict = field.get_as_req(intern!(py, "schema"))?;
+            let has_default = has_default(field)?
+                || (field_schema.get_as_req::<&str>(intern!(py, "type"))? == "with-default"
+                    && has_default(field_schema)?);
+            field.set_item(intern!(py, "required"), total && !has_default)?;
+        }
+        let lookup_key = match field.get_item(intern!(py, "alias")) {
+            Some(alias) => {
+                let alt_alias = if populate_by_name { Some(field_name) } else { None };
+                LookupKey::from_py(py, alias, alt_alias)?
+            }
+            None => LookupKey::from_string(py, field_name),
+        };
+        field.set_item(intern!(py, "lookup"), lookup_key.py_paths(py)?)?;
+    }
+    Ok(())
+}
+
+fn has_default(dict: &PyDict) -> PyResult<bool> {
+    let py = dict.py();
+    Ok(dict.contains(intern!(py, "default"))? || dict.contains(intern!(py, "default_factory"))?)
+}
+
+#[derive(Default)]
+struct SchemaDumper<'py> {
+    definitions: HashMap<String, &'py PyDict>,
+    // refs of the schemas currently being inlined, and whether a `recursive-ref` to them was kept
+    ref_stack: Vec<(String, bool)>,
+}
+
+impl<'py> SchemaDumper<'py> {
+    fn normalize(&mut self, schema: &'py PyAny) -> PyResult<&'py PyDict> {
+        let py = schema.py();
+        let dict = match schema.cast_as::<PyDict>() {
+            Ok(dict) => dict.copy()?,
+            Err(_) => {
+                let dict = PyDict::new(py);
+                dict.set_item(intern!(py, "type"), schema)?;
+                dict
+            }
+        };
+
+        let schema_type: &str = dict.get_as_req(intern!(py, "type"))?;
+        let any_keys: &[&str] = match schema_type {
+            "list" | "set" | "frozenset" => &["items_schema"],
+            "dict" | "defaultdict" | "ordered-dict" => &["keys_schema", "values_schema"],
+            "counter" => &["keys_schema"],
+            "tuple" => {
+                if !dict.contains(intern!(py, "mode"))? {
+                    dict.set_item(intern!(py, "mode"), "variable")?;
+                }
+                match dict.get_as_req(intern!(py, "mode"))? {
+                    "variable" => &["items_schema"],
+                    _ => &[],
+                }
+            }
+            _ => &[],
+        };
+        for key in any_keys {
+            if !dict.contains(key)? {
+                let any_schema = PyDict::new(py);
+                any_schema.set_item(intern!(py, "type"), "any")?;
+                dict.set_item(key, any_schema)?;
+            }
+        }
+
+        map_sub_schemas(dict, |sub_schema| self.normalize(sub_schema).map(|d| d.as_ref()))?;
+        if let Some(schema_ref) = dict.get_as::<String>(intern!(py, "ref"))? {
+            self.definitions.insert(schema_ref, dict);
+        }
+        Ok(dict)
+    }
+
+    fn inline(&mut self, schema: &'py PyDict) -> PyResult<&'py PyDict> {
+        let py = schema.py();
+        let schema_type: &str = schema.get_as_req(intern!(py, "type"))?;
+        if schema_type == "recursive-ref" || schema_type == "definition-ref" {
+            let schema_ref: String = schema.get_as_req(intern!(py, "schema_ref"))?;
+            if let Some(entry) = self.ref_stack.iter_mut().find(|(r, _)| r == &schema_ref) {
+                // the reference is recursive, so it can't be inlined
+                entry.1 = true;
+                return Ok(schema);
+            }
+            return match self.definitions.get(&schema_ref).copied() {
+                Some(definition) => self.inline(definition),
+                None => Ok(schema),
+            };
+        }
+
+        let dict = schema.copy()?;
+        let schema_ref: Option<String> = dict.get_as(intern!(py, "ref"))?;
+        if let Some(ref schema_ref) = schema_ref {
+            self.ref_stack.push((schema_ref.clone(), false));
+        }
+        map_sub_schemas(dict, |sub_schema| {
+            self.inline(sub_schema.cast_as()?).map(|d| d.as_ref())
+        })?;
+        if schema_ref.is_some() {
+            // refs are only kept where a `recursive-ref` still points to them
+            if let Some((_, false)) = self.ref_stack.pop() {
+                dict.del_item(intern!(py, "ref"))?;
+            }
+        }
+        Ok(dict)
+    }
+}
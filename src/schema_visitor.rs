@@ -0,0 +1,399 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+
+use crate::build_tools::{py_error, SchemaDict};
+
+/// Keys which hold a single sub-schema, regardless of the schema type
+const SUB_SCHEMA_KEYS: [&str; 8] = [
+    "schema",
+    "keys_schema",
+    "values_schema",
+    "extra_validator",
+    "extra_schema",
+    "var_args_schema",
+    "var_kwargs_schema",
+    "return_schema",
+];
+
+/// Walk a schema calling `visitor` on every schema in it, children before their parents; `visitor` is called
+/// with a copy of each schema (as a dict, with sub-schemas already visited) and may return a replacement schema,
+/// or `None` to keep it. The input schema is not modified.
+#[pyfunction]
+pub fn walk_schema(py: Python, schema: &PyAny, visitor: &PyAny) -> PyResult<PyObject> {
+    Ok(visit(schema, visitor)?.into_py(py))
+}
+
+fn visit<'py>(schema: &'py PyAny, visitor: &'py PyAny) -> PyResult<&'py PyAny> {
+    let py = schema.py();
+    let dict = match schema.cast_as::<PyDict>() {
+        Ok(dict) => dict.copy()?,
+        Err(_) => {
+            let dict = PyDict::new(py);
+            dict.set_item(intern!(py, "type"), schema)?;
+            dict
+        }
+    };
+    map_sub_schemas(dict, |sub_schema| visit(sub_schema, visitor))?;
+    let new_schema = visitor.call1((dict,))?;
+    if new_schema.is_none() {
+        Ok(dict)
+    } else {
+        Ok(new_schema)
+    }
+}
+
+/// Create a "partial" variant of a model schema, as used for PATCH requests: every field of the model's
+/// typed-dict is optional, accepts `None` and defaults to `None`. Wrapper schemas (`new-class`, `function`)
+/// around the typed-dict are kept, field schemas and any definitions they contain are not changed.
+#[pyfunction]
+pub fn partial_schema(py: Python, schema: &PyAny) -> PyResult<PyObject> {
+    Ok(make_partial(schema)?.into_py(py))
+}
+
+fn make_partial(schema: &PyAny) -> PyResult<&PyDict> {
+    let py = schema.py();
+    let dict = match schema.cast_as::<PyDict>() {
+        Ok(dict) => dict.copy()?,
+        Err(_) => return py_error!(PyValueError; "partial_schema requires a typed-dict schema, got \"{}\"", schema),
+    };
+    let schema_type: &str = dict.get_as_req(intern!(py, "type"))?;
+    match schema_type {
+        "typed-dict" => {
+            let fields: &PyDict = dict.get_as_req(intern!(py, "fields"))?;
+            let new_fields = PyDict::new(py);
+            for (name, field) in fields {
+                let field: &PyDict = field.cast_as::<PyDict>()?.copy()?;
+                let field_schema = field.get_as_req(intern!(py, "schema"))?;
+                if !is_nullable(field_schema)? {
+                    let nullable_schema = PyDict::new(py);
+                    nullable_schema.set_item(intern!(py, "type"), "nullable")?;
+                    nullable_schema.set_item(intern!(py, "schema"), field_schema)?;
+                    field.set_item(intern!(py, "schema"), nullable_schema)?;
+                }
+                if field.contains(intern!(py, "default_factory"))? {
+                    field.del_item(intern!(py, "default_factory"))?;
+                }
+                field.set_item(intern!(py, "required"), false)?;
+                field.set_item(intern!(py, "default"), py.None())?;
+                new_fields.set_item(name, field)?;
+            }
+            dict.set_item(intern!(py, "fields"), new_fields)?;
+        }
+        "new-class" | "function" => match dict.get_item(intern!(py, "schema")) {
+            Some(inner_schema) => dict.set_item(intern!(py, "schema"), make_partial(inner_schema)?)?,
+            None => {
+                return py_error!(PyValueError; "partial_schema requires a typed-dict schema, got \"{}\"", schema_type)
+            }
+        },
+        _ => return py_error!(PyValueError; "partial_schema requires a typed-dict schema, got \"{}\"", schema_type),
+    }
+    Ok(dict)
+}
+
+fn is_nullable(schema: &PyAny) -> PyResult<bool> {
+    let schema_type: &str = match schema.cast_as::<PyDict>() {
+        Ok(dict) => dict.get_as_req(intern!(schema.py(), "type"))?,
+        Err(_) => schema.extract()?,
+    };
+    Ok(matches!(schema_type, "nullable" | "none" | "any"))
+}
+
+/// Collect the metadata of every typed-dict field in `schema` (the field's dict without `schema`, e.g. `description`
+/// and `examples`), keyed by the field's location: a tuple of field names through any nested typed-dicts
+pub fn field_metadata(py: Python, schema: &PyAny) -> PyResult<PyObject> {
+    let output = PyDict::new(py);
+    collect_field_metadata(schema, &mut Vec::new(), output)?;
+    Ok(output.into_py(py))
+}
+
+fn collect_field_metadata<'py>(schema: &'py PyAny, loc: &mut Vec<&'py PyAny>, output: &'py PyDict) -> PyResult<()> {
+    let py = schema.py();
+    let dict = match schema.cast_as::<PyDict>() {
+        Ok(dict) => dict,
+        // bare types like 'int' have no sub-schemas
+        Err(_) => return Ok(()),
+    };
+    if dict.get_as_req::<&str>(intern!(py, "type"))? == "typed-dict" {
+        let fields: &PyDict = dict.get_as_req(intern!(py, "fields"))?;
+        for (name, field) in fields {
+            let field: &PyDict = field.cast_as()?;
+            let metadata = field.copy()?;
+            metadata.del_item(intern!(py, "schema"))?;
+            loc.push(name);
+            output.set_item(PyTuple::new(py, loc.iter()), metadata)?;
+            collect_field_metadata(field.get_as_req(intern!(py, "schema"))?, loc, output)?;
+            loc.pop();
+        }
+        Ok(())
+    } else {
+        // `map_sub_schemas` is only used to visit sub-schemas, the copy it modifies is discarded
+        map_sub_schemas(dict.copy()?, |sub_schema| {
+            collect_field_metadata(sub_schema, loc, output)?;
+            Ok(sub_schema)
+        })
+    }
+}
+
+/// Replace every sub-schema of `schema` with the result of `f`, nested containers (lists of schemas,
+/// typed-dict fields, parameters) are copied rather than modified in place
+pub fn map_sub_schemas<'py, F>(schema: &'py PyDict, mut f: F) -> PyResult<()>
+where
+    F: FnMut(&'py PyAny) -> PyResult<&'py PyAny>,
+{
+    map_sub_schemas_with_path(schema, |_, sub_schema| f(sub_schema))
+}
+
+/// Like `map_sub_schemas` but `f` is also given the path of keys from `schema` to the sub-schema,
+/// e.g. `['fields', 'a', 'schema']` or `['choices', 0]`
+pub fn map_sub_schemas_with_path<'py, F>(schema: &'py PyDict, mut f: F) -> PyResult<()>
+where
+    F: FnMut(&[&'py PyAny], &'py PyAny) -> PyResult<&'py PyAny>,
+{
+    let py = schema.py();
+    let schema_type: &str = schema.get_as_req(intern!(py, "type"))?;
+    for key in SUB_SCHEMA_KEYS {
+        if let Some(sub_schema) = schema.get_item(key) {
+            schema.set_item(key, f(&[PyString::new(py, key)], sub_schema)?)?;
+        }
+    }
+
+    let key = intern!(py, "items_schema");
+    if let Some(items_schema) = schema.get_item(key) {
+        match items_schema.cast_as::<PyList>() {
+            Ok(list) => schema.set_item(key, map_list(key, list, &mut f)?)?,
+            Err(_) => schema.set_item(key, f(&[key], items_schema)?)?,
+        }
+    }
+
+    let key = intern!(py, "choices");
+    if let Some(choices) = schema.get_item(key) {
+        match choices.cast_as::<PyDict>() {
+            Ok(dict) => {
+                let new_choices = PyDict::new(py);
+                for (tag, choice) in dict {
+                    new_choices.set_item(tag, f(&[key, tag], choice)?)?;
+                }
+                schema.set_item(key, new_choices)?;
+            }
+            Err(_) => schema.set_item(key, map_list(key, choices.cast_as()?, &mut f)?)?,
+        }
+    }
+
+    match schema_type {
+        "definitions" => {
+            let key = intern!(py, "definitions");
+            let definitions: &PyList = schema.get_as_req(key)?;
+            schema.set_item(key, map_list(key, definitions, &mut f)?)?;
+        }
+        "chain" => {
+            let key = intern!(py, "steps");
+            let steps: &PyList = schema.get_as_req(key)?;
+            schema.set_item(key, map_list(key, steps, &mut f)?)?;
+        }
+        "typed-dict" => {
+            let key = intern!(py, "fields");
+            let schema_key = intern!(py, "schema");
+            let fields: &PyDict = schema.get_as_req(key)?;
+            let new_fields = PyDict::new(py);
+            for (name, field) in fields {
+                let field: &PyDict = field.cast_as::<PyDict>()?.copy()?;
+                let field_schema = field.get_as_req(schema_key)?;
+                field.set_item(schema_key, f(&[key, name, schema_key], field_schema)?)?;
+                new_fields.set_item(name, field)?;
+            }
+            schema.set_item(key, new_fields)?;
+        }
+        "dataclass" => {
+            // unlike typed-dict, dataclass fields map directly to their schema
+            let key = intern!(py, "fields");
+            if let Some(fields) = schema.get_as::<&PyDict>(key)? {
+                let new_fields = PyDict::new(py);
+                for (name, field_schema) in fields {
+                    new_fields.set_item(name, f(&[key, name], field_schema)?)?;
+                }
+                schema.set_item(key, new_fields)?;
+            }
+        }
+        "arguments" => {
+            let key = intern!(py, "arguments_schema");
+            let schema_key = intern!(py, "schema");
+            let parameters: &PyList = schema.get_as_req(key)?;
+            let mut new_parameters: Vec<&PyDict> = Vec::with_capacity(parameters.len());
+            for (index, parameter) in parameters.iter().enumerate() {
+                let parameter: &PyDict = parameter.cast_as::<PyDict>()?.copy()?;
+                let parameter_schema = parameter.get_as_req(schema_key)?;
+                let path = [key, index.to_object(py).into_ref(py), schema_key];
+                parameter.set_item(schema_key, f(&path, parameter_schema)?)?;
+                new_parameters.push(parameter);
+            }
+            schema.set_item(key, PyList::new(py, new_parameters))?;
+        }
+        "call" => {
+            let key = intern!(py, "arguments_schema");
+            let arguments_schema = schema.get_as_req(key)?;
+            schema.set_item(key, f(&[key], arguments_schema)?)?;
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+fn map_list<'py, F>(key: &'py PyString, list: &'py PyList, f: &mut F) -> PyResult<&'py PyList>
+where
+    F: FnMut(&[&'py PyAny], &'py PyAny) -> PyResult<&'py PyAny>,
+{
+    let py = list.py();
+    let items = list
+        .iter()
+        .enumerate()
+        .map(|(index, item)| f(&[key, index.to_object(py).into_ref(py)], item))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(PyList::new(py, items))
+}
+
+/// Find the path of keys from `schema` to `target`, which is compared by identity, `None` if `target`
+/// isn't part of `schema`
+pub fn schema_path<'py>(schema: &'py PyAny, target: &PyAny) -> PyResult<Option<Vec<&'py PyAny>>> {
+    if schema.is(target) {
+        return Ok(Some(Vec::new()));
+    }
+    let dict = match schema.cast_as::<PyDict>() {
+        Ok(dict) => dict,
+        Err(_) => return Ok(None),
+    };
+    let mut found: Option<Vec<&PyAny>> = None;
+    // as in `collect_field_metadata`, the copy modified by `map_sub_schemas_with_path` is discarded
+    map_sub_schemas_with_path(dict.copy()?, |path, sub_schema| {
+        if found.is_none() {
+            if let Some(sub_path) = schema_path(sub_schema, target)? {
+                found = Some(path.iter().copied().chain(sub_path).collect());
+            }
+        }
+        Ok(sub_schema)
+    })?;
+    Ok(found)
+}
+
+/// Copy `schema` with the schema of the field at `field_path` replaced by `field_schema`, fields are found through
+/// the same schemas as `Validator::replace_field`: typed-dict and dataclass fields, wrapper schemas like `new-class`
+/// and `recursive-ref`s. Only the dicts and lists on the path to the field are copied, `None` if the field isn't found.
+pub fn replace_field_schema<'py>(
+    schema: &'py PyAny,
+    field_path: &[String],
+    field_schema: &'py PyAny,
+) -> PyResult<Option<&'py PyAny>> {
+    match find_field_schema(schema, Vec::new(), schema, field_path)? {
+        Some(path) => replace_at_path(schema, &path, field_schema).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Find the path of keys from `root` to the schema of the field at `field_path` in `schema`, `path` is the path
+/// from `root` to `schema`
+fn find_field_schema<'py>(
+    root: &'py PyAny,
+    mut path: Vec<&'py PyAny>,
+    schema: &'py PyAny,
+    field_path: &[String],
+) -> PyResult<Option<Vec<&'py PyAny>>> {
+    let py = root.py();
+    let dict = match schema.cast_as::<PyDict>() {
+        Ok(dict) => dict,
+        Err(_) => return Ok(None),
+    };
+    let sub_schema = match dict.get_as::<&str>(intern!(py, "type"))? {
+        Some(schema_type @ ("typed-dict" | "dataclass")) => {
+            let (name, rest) = match field_path.split_first() {
+                Some(split) => split,
+                None => return Ok(None),
+            };
+            let field = dict
+                .get_as::<&PyDict>(intern!(py, "fields"))?
+                .and_then(|fields| fields.get_item(name.as_str()));
+            path.push(intern!(py, "fields"));
+            path.push(PyString::new(py, name));
+            let field_schema = match schema_type {
+                "typed-dict" => match field {
+                    Some(field) => {
+                        path.push(intern!(py, "schema"));
+                        field.cast_as::<PyDict>()?.get_item(intern!(py, "schema"))
+                    }
+                    None => return Ok(None),
+                },
+                // the schema of dataclass fields is optional, they're validated as "any" without one
+                _ => field,
+            };
+            if rest.is_empty() {
+                return Ok(Some(path));
+            }
+            match field_schema {
+                Some(field_schema) => return find_field_schema(root, path, field_schema, rest),
+                None => return Ok(None),
+            }
+        }
+        Some("new-class" | "nullable" | "with-default") => {
+            path.push(intern!(py, "schema"));
+            dict.get_item(intern!(py, "schema"))
+        }
+        Some("recursive-ref") => {
+            let schema_ref: &str = dict.get_as_req(intern!(py, "schema_ref"))?;
+            let definition = match find_ref(root, schema_ref)? {
+                Some(definition) => definition,
+                None => return Ok(None),
+            };
+            path = match schema_path(root, definition)? {
+                Some(definition_path) => definition_path,
+                None => return Ok(None),
+            };
+            Some(definition)
+        }
+        _ => None,
+    };
+    match sub_schema {
+        Some(sub_schema) => find_field_schema(root, path, sub_schema, field_path),
+        None => Ok(None),
+    }
+}
+
+/// Find the schema which defines `schema_ref` with its `ref` key
+fn find_ref<'py>(schema: &'py PyAny, schema_ref: &str) -> PyResult<Option<&'py PyAny>> {
+    let dict = match schema.cast_as::<PyDict>() {
+        Ok(dict) => dict,
+        Err(_) => return Ok(None),
+    };
+    if dict.get_as::<&str>(intern!(schema.py(), "ref"))? == Some(schema_ref) {
+        return Ok(Some(schema));
+    }
+    let mut found: Option<&PyAny> = None;
+    map_sub_schemas(dict.copy()?, |sub_schema| {
+        if found.is_none() {
+            found = find_ref(sub_schema, schema_ref)?;
+        }
+        Ok(sub_schema)
+    })?;
+    Ok(found)
+}
+
+/// Copy `container` with the item at `path` set to `value`, only the dicts and lists along `path` are copied
+fn replace_at_path<'py>(container: &'py PyAny, path: &[&'py PyAny], value: &'py PyAny) -> PyResult<&'py PyAny> {
+    let py = container.py();
+    let (key, rest) = match path.split_first() {
+        Some(split) => split,
+        None => return Ok(value),
+    };
+    if let Ok(list) = container.cast_as::<PyList>() {
+        let index: usize = key.extract()?;
+        let copy = PyList::new(py, list);
+        copy.set_item(index, replace_at_path(list.get_item(index)?, rest, value)?)?;
+        Ok(copy)
+    } else {
+        let dict: &PyDict = container.cast_as()?;
+        // dataclass schemas don't need to have "fields"
+        let item = dict.get_item(*key).unwrap_or_else(|| PyDict::new(py));
+        let copy = dict.copy()?;
+        copy.set_item(*key, replace_at_path(item, rest, value)?)?;
+        Ok(copy)
+    }
+}
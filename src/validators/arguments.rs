@@ -4,7 +4,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 
 use crate::build_tools::{py_error, schema_or_config_same, SchemaDict};
-use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
+use crate::errors::{CurrentLoc, ErrorKind, ValError, ValLineError, ValResult};
 use crate::input::{GenericArguments, Input};
 use crate::lookup_key::LookupKey;
 use crate::recursion_guard::RecursionGuard;
@@ -185,10 +185,19 @@ impl Validator for ArgumentsValidator {
                             ));
                         }
                         (Some(pos_value), None) => {
-                            match parameter
+                            let coercions_start = extra.coercions.map(|c| c.len());
+                            let loc = CurrentLoc::index(index, extra.loc);
+                            let arg_extra = Extra {
+                                loc: Some(&loc),
+                                ..*extra
+                            };
+                            let result = parameter
                                 .validator
-                                .validate(py, pos_value, extra, slots, recursion_guard)
-                            {
+                                .validate(py, pos_value, &arg_extra, slots, recursion_guard);
+                            if let (Some(coercions), Some(start)) = (extra.coercions, coercions_start) {
+                                coercions.with_outer_location(start, &index.into());
+                            }
+                            match result {
                                 Ok(value) => output_args.push(value),
                                 Err(ValError::LineErrors(line_errors)) => {
                                     errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
@@ -197,10 +206,19 @@ impl Validator for ArgumentsValidator {
                             }
                         }
                         (None, Some(kw_value)) => {
-                            match parameter
+                            let coercions_start = extra.coercions.map(|c| c.len());
+                            let loc = CurrentLoc::field(&parameter.name, extra.loc);
+                            let arg_extra = Extra {
+                                loc: Some(&loc),
+                                ..*extra
+                            };
+                            let result = parameter
                                 .validator
-                                .validate(py, kw_value, extra, slots, recursion_guard)
-                            {
+                                .validate(py, kw_value, &arg_extra, slots, recursion_guard);
+                            if let (Some(coercions), Some(start)) = (extra.coercions, coercions_start) {
+                                coercions.with_outer_location(start, &parameter.name.clone().into());
+                            }
+                            match result {
                                 Ok(value) => output_kwargs.set_item(parameter.kwarg_key.as_ref().unwrap(), value)?,
                                 Err(ValError::LineErrors(line_errors)) => {
                                     errors.extend(
@@ -244,7 +262,12 @@ impl Validator for ArgumentsValidator {
                     if len > self.positional_params_count {
                         if let Some(ref validator) = self.var_args_validator {
                             for (index, item) in $slice_macro!(args, self.positional_params_count, len).iter().enumerate() {
-                                match validator.validate(py, item, extra, slots, recursion_guard) {
+                                let coercions_start = extra.coercions.map(|c| c.len());
+                                let result = validator.validate(py, item, extra, slots, recursion_guard);
+                                if let (Some(coercions), Some(start)) = (extra.coercions, coercions_start) {
+                                    coercions.with_outer_location(start, &(index + self.positional_params_count).into());
+                                }
+                                match result {
                                     Ok(value) => output_args.push(value),
                                     Err(ValError::LineErrors(line_errors)) => {
                                         errors.extend(
@@ -285,15 +308,22 @@ impl Validator for ArgumentsValidator {
                         };
                         if !used_kwargs.contains(either_str.as_cow()?.as_ref()) {
                             match self.var_kwargs_validator {
-                                Some(ref validator) => match validator.validate(py, value, extra, slots, recursion_guard) {
-                                    Ok(value) => output_kwargs.set_item(either_str.as_py_string(py), value)?,
-                                    Err(ValError::LineErrors(line_errors)) => {
-                                        for err in line_errors {
-                                            errors.push(err.with_outer_location(raw_key.as_loc_item()));
+                                Some(ref validator) => {
+                                    let coercions_start = extra.coercions.map(|c| c.len());
+                                    let result = validator.validate(py, value, extra, slots, recursion_guard);
+                                    if let (Some(coercions), Some(start)) = (extra.coercions, coercions_start) {
+                                        coercions.with_outer_location(start, &raw_key.as_loc_item());
+                                    }
+                                    match result {
+                                        Ok(value) => output_kwargs.set_item(either_str.as_py_string(py), value)?,
+                                        Err(ValError::LineErrors(line_errors)) => {
+                                            for err in line_errors {
+                                                errors.push(err.with_outer_location(raw_key.as_loc_item()));
+                                            }
                                         }
+                                        Err(err) => return Err(err),
                                     }
-                                    Err(err) => return Err(err),
-                                },
+                                }
                                 None => {
                                     errors.push(ValLineError::new_with_loc(
                                         ErrorKind::UnexpectedKeywordArgument,
@@ -321,4 +351,17 @@ impl Validator for ArgumentsValidator {
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.parameters
+            .iter_mut()
+            .try_for_each(|p| p.validator.complete(build_context))?;
+        if let Some(ref mut v) = self.var_args_validator {
+            v.complete(build_context)?;
+        }
+        match self.var_kwargs_validator {
+            Some(ref mut v) => v.complete(build_context),
+            None => Ok(()),
+        }
+    }
 }
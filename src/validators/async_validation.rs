@@ -0,0 +1,63 @@
+use pyo3::prelude::*;
+
+use crate::recursion_guard::RecursionGuard;
+
+use super::{Extra, SchemaValidator, Validator};
+
+/// Called in a worker thread by the event loop's default executor to run validation for
+/// `SchemaValidator.validate_python_async`, so the event loop is free to run coroutines returned by function
+/// validators while validation waits for them
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct AsyncValidation {
+    schema_validator: Py<SchemaValidator>,
+    input: PyObject,
+    strict: Option<bool>,
+    context: Option<PyObject>,
+    event_loop: PyObject,
+}
+
+impl AsyncValidation {
+    pub fn new(
+        schema_validator: Py<SchemaValidator>,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        event_loop: &PyAny,
+    ) -> Self {
+        let py = input.py();
+        Self {
+            schema_validator,
+            input: input.into_py(py),
+            strict,
+            context: context.map(|c| c.into_py(py)),
+            event_loop: event_loop.into_py(py),
+        }
+    }
+}
+
+#[pymethods]
+impl AsyncValidation {
+    fn __call__(&self, py: Python) -> PyResult<PyObject> {
+        let schema_validator = self.schema_validator.borrow(py);
+        let error_limit = schema_validator.error_limit(None);
+        let extra = Extra {
+            event_loop: Some(self.event_loop.as_ref(py)),
+            error_limit: error_limit.as_ref(),
+            slots: Some(&schema_validator.slots),
+            ..Extra::new(self.strict, self.context.as_ref().map(|c| c.as_ref(py)))
+        };
+        schema_validator.run_with_hooks(py, "python", || {
+            schema_validator
+                .validator
+                .validate(
+                    py,
+                    self.input.as_ref(py),
+                    &extra,
+                    &schema_validator.slots,
+                    &mut RecursionGuard::default(),
+                )
+                .map_err(|e| schema_validator.prepare_validation_err(py, e, error_limit.as_ref()))
+        })
+    }
+}
@@ -1,7 +1,9 @@
+use ahash::AHashMap;
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, py_error, schema_or_config};
 use crate::errors::ValResult;
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
@@ -11,6 +13,8 @@ use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 #[derive(Debug, Clone)]
 pub struct BoolValidator {
     strict: bool,
+    // strings accepted in lax mode on top of the built-in ones, keys are lowercase
+    extra_strings: Option<AHashMap<String, bool>>,
 }
 
 impl BuildValidator for BoolValidator {
@@ -21,8 +25,37 @@ impl BuildValidator for BoolValidator {
         config: Option<&PyDict>,
         _build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let true_strings: Option<Vec<&str>> = schema_or_config(
+            schema,
+            config,
+            intern!(py, "true_strings"),
+            intern!(py, "bool_true_strings"),
+        )?;
+        let false_strings: Option<Vec<&str>> = schema_or_config(
+            schema,
+            config,
+            intern!(py, "false_strings"),
+            intern!(py, "bool_false_strings"),
+        )?;
+        let extra_strings = match (true_strings, false_strings) {
+            (None, None) => None,
+            (true_strings, false_strings) => {
+                let mut extra_strings = AHashMap::new();
+                for s in true_strings.unwrap_or_default() {
+                    extra_strings.insert(s.to_lowercase(), true);
+                }
+                for s in false_strings.unwrap_or_default() {
+                    if extra_strings.insert(s.to_lowercase(), false) == Some(true) {
+                        return py_error!(r#""{}" can't be in both true_strings and false_strings"#, s);
+                    }
+                }
+                Some(extra_strings)
+            }
+        };
         Ok(Self {
             strict: is_strict(schema, config)?,
+            extra_strings,
         }
         .into())
     }
@@ -37,9 +70,17 @@ impl Validator for BoolValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
+        if let (false, Some(extra_strings)) = (strict, &self.extra_strings) {
+            if let Ok(either_str) = input.strict_str() {
+                if let Some(bool) = extra_strings.get(&either_str.as_cow()?.to_lowercase()) {
+                    return Ok(bool.into_py(py));
+                }
+            }
+        }
         // TODO in theory this could be quicker if we used PyBool rather than going to a bool
         // and back again, might be worth profiling?
-        Ok(input.validate_bool(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        Ok(input.validate_bool(strict)?.into_py(py))
     }
 
     fn get_name(&self) -> &str {
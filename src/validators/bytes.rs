@@ -2,9 +2,9 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, schema_or_config, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{BytesEncoding, Input};
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -12,6 +12,7 @@ use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 #[derive(Debug, Clone)]
 pub struct BytesValidator {
     strict: bool,
+    encoding: BytesEncoding,
 }
 
 impl BuildValidator for BytesValidator {
@@ -30,6 +31,7 @@ impl BuildValidator for BytesValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                encoding: get_encoding(schema, config)?,
             }
             .into())
         }
@@ -45,7 +47,7 @@ impl Validator for BytesValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let either_bytes = input.validate_bytes(extra.strict.unwrap_or(self.strict))?;
+        let either_bytes = input.validate_bytes(extra.strict.unwrap_or(self.strict), self.encoding)?;
         Ok(either_bytes.into_py(py))
     }
 
@@ -57,6 +59,7 @@ impl Validator for BytesValidator {
 #[derive(Debug, Clone)]
 pub struct BytesConstrainedValidator {
     strict: bool,
+    encoding: BytesEncoding,
     max_length: Option<usize>,
     min_length: Option<usize>,
 }
@@ -70,7 +73,7 @@ impl Validator for BytesConstrainedValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let either_bytes = input.validate_bytes(extra.strict.unwrap_or(self.strict))?;
+        let either_bytes = input.validate_bytes(extra.strict.unwrap_or(self.strict), self.encoding)?;
         let len = either_bytes.len()?;
 
         if let Some(min_length) = self.min_length {
@@ -97,9 +100,16 @@ impl BytesConstrainedValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            encoding: get_encoding(schema, config)?,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
         }
         .into())
     }
 }
+
+fn get_encoding(schema: &PyDict, config: Option<&PyDict>) -> PyResult<BytesEncoding> {
+    let py = schema.py();
+    let encoding = schema_or_config(schema, config, intern!(py, "encoding"), intern!(py, "bytes_encoding"))?;
+    BytesEncoding::from_str_opt(encoding)
+}
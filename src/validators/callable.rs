@@ -1,6 +1,8 @@
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyType};
 
+use crate::build_tools::{is_strict, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
@@ -8,17 +10,24 @@ use crate::recursion_guard::RecursionGuard;
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
-pub struct CallableValidator;
+pub struct CallableValidator {
+    strict: bool,
+    reject_classes: bool,
+}
 
 impl BuildValidator for CallableValidator {
     const EXPECTED_TYPE: &'static str = "callable";
 
     fn build(
-        _schema: &PyDict,
-        _config: Option<&PyDict>,
+        schema: &PyDict,
+        config: Option<&PyDict>,
         _build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
-        Ok(Self.into())
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            reject_classes: schema.get_as(intern!(schema.py(), "reject_classes"))?.unwrap_or(false),
+        }
+        .into())
     }
 }
 
@@ -27,14 +36,19 @@ impl Validator for CallableValidator {
         &'s self,
         py: Python<'data>,
         input: &'data impl Input<'data>,
-        _extra: &Extra,
+        extra: &Extra,
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        match input.callable() {
-            true => Ok(input.to_object(py)),
-            false => Err(ValError::new(ErrorKind::CallableType, input)),
+        if !input.callable() {
+            return Err(ValError::new(ErrorKind::CallableType, input));
+        }
+        let output = input.to_object(py);
+        // classes are callable, but in strict mode they may not be what's wanted
+        if self.reject_classes && extra.strict.unwrap_or(self.strict) && output.as_ref(py).cast_as::<PyType>().is_ok() {
+            return Err(ValError::new(ErrorKind::CallableType, input));
         }
+        Ok(output)
     }
 
     fn get_name(&self) -> &str {
@@ -0,0 +1,80 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::build_tools::{py_error, SchemaDict};
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// Runs each step in order, the output of one step is the input to the next
+#[derive(Debug, Clone)]
+pub struct ChainValidator {
+    steps: Vec<CombinedValidator>,
+    name: String,
+}
+
+impl BuildValidator for ChainValidator {
+    const EXPECTED_TYPE: &'static str = "chain";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let steps: Vec<CombinedValidator> = schema
+            .get_as_req::<&PyList>(intern!(schema.py(), "steps"))?
+            .iter()
+            .map(|step| build_validator(step, config, build_context))
+            .collect::<PyResult<_>>()?;
+
+        match steps.len() {
+            0 => py_error!("One or more steps are required for a chain validator"),
+            // a chain of one step is just that step
+            1 => Ok(steps.into_iter().next().unwrap()),
+            _ => {
+                let descr = steps.iter().map(Validator::get_name).collect::<Vec<_>>().join(",");
+                Ok(Self {
+                    steps,
+                    name: format!("{}[{}]", Self::EXPECTED_TYPE, descr),
+                }
+                .into())
+            }
+        }
+    }
+}
+
+impl Validator for ChainValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let mut steps = self.steps.iter();
+        // the first step gets the input as it is, so it can be JSON
+        let first_step = steps.next().unwrap();
+        let mut value = first_step.validate(py, input, extra, slots, recursion_guard)?;
+        for step in steps {
+            value = step.validate(py, value.into_ref(py), extra, slots, recursion_guard)?;
+        }
+        Ok(value)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn ask(&self, question: &str) -> bool {
+        // the output is that of the last step
+        self.steps.last().is_some_and(|step| step.ask(question))
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.steps.iter_mut().try_for_each(|step| step.complete(build_context))
+    }
+}
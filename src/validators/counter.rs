@@ -0,0 +1,63 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::dict::build_inner_dict_validator;
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct CounterValidator {
+    dict_validator: Box<CombinedValidator>,
+    counter_type: PyObject,
+    name: String,
+}
+
+impl BuildValidator for CounterValidator {
+    const EXPECTED_TYPE: &'static str = "counter";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        // counts are always ints
+        let dict_schema = schema.copy()?;
+        dict_schema.set_item(intern!(py, "values_schema"), "int")?;
+        let dict_validator = build_inner_dict_validator(dict_schema, config, build_context, &[])?;
+
+        let name = format!("{}{}", Self::EXPECTED_TYPE, &dict_validator.get_name()["dict".len()..]);
+        Ok(Self {
+            dict_validator,
+            counter_type: py.import("collections")?.getattr(intern!(py, "Counter"))?.into_py(py),
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for CounterValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let dict = self.dict_validator.validate(py, input, extra, slots, recursion_guard)?;
+        Ok(self.counter_type.call1(py, (dict,))?)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.dict_validator.complete(build_context)
+    }
+}
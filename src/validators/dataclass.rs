@@ -0,0 +1,170 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString, PyType};
+
+use crate::build_tools::{py_error, SchemaDict};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::function::convert_err;
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, FieldReplacement, Validator};
+
+#[derive(Debug, Clone)]
+pub struct DataclassValidator {
+    strict: bool,
+    revalidate: bool,
+    validator: Box<CombinedValidator>,
+    class: Py<PyType>,
+    // names of the fields read from existing instances when they're revalidated
+    field_names: Vec<Py<PyString>>,
+    name: String,
+}
+
+impl BuildValidator for DataclassValidator {
+    const EXPECTED_TYPE: &'static str = "dataclass";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let class: &PyType = schema.get_as_req(intern!(py, "dataclass_type"))?;
+        let name: String = class.getattr(intern!(py, "__name__"))?.extract()?;
+        let dataclasses = py.import("dataclasses")?;
+        if !dataclasses
+            .getattr(intern!(py, "is_dataclass"))?
+            .call1((class,))?
+            .is_true()?
+        {
+            return py_error!("dataclass_type must be a dataclass, got {}", name);
+        }
+        let field_schemas: Option<&PyDict> = schema.get_as(intern!(py, "fields"))?;
+
+        // `__dataclass_fields__` includes `InitVar`s and class variables, unlike `dataclasses.fields()`
+        let missing = dataclasses.getattr(intern!(py, "MISSING"))?;
+        let init_var_type = dataclasses.getattr(intern!(py, "_FIELD_INITVAR"))?;
+        let class_var_type = dataclasses.getattr(intern!(py, "_FIELD_CLASSVAR"))?;
+        let typed_dict_fields = PyDict::new(py);
+        let mut field_names: Vec<Py<PyString>> = Vec::new();
+        let dataclass_fields: &PyDict = class.getattr(intern!(py, "__dataclass_fields__"))?.cast_as()?;
+        for (field_name, field) in dataclass_fields.iter() {
+            let field_name: &PyString = field_name.cast_as()?;
+            let field_type = field.getattr(intern!(py, "_field_type"))?;
+            let is_init_var = field_type.is(init_var_type);
+            if field_type.is(class_var_type) {
+                continue;
+            } else if !is_init_var {
+                field_names.push(field_name.into());
+            }
+            // fields with `init=False` are set by `__init__`, so they can't be provided as input
+            if !is_init_var && !field.getattr(intern!(py, "init"))?.is_true()? {
+                continue;
+            }
+
+            let field_info = PyDict::new(py);
+            match field_schemas.and_then(|fields| fields.get_item(field_name)) {
+                Some(field_schema) => field_info.set_item(intern!(py, "schema"), field_schema)?,
+                None => field_info.set_item(intern!(py, "schema"), "any")?,
+            }
+            // defaults are left for `__init__` to apply
+            let has_default = !field.getattr(intern!(py, "default"))?.is(missing)
+                || !field.getattr(intern!(py, "default_factory"))?.is(missing);
+            field_info.set_item(intern!(py, "required"), !has_default)?;
+            typed_dict_fields.set_item(field_name, field_info)?;
+        }
+
+        let typed_dict_schema = PyDict::new(py);
+        typed_dict_schema.set_item(intern!(py, "type"), "typed-dict")?;
+        typed_dict_schema.set_item(intern!(py, "fields"), typed_dict_fields)?;
+        let validator = build_validator(typed_dict_schema, config, build_context)?;
+
+        Ok(Self {
+            // as with `new-class`, `config.strict` doesn't make this validator strict
+            strict: schema.get_as(intern!(py, "strict"))?.unwrap_or(false),
+            revalidate: schema.get_as(intern!(py, "revalidate_instances"))?.unwrap_or(false),
+            validator: Box::new(validator),
+            class: class.into(),
+            field_names,
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for DataclassValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let class = self.class.as_ref(py);
+        if input.is_instance(class)? {
+            if self.revalidate {
+                let instance = input.to_object(py).into_ref(py);
+                let fields = PyDict::new(py);
+                for field_name in &self.field_names {
+                    fields.set_item(field_name, instance.getattr(field_name.as_ref(py))?)?;
+                }
+                self.create_instance(py, fields.as_ref(), extra, slots, recursion_guard)
+            } else {
+                Ok(input.to_object(py))
+            }
+        } else if extra.strict.unwrap_or(self.strict) {
+            Err(ValError::new(
+                ErrorKind::DataclassType {
+                    class_name: self.name.clone(),
+                },
+                input,
+            ))
+        } else {
+            self.create_instance(py, input, extra, slots, recursion_guard)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn find_field<'s>(
+        &'s self,
+        py: Python,
+        field_path: &[String],
+        slots: &'s [CombinedValidator],
+    ) -> PyResult<Option<(Option<PyObject>, &'s CombinedValidator)>> {
+        self.validator.find_field(py, field_path, slots)
+    }
+
+    fn replace_field(&mut self, field_path: &[String], validator: CombinedValidator) -> FieldReplacement {
+        self.validator.replace_field(field_path, validator)
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.validator.complete(build_context)
+    }
+}
+
+impl DataclassValidator {
+    fn create_instance<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let output = self.validator.validate(py, input, extra, slots, recursion_guard)?;
+        let kwargs: &PyDict = output.as_ref(py).cast_as()?;
+        // `__init__` applies defaults and calls `__post_init__`, errors it raises are treated like those from
+        // function validators
+        self.class
+            .as_ref(py)
+            .call((), Some(kwargs))
+            .map(|instance| instance.into_py(py))
+            .map_err(|err| convert_err(py, err, input))
+    }
+}
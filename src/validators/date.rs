@@ -63,11 +63,12 @@ impl Validator for DateValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let date = match input.validate_date(extra.strict.unwrap_or(self.strict)) {
+        let strict = extra.strict.unwrap_or(self.strict);
+        let date = match input.validate_date(strict) {
             Ok(date) => date,
             // if the date error was an internal error, return that immediately
             Err(ValError::InternalErr(internal_err)) => return Err(ValError::InternalErr(internal_err)),
-            Err(date_err) => match self.strict {
+            Err(date_err) => match strict {
                 // if we're in strict mode, we doing try coercing from a date
                 true => return Err(date_err),
                 // otherwise, try creating a date from a datetime input
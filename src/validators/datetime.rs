@@ -3,7 +3,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDateTime, PyDict, PyString};
 use speedate::DateTime;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, py_error, schema_or_config, SchemaDict};
 use crate::errors::{py_err_string, ErrorKind, ValError, ValResult};
 use crate::input::{EitherDateTime, Input};
 use crate::recursion_guard::RecursionGuard;
@@ -14,6 +14,8 @@ use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 pub struct DateTimeValidator {
     strict: bool,
     constraints: Option<DateTimeConstraints>,
+    // timezone applied to naive datetimes
+    assume_tz: Option<PyObject>,
 }
 
 #[derive(Debug, Clone)]
@@ -38,6 +40,18 @@ impl BuildValidator for DateTimeValidator {
             || schema.get_item(intern!(py, "ge")).is_some()
             || schema.get_item(intern!(py, "gt")).is_some();
 
+        let assume_tz: Option<&PyAny> = schema_or_config(
+            schema,
+            config,
+            intern!(py, "assume_tz"),
+            intern!(py, "datetime_assume_tz"),
+        )?;
+        if let Some(tz) = assume_tz {
+            if !tz.is_instance(py.import("datetime")?.getattr(intern!(py, "tzinfo"))?)? {
+                return py_error!("assume_tz must be a datetime.tzinfo instance");
+            }
+        }
+
         Ok(Self {
             strict: is_strict(schema, config)?,
             constraints: match has_constraints {
@@ -49,6 +63,7 @@ impl BuildValidator for DateTimeValidator {
                 }),
                 false => None,
             },
+            assume_tz: assume_tz.map(|tz| tz.into_py(py)),
         }
         .into())
     }
@@ -63,7 +78,19 @@ impl Validator for DateTimeValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let datetime = input.validate_datetime(extra.strict.unwrap_or(self.strict))?;
+        let mut datetime = input.validate_datetime(extra.strict.unwrap_or(self.strict))?;
+        if let Some(ref tz) = self.assume_tz {
+            if is_naive(&datetime)? {
+                let kwargs = PyDict::new(py);
+                kwargs.set_item(intern!(py, "tzinfo"), tz)?;
+                let aware =
+                    datetime
+                        .try_into_py(py)?
+                        .into_ref(py)
+                        .call_method(intern!(py, "replace"), (), Some(kwargs))?;
+                datetime = EitherDateTime::Py(aware.cast_as()?);
+            }
+        }
         if let Some(constraints) = &self.constraints {
             // if we get an error from as_speedate, it's probably because the input datetime was invalid
             // specifically had an invalid tzinfo, hence here we return a validation error
@@ -108,3 +135,10 @@ fn py_datetime_as_datetime(schema: &PyDict, field: &PyString) -> PyResult<Option
         None => Ok(None),
     }
 }
+
+fn is_naive(datetime: &EitherDateTime) -> PyResult<bool> {
+    match datetime {
+        EitherDateTime::Raw(dt) => Ok(dt.offset.is_none()),
+        EitherDateTime::Py(py_dt) => Ok(py_dt.getattr(intern!(py_dt.py(), "tzinfo"))?.is_none()),
+    }
+}
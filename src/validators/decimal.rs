@@ -0,0 +1,117 @@
+use pyo3::exceptions::PyArithmeticError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyLong, PyType};
+
+use crate::build_tools::{is_strict, py_error, SchemaDict};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// How binary floats are converted to `Decimal`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FloatPolicy {
+    Reject,
+    // `Decimal(str(value))`, e.g. `0.1` becomes `Decimal('0.1')`
+    Str,
+    // `Decimal(value)`, e.g. `0.1` becomes `Decimal('0.1000000000000000055511151231257827021181583404541015625')`
+    Exact,
+}
+
+impl FloatPolicy {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Reject => "reject",
+            Self::Str => "str",
+            Self::Exact => "exact",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DecimalValidator {
+    strict: bool,
+    float_policy: FloatPolicy,
+    decimal_class: Py<PyType>,
+}
+
+impl BuildValidator for DecimalValidator {
+    const EXPECTED_TYPE: &'static str = "decimal";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let float_policy = match schema.get_as(intern!(py, "float_policy"))? {
+            Some("reject") => FloatPolicy::Reject,
+            Some("str") | None => FloatPolicy::Str,
+            Some("exact") => FloatPolicy::Exact,
+            Some(s) => return py_error!(r#"Invalid float_policy: "{}""#, s),
+        };
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            float_policy,
+            decimal_class: py.import("decimal")?.getattr("Decimal")?.cast_as::<PyType>()?.into(),
+        }
+        .into())
+    }
+}
+
+impl Validator for DecimalValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let decimal_class = self.decimal_class.as_ref(py);
+        if input.is_instance(decimal_class)? {
+            return Ok(input.to_object(py));
+        } else if extra.strict.unwrap_or(self.strict) {
+            return Err(ValError::new(ErrorKind::DecimalType, input));
+        }
+
+        if let Ok(either_str) = input.strict_str() {
+            return match decimal_class.call1((either_str.as_cow()?.trim(),)) {
+                Ok(decimal) => Ok(decimal.into_py(py)),
+                // `decimal.InvalidOperation` is a subclass of `ArithmeticError`
+                Err(err) if err.is_instance_of::<PyArithmeticError>(py) => {
+                    Err(ValError::new(ErrorKind::DecimalParsing, input))
+                }
+                Err(err) => Err(err.into()),
+            };
+        }
+
+        let value = input.to_object(py);
+        let value = value.as_ref(py);
+        if value.cast_as::<PyFloat>().is_ok() {
+            let decimal = match self.float_policy {
+                FloatPolicy::Reject => {
+                    return Err(ValError::new(
+                        ErrorKind::DecimalFromFloat {
+                            float_policy: self.float_policy.as_str(),
+                        },
+                        input,
+                    ))
+                }
+                FloatPolicy::Str => decimal_class.call1((value.str()?,))?,
+                FloatPolicy::Exact => decimal_class.call1((value,))?,
+            };
+            Ok(decimal.into_py(py))
+        } else if value.cast_as::<PyLong>().is_ok() && value.cast_as::<PyBool>().is_err() {
+            Ok(decimal_class.call1((value,))?.into_py(py))
+        } else {
+            Err(ValError::new(ErrorKind::DecimalType, input))
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
@@ -0,0 +1,72 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::SchemaDict;
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::dict::build_inner_dict_validator;
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct DefaultDictValidator {
+    dict_validator: Box<CombinedValidator>,
+    default_factory: PyObject,
+    defaultdict_type: PyObject,
+    name: String,
+}
+
+impl BuildValidator for DefaultDictValidator {
+    const EXPECTED_TYPE: &'static str = "defaultdict";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let default_factory: &PyAny = schema.get_as_req(intern!(py, "default_factory"))?;
+
+        // keys and values are validated exactly as by the dict validator
+        let dict_validator = build_inner_dict_validator(schema, config, build_context, &["default_factory"])?;
+        let name = format!("{}{}", Self::EXPECTED_TYPE, &dict_validator.get_name()["dict".len()..]);
+
+        Ok(Self {
+            dict_validator,
+            default_factory: default_factory.into_py(py),
+            defaultdict_type: py
+                .import("collections")?
+                .getattr(intern!(py, "defaultdict"))?
+                .into_py(py),
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for DefaultDictValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let dict = self.dict_validator.validate(py, input, extra, slots, recursion_guard)?;
+        let default_dict = self
+            .defaultdict_type
+            .call1(py, (self.default_factory.clone_ref(py), dict))?;
+        Ok(default_dict)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.dict_validator.complete(build_context)
+    }
+}
@@ -0,0 +1,92 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+
+use crate::build_tools::{is_strict, SchemaDict};
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct DequeValidator {
+    strict: bool,
+    item_validator: Option<Box<CombinedValidator>>,
+    size_range: Option<(Option<usize>, Option<usize>)>,
+    maxlen: Option<usize>,
+    fail_fast: bool,
+    deque_type: Py<PyType>,
+    name: String,
+}
+
+impl BuildValidator for DequeValidator {
+    const EXPECTED_TYPE: &'static str = "deque";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let item_validator = match schema.get_item(intern!(py, "items_schema")) {
+            Some(d) => Some(Box::new(build_validator(d, config, build_context)?)),
+            None => None,
+        };
+        let inner_name = item_validator.as_ref().map(|v| v.get_name()).unwrap_or("any");
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, inner_name);
+        let min_items = schema.get_as(intern!(py, "min_items"))?;
+        let max_items = schema.get_as(intern!(py, "max_items"))?;
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            item_validator,
+            size_range: match min_items.is_some() || max_items.is_some() {
+                true => Some((min_items, max_items)),
+                false => None,
+            },
+            maxlen: schema.get_as(intern!(py, "maxlen"))?,
+            fail_fast: schema.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
+            deque_type: py
+                .import("collections")?
+                .getattr(intern!(py, "deque"))?
+                .cast_as::<PyType>()?
+                .into(),
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for DequeValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let seq = input.validate_deque(extra.strict.unwrap_or(self.strict))?;
+
+        let length = seq.check_len(self.size_range, input)?;
+
+        let output = match self.item_validator {
+            Some(ref v) => seq.validate_to_vec(py, length, v, self.fail_fast, extra, slots, recursion_guard)?,
+            None => seq.to_vec(py),
+        };
+        // as with `deque(items, maxlen)`, if there are more than `maxlen` items, those at the start are discarded
+        let deque = self.deque_type.call1(py, (output, self.maxlen))?;
+        Ok(deque)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        match self.item_validator {
+            Some(ref mut v) => v.complete(build_context),
+            None => Ok(()),
+        }
+    }
+}
@@ -3,7 +3,7 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::build_tools::{is_strict, SchemaDict};
-use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
+use crate::errors::{CurrentLoc, ErrorKind, ValError, ValLineError, ValResult};
 use crate::input::{GenericMapping, Input, JsonObject};
 use crate::recursion_guard::RecursionGuard;
 
@@ -17,6 +17,7 @@ pub struct DictValidator {
     value_validator: Box<CombinedValidator>,
     min_items: Option<usize>,
     max_items: Option<usize>,
+    fail_fast: bool,
     name: String,
 }
 
@@ -49,12 +50,32 @@ impl BuildValidator for DictValidator {
             value_validator,
             min_items: schema.get_as(intern!(py, "min_items"))?,
             max_items: schema.get_as(intern!(py, "max_items"))?,
+            fail_fast: schema.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
             name,
         }
         .into())
     }
 }
 
+/// Build a dict validator from the schema of a validator which validates a dict then converts it to another
+/// type, e.g. `defaultdict`; `own_keys` are removed from the schema before it's used
+pub fn build_inner_dict_validator(
+    schema: &PyDict,
+    config: Option<&PyDict>,
+    build_context: &mut BuildContext,
+    own_keys: &[&str],
+) -> PyResult<Box<CombinedValidator>> {
+    let py = schema.py();
+    let dict_schema = schema.copy()?;
+    dict_schema.set_item(intern!(py, "type"), DictValidator::EXPECTED_TYPE)?;
+    for key in own_keys.iter().chain(&["ref"]) {
+        if dict_schema.contains(key)? {
+            dict_schema.del_item(key)?;
+        }
+    }
+    Ok(Box::new(build_validator(dict_schema, config, build_context)?))
+}
+
 impl Validator for DictValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -128,12 +149,15 @@ macro_rules! build_validate {
             let value_validator = self.value_validator.as_ref();
 
             for (key, value) in dict.iter() {
+                let salvage_start = extra.salvage.map(|s| s.len());
+                let coercions_start = extra.coercions.map(|c| c.len());
                 let output_key = match key_validator.validate(py, key, extra, slots, recursion_guard) {
                     Ok(value) => Some(value),
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
                             // these are added in reverse order so [key] is shunted along by the second call
-                            errors.push(
+                            extra.push_error(
+                                &mut errors,
                                 err.with_outer_location("[key]".into())
                                     .with_outer_location(key.as_loc_item()),
                             );
@@ -142,23 +166,44 @@ macro_rules! build_validate {
                     }
                     Err(err) => return Err(err),
                 };
-                let output_value = match value_validator.validate(py, value, extra, slots, recursion_guard) {
+                let loc = CurrentLoc::items(vec![key.as_loc_item()], extra.loc);
+                let value_extra = Extra {
+                    loc: Some(&loc),
+                    ..*extra
+                };
+                let output_value = match value_validator.validate(py, value, &value_extra, slots, recursion_guard) {
                     Ok(value) => Some(value),
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
-                            errors.push(err.with_outer_location(key.as_loc_item()));
+                            extra.push_error(&mut errors, err.with_outer_location(key.as_loc_item()));
                         }
                         None
                     }
                     Err(err) => return Err(err),
                 };
-                if let (Some(key), Some(value)) = (output_key, output_value) {
-                    output.set_item(key, value)?;
+                if let (Some(salvage), Some(start)) = (extra.salvage, salvage_start) {
+                    salvage.with_outer_location(start, &key.as_loc_item());
+                }
+                if let (Some(coercions), Some(start)) = (extra.coercions, coercions_start) {
+                    coercions.with_outer_location(start, &key.as_loc_item());
+                }
+                match (output_key, output_value) {
+                    (Some(key), Some(value)) => output.set_item(key, value)?,
+                    // when salvaging, entries with an invalid value are kept with the value `None`,
+                    // entries with an invalid key are dropped
+                    (Some(key), None) if extra.salvage.is_some() => output.set_item(key, py.None())?,
+                    _ => (),
+                }
+                if (self.fail_fast || extra.fail_fast) && !errors.is_empty() {
+                    break;
                 }
             }
 
             if errors.is_empty() {
                 Ok(output.into())
+            } else if let Some(salvage) = extra.salvage {
+                salvage.record(py, errors);
+                Ok(output.into())
             } else {
                 Err(ValError::LineErrors(errors))
             }
@@ -2,9 +2,9 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{is_strict, schema_or_config_same, SchemaDict};
+use crate::build_tools::{is_strict, py_error, schema_or_config_same, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{Input, NumberParsing};
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -13,6 +13,7 @@ use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 pub struct FloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    parsing: NumberParsing,
 }
 
 impl BuildValidator for FloatValidator {
@@ -35,6 +36,7 @@ impl BuildValidator for FloatValidator {
             Ok(Self {
                 strict: is_strict(schema, config)?,
                 allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+                parsing: NumberParsing::from_schema(schema, config)?,
             }
             .into())
         }
@@ -50,7 +52,7 @@ impl Validator for FloatValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let float = input.validate_float(extra.strict.unwrap_or(self.strict))?;
+        let float = input.validate_float(extra.strict.unwrap_or(self.strict), self.parsing)?;
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorKind::FloatFiniteNumber, input));
         }
@@ -66,6 +68,7 @@ impl Validator for FloatValidator {
 pub struct ConstrainedFloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    parsing: NumberParsing,
     multiple_of: Option<f64>,
     le: Option<f64>,
     lt: Option<f64>,
@@ -82,12 +85,12 @@ impl Validator for ConstrainedFloatValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let float = input.validate_float(extra.strict.unwrap_or(self.strict))?;
+        let float = input.validate_float(extra.strict.unwrap_or(self.strict), self.parsing)?;
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorKind::FloatFiniteNumber, input));
         }
         if let Some(multiple_of) = self.multiple_of {
-            if float % multiple_of != 0.0 {
+            if !is_multiple_of(float, multiple_of) {
                 return Err(ValError::new(ErrorKind::FloatMultipleOf { multiple_of }, input));
             }
         }
@@ -121,10 +124,15 @@ impl Validator for ConstrainedFloatValidator {
 impl ConstrainedFloatValidator {
     pub fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
         let py = schema.py();
+        let multiple_of: Option<f64> = schema.get_as(intern!(py, "multiple_of"))?;
+        if matches!(multiple_of, Some(m) if !(m > 0.0 && m.is_finite())) {
+            return py_error!("multiple_of must be a positive finite number");
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
-            multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
+            parsing: NumberParsing::from_schema(schema, config)?,
+            multiple_of,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
@@ -133,3 +141,11 @@ impl ConstrainedFloatValidator {
         .into())
     }
 }
+
+/// `float % multiple_of` is rarely exactly zero for non-integer values (e.g. `0.3 % 0.1`), instead check
+/// that `float / multiple_of` is within rounding error of an integer, the rounding error grows with the
+/// magnitude of the ratio so the tolerance has to scale with it
+fn is_multiple_of(float: f64, multiple_of: f64) -> bool {
+    let ratio = float / multiple_of;
+    (ratio - ratio.round()).abs() <= ratio.abs().max(1.0) * f64::EPSILON * 4.0
+}
@@ -0,0 +1,157 @@
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyOverflowError, PyValueError, PyZeroDivisionError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyLong, PyType};
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct FractionValidator {
+    strict: bool,
+    fraction_class: Py<PyType>,
+    decimal_class: Py<PyType>,
+    le: Option<PyObject>,
+    lt: Option<PyObject>,
+    ge: Option<PyObject>,
+    gt: Option<PyObject>,
+}
+
+impl BuildValidator for FractionValidator {
+    const EXPECTED_TYPE: &'static str = "fraction";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let fraction_class: &PyType = py.import("fractions")?.getattr("Fraction")?.cast_as()?;
+        let get_bound = |key: &PyAny| -> PyResult<Option<PyObject>> {
+            match schema.get_item(key) {
+                Some(value) => Ok(Some(fraction_class.call1((value,))?.into_py(py))),
+                None => Ok(None),
+            }
+        };
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            fraction_class: fraction_class.into(),
+            decimal_class: py.import("decimal")?.getattr("Decimal")?.cast_as::<PyType>()?.into(),
+            le: get_bound(intern!(py, "le"))?,
+            lt: get_bound(intern!(py, "lt"))?,
+            ge: get_bound(intern!(py, "ge"))?,
+            gt: get_bound(intern!(py, "gt"))?,
+        }
+        .into())
+    }
+}
+
+impl Validator for FractionValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let fraction = self.validate_fraction(py, input, extra.strict.unwrap_or(self.strict))?;
+        let fraction_ref = fraction.as_ref(py);
+
+        if let Some(ref le) = self.le {
+            if !fraction_ref.rich_compare(le, CompareOp::Le)?.is_true()? {
+                return Err(ValError::new(
+                    ErrorKind::FractionLessThanEqual {
+                        le: le.as_ref(py).str()?.to_string(),
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(ref lt) = self.lt {
+            if !fraction_ref.rich_compare(lt, CompareOp::Lt)?.is_true()? {
+                return Err(ValError::new(
+                    ErrorKind::FractionLessThan {
+                        lt: lt.as_ref(py).str()?.to_string(),
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(ref ge) = self.ge {
+            if !fraction_ref.rich_compare(ge, CompareOp::Ge)?.is_true()? {
+                return Err(ValError::new(
+                    ErrorKind::FractionGreaterThanEqual {
+                        ge: ge.as_ref(py).str()?.to_string(),
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(ref gt) = self.gt {
+            if !fraction_ref.rich_compare(gt, CompareOp::Gt)?.is_true()? {
+                return Err(ValError::new(
+                    ErrorKind::FractionGreaterThan {
+                        gt: gt.as_ref(py).str()?.to_string(),
+                    },
+                    input,
+                ));
+            }
+        }
+        Ok(fraction)
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+impl FractionValidator {
+    fn validate_fraction<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        strict: bool,
+    ) -> ValResult<'data, PyObject> {
+        let fraction_class = self.fraction_class.as_ref(py);
+        if input.is_instance(fraction_class)? {
+            return Ok(input.to_object(py));
+        } else if strict {
+            return Err(ValError::new(ErrorKind::FractionType, input));
+        }
+
+        if let Ok(either_str) = input.strict_str() {
+            // `Fraction` parses strings like "3/7", "1.5" and "2e-3" and allows surrounding whitespace
+            return match fraction_class.call1((either_str.as_cow()?.as_ref(),)) {
+                Ok(fraction) => Ok(fraction.into_py(py)),
+                Err(err) if err.is_instance_of::<PyValueError>(py) || err.is_instance_of::<PyZeroDivisionError>(py) => {
+                    Err(ValError::new(ErrorKind::FractionParsing, input))
+                }
+                Err(err) => Err(err.into()),
+            };
+        }
+
+        let value = input.to_object(py);
+        let value = value.as_ref(py);
+        let is_number = value.cast_as::<PyBool>().is_err()
+            && (value.cast_as::<PyLong>().is_ok()
+                || value.cast_as::<PyFloat>().is_ok()
+                || value.is_instance(self.decimal_class.as_ref(py))?);
+        if !is_number {
+            return Err(ValError::new(ErrorKind::FractionType, input));
+        }
+        match fraction_class.call1((value,)) {
+            Ok(fraction) => Ok(fraction.into_py(py)),
+            // infinity and NaN can't be represented as a fraction
+            Err(err) if err.is_instance_of::<PyValueError>(py) || err.is_instance_of::<PyOverflowError>(py) => {
+                Err(ValError::new(ErrorKind::FloatFiniteNumber, input))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+}
@@ -6,7 +6,8 @@ use crate::errors::ValResult;
 use crate::input::{GenericCollection, Input};
 use crate::recursion_guard::RecursionGuard;
 
-use super::list::generic_collection_build;
+use super::list::{generic_collection_build, split_str_input};
+use super::set::unhashable_error;
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
@@ -14,12 +15,14 @@ pub struct FrozenSetValidator {
     strict: bool,
     item_validator: Option<Box<CombinedValidator>>,
     size_range: Option<(Option<usize>, Option<usize>)>,
+    str_delimiter: Option<String>,
+    fail_fast: bool,
     name: String,
 }
 
 impl BuildValidator for FrozenSetValidator {
     const EXPECTED_TYPE: &'static str = "frozenset";
-    generic_collection_build!();
+    generic_collection_build!(fail_fast);
 }
 
 impl Validator for FrozenSetValidator {
@@ -31,18 +34,26 @@ impl Validator for FrozenSetValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_frozenset(extra.strict.unwrap_or(self.strict))?;
-
-        let length = seq.check_len(self.size_range, input)?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let seq = match split_str_input(py, input, self.str_delimiter.as_deref(), strict)? {
+            Some(seq) => seq,
+            None => input.validate_frozenset(strict)?,
+        };
 
         let output = match self.item_validator {
-            Some(ref v) => seq.validate_to_vec(py, length, v, extra, slots, recursion_guard)?,
+            Some(ref v) => seq.validate_to_vec(py, None, v, self.fail_fast, extra, slots, recursion_guard)?,
             None => match seq {
-                GenericCollection::FrozenSet(f_set) => return Ok(f_set.into_py(py)),
+                GenericCollection::FrozenSet(f_set) => {
+                    seq.check_len(self.size_range, input)?;
+                    return Ok(f_set.into_py(py));
+                }
                 _ => seq.to_vec(py),
             },
         };
-        Ok(PyFrozenSet::new(py, &output)?.into_py(py))
+        let f_set = PyFrozenSet::new(py, &output).map_err(|err| unhashable_error(py, err, output, input))?;
+        // length constraints apply after duplicates have been collapsed
+        GenericCollection::from(f_set).check_len(self.size_range, input)?;
+        Ok(f_set.into_py(py))
     }
 
     fn get_name(&self) -> &str {
@@ -1,10 +1,13 @@
-use pyo3::exceptions::{PyAssertionError, PyValueError};
+use std::sync::Arc;
+use std::time::Instant;
+
+use pyo3::exceptions::{PyAssertionError, PyRuntimeError, PyValueError};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyAny, PyDict};
 
-use crate::build_tools::SchemaDict;
-use crate::errors::{ErrorKind, PydanticValueError, ValError, ValResult, ValidationError};
+use crate::build_tools::{py_error, SchemaDict};
+use crate::errors::{CurrentLoc, ErrorKind, LocItem, PydanticValueError, ValError, ValResult, ValidationError};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
@@ -32,8 +35,13 @@ impl BuildValidator for FunctionBuilder {
 }
 
 macro_rules! kwargs {
-    ($py:ident, $($k:ident: $v:expr),* $(,)?) => {{
-        Some(pyo3::types::IntoPyDict::into_py_dict([$((stringify!($k), $v.into_py($py)),)*], $py).into())
+    ($py:ident, $extra:ident, $($k:ident: $v:expr),* $(,)?) => {{
+        let kwargs = pyo3::types::IntoPyDict::into_py_dict([$((stringify!($k), $v.into_py($py)),)*], $py);
+        // only passed when a timeout is given, so functions which don't expect it aren't affected
+        if let Some(remaining_time) = $extra.remaining_time() {
+            kwargs.set_item(intern!($py, "remaining_time"), remaining_time)?;
+        }
+        Some(kwargs)
     }};
 }
 
@@ -82,10 +90,18 @@ impl Validator for FunctionBeforeValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let kwargs = kwargs!(py, data: extra.data, config: self.config.clone_ref(py), context: extra.context);
+        let kwargs = kwargs!(
+            py,
+            extra,
+            data: extra.data,
+            config: self.config.clone_ref(py),
+            context: extra.context,
+            info: ValidationInfo::new(py, input, extra, &self.config),
+        );
         let value = self
             .func
             .call(py, (input.to_object(py),), kwargs)
+            .and_then(|v| await_coroutine(py, v, extra))
             .map_err(|e| convert_err(py, e, input))?;
 
         self.validator
@@ -125,8 +141,18 @@ impl Validator for FunctionAfterValidator {
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         let v = self.validator.validate(py, input, extra, slots, recursion_guard)?;
-        let kwargs = kwargs!(py, data: extra.data, config: self.config.clone_ref(py), context: extra.context);
-        self.func.call(py, (v,), kwargs).map_err(|e| convert_err(py, e, input))
+        let kwargs = kwargs!(
+            py,
+            extra,
+            data: extra.data,
+            config: self.config.clone_ref(py),
+            context: extra.context,
+            info: ValidationInfo::new(py, input, extra, &self.config),
+        );
+        self.func
+            .call(py, (v,), kwargs)
+            .and_then(|v| await_coroutine(py, v, extra))
+            .map_err(|e| convert_err(py, e, input))
     }
 
     fn get_name(&self) -> &str {
@@ -171,9 +197,17 @@ impl Validator for FunctionPlainValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let kwargs = kwargs!(py, data: extra.data, config: self.config.clone_ref(py), context: extra.context);
+        let kwargs = kwargs!(
+            py,
+            extra,
+            data: extra.data,
+            config: self.config.clone_ref(py),
+            context: extra.context,
+            info: ValidationInfo::new(py, input, extra, &self.config),
+        );
         self.func
             .call(py, (input.to_object(py),), kwargs)
+            .and_then(|v| await_coroutine(py, v, extra))
             .map_err(|e| convert_err(py, e, input))
     }
 
@@ -203,22 +237,31 @@ impl Validator for FunctionWrapValidator {
     ) -> ValResult<'data, PyObject> {
         let validator_kwarg = ValidatorCallable {
             validator: self.validator.clone(),
-            slots: slots.to_vec(),
+            slots: extra.shared_slots(slots),
             data: extra.data.map(|d| d.into_py(py)),
             field: extra.field.map(|f| f.to_string()),
             strict: extra.strict,
             context: extra.context.map(|d| d.into_py(py)),
+            deadline: extra.deadline,
+            partial: extra.partial,
+            fail_fast: extra.fail_fast,
+            event_loop: extra.event_loop.map(|l| l.into_py(py)),
+            loc: extra.loc.map(CurrentLoc::to_vec).unwrap_or_default(),
+            field_name: extra.loc.and_then(CurrentLoc::field_name).map(ToString::to_string),
             recursion_guard: recursion_guard.clone(),
         };
         let kwargs = kwargs!(
             py,
+            extra,
             validator: validator_kwarg,
             data: extra.data,
             config: self.config.clone_ref(py),
             context: extra.context,
+            info: ValidationInfo::new(py, input, extra, &self.config),
         );
         self.func
             .call(py, (input.to_object(py),), kwargs)
+            .and_then(|v| await_coroutine(py, v, extra))
             .map_err(|e| convert_err(py, e, input))
     }
 
@@ -235,30 +278,77 @@ impl Validator for FunctionWrapValidator {
     }
 }
 
+/// With `validate_python_async` validation runs in a worker thread, a coroutine returned by a function is run on
+/// the event loop while the worker thread waits for its result
+fn await_coroutine(py: Python, value: PyObject, extra: &Extra) -> PyResult<PyObject> {
+    let event_loop = match extra.event_loop {
+        Some(event_loop) => event_loop,
+        None => return Ok(value),
+    };
+    let asyncio = py.import("asyncio")?;
+    if asyncio.call_method1(intern!(py, "iscoroutine"), (&value,))?.is_true()? {
+        // e.g. the handler of an async wrap function, waiting here would block the event loop forever
+        if asyncio.call_method0(intern!(py, "_get_running_loop"))?.is(event_loop) {
+            value.call_method0(py, intern!(py, "close"))?;
+            return py_error!(PyRuntimeError; "Coroutines can't be awaited by validation running on the event loop");
+        }
+        let future = asyncio.call_method1(intern!(py, "run_coroutine_threadsafe"), (value, event_loop))?;
+        Ok(future.call_method0(intern!(py, "result"))?.into_py(py))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Create the `ValidatorCallable` type object which would otherwise happen the first time
+/// a wrap validator is called, see `SchemaValidator.warmup`
+pub fn function_warmup(py: Python) {
+    py.get_type::<ValidatorCallable>();
+}
+
 #[pyclass]
-#[derive(Debug, Clone)]
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
 struct ValidatorCallable {
     validator: Box<CombinedValidator>,
-    slots: Vec<CombinedValidator>,
+    slots: Arc<Vec<CombinedValidator>>,
     data: Option<Py<PyDict>>,
     field: Option<String>,
     strict: Option<bool>,
     context: Option<PyObject>,
+    deadline: Option<Instant>,
+    partial: bool,
+    fail_fast: bool,
+    event_loop: Option<PyObject>,
+    loc: Vec<LocItem>,
+    field_name: Option<String>,
     recursion_guard: RecursionGuard,
 }
 
 #[pymethods]
 impl ValidatorCallable {
     fn __call__(&mut self, py: Python, arg: &PyAny) -> PyResult<PyObject> {
+        let loc = CurrentLoc::restore(self.loc.clone(), self.field_name.as_deref());
         let extra = Extra {
             data: self.data.as_ref().map(|data| data.as_ref(py)),
             field: self.field.as_deref(),
             strict: self.strict,
             context: self.context.as_ref().map(|data| data.as_ref(py)),
+            coercions: None,
+            salvage: None,
+            deadline: self.deadline,
+            loc: Some(&loc),
+            // the handler is called with python values
+            strings: false,
+            partial: self.partial,
+            fail_fast: self.fail_fast,
+            event_loop: self.event_loop.as_ref().map(|l| l.as_ref(py)),
+            // the errors are returned in full to the wrap function as a `ValidationError`
+            error_limit: None,
+            slots: Some(&self.slots),
         };
         self.validator
             .validate(py, arg, &extra, &self.slots, &mut self.recursion_guard)
-            .map_err(|e| ValidationError::from_val_error(py, "Model".to_object(py), e))
+            .map_err(|e| ValidationError::from_val_error(py, self.validator.get_name().to_object(py), e))
     }
 
     fn __repr__(&self) -> String {
@@ -270,6 +360,78 @@ impl ValidatorCallable {
     }
 }
 
+/// Passed to all function validators as the `info` kwarg, describes where and how validation is happening
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct ValidationInfo {
+    data: Option<Py<PyDict>>,
+    config: PyObject,
+    context: Option<PyObject>,
+    loc: Vec<LocItem>,
+    field_name: Option<String>,
+    mode: &'static str,
+}
+
+impl ValidationInfo {
+    fn new<'data>(py: Python, input: &impl Input<'data>, extra: &Extra, config: &PyObject) -> Self {
+        Self {
+            data: extra.data.map(Into::into),
+            config: config.clone_ref(py),
+            context: extra.context.map(Into::into),
+            loc: extra.loc.map(CurrentLoc::to_vec).unwrap_or_default(),
+            field_name: extra.loc.and_then(CurrentLoc::field_name).map(ToString::to_string),
+            mode: match (extra.strings, input.is_python()) {
+                (true, _) => "strings",
+                (false, true) => "python",
+                (false, false) => "json",
+            },
+        }
+    }
+}
+
+#[pymethods]
+impl ValidationInfo {
+    #[getter]
+    fn data(&self, py: Python) -> Option<Py<PyDict>> {
+        self.data.as_ref().map(|data| data.clone_ref(py))
+    }
+
+    #[getter]
+    fn config(&self, py: Python) -> PyObject {
+        self.config.clone_ref(py)
+    }
+
+    #[getter]
+    fn context(&self, py: Python) -> Option<PyObject> {
+        self.context.as_ref().map(|context| context.clone_ref(py))
+    }
+
+    #[getter]
+    fn loc(&self, py: Python) -> PyObject {
+        self.loc.to_object(py)
+    }
+
+    #[getter]
+    fn field_name(&self) -> Option<&str> {
+        self.field_name.as_deref()
+    }
+
+    #[getter]
+    fn mode(&self) -> &str {
+        self.mode
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!(
+            "ValidationInfo(loc={}, field_name={:?}, mode={:?})",
+            self.loc(py).as_ref(py).repr()?,
+            self.field_name,
+            self.mode
+        ))
+    }
+}
+
 macro_rules! py_err_string {
     ($error_value:expr, $kind_member:ident, $input:ident) => {
         match $error_value.str() {
@@ -288,7 +450,7 @@ macro_rules! py_err_string {
     };
 }
 
-fn convert_err<'a>(py: Python<'a>, err: PyErr, input: &'a impl Input<'a>) -> ValError<'a> {
+pub fn convert_err<'a>(py: Python<'a>, err: PyErr, input: &'a impl Input<'a>) -> ValError<'a> {
     // Only ValueError and AssertionError are considered as validation errors,
     // TypeError is now considered as a runtime error to catch errors in function signatures
     if err.is_instance_of::<PyValueError>(py) {
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyIterator};
+
+use crate::errors::{ValResult, ValidationError};
+use crate::input::{GenericIterator, Input};
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct GeneratorValidator {
+    // shared with the iterators returned, since they may outlive this validation
+    item_validator: Option<Arc<CombinedValidator>>,
+    name: String,
+}
+
+impl BuildValidator for GeneratorValidator {
+    const EXPECTED_TYPE: &'static str = "generator";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let item_validator = match schema.get_item(intern!(schema.py(), "items_schema")) {
+            Some(d) => Some(Arc::new(build_validator(d, config, build_context)?)),
+            None => None,
+        };
+        let inner_name = item_validator.as_ref().map(|v| v.get_name()).unwrap_or("any");
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, inner_name);
+        Ok(Self { item_validator, name }.into())
+    }
+}
+
+impl Validator for GeneratorValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let iterator = input.validate_iter()?;
+        let item_validator = self.item_validator.as_ref().map(|v| ItemValidator {
+            validator: v.clone(),
+            slots: extra.shared_slots(slots),
+            strict: extra.strict,
+            context: extra.context.map(|c| c.into_py(py)),
+        });
+        let validator_iterator = ValidatorIterator {
+            iterator,
+            index: 0,
+            item_validator,
+            title: self.name.clone(),
+        };
+        Ok(validator_iterator.into_py(py))
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        match self.item_validator {
+            Some(ref mut v) => Arc::make_mut(v).complete(build_context),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ItemValidator {
+    validator: Arc<CombinedValidator>,
+    slots: Arc<Vec<CombinedValidator>>,
+    strict: Option<bool>,
+    context: Option<PyObject>,
+}
+
+/// Returned by the `generator` validator, items are validated as they're consumed and a `ValidationError`
+/// with the item's index as its location is raised for the first invalid item
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Debug, Clone)]
+pub struct ValidatorIterator {
+    iterator: GenericIterator,
+    index: usize,
+    item_validator: Option<ItemValidator>,
+    title: String,
+}
+
+#[pymethods]
+impl ValidatorIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        let index = self.index;
+        let next = match self.iterator {
+            GenericIterator::PyIterator(ref iterator) => {
+                let mut iterator: &PyIterator = iterator.as_ref(py).cast_as()?;
+                match iterator.next() {
+                    Some(item) => {
+                        let item = item?;
+                        Some(self.validate_item(py, item, index)?)
+                    }
+                    None => None,
+                }
+            }
+            GenericIterator::JsonArray(ref array) => match array.get(index) {
+                Some(item) => Some(self.validate_item(py, item, index)?),
+                None => None,
+            },
+        };
+        if next.is_some() {
+            self.index += 1;
+        }
+        Ok(next)
+    }
+
+    #[getter]
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ValidatorIterator(index={}, schema={})", self.index, self.title)
+    }
+}
+
+impl ValidatorIterator {
+    fn validate_item<'data>(
+        &'data self,
+        py: Python<'data>,
+        item: &'data impl Input<'data>,
+        index: usize,
+    ) -> PyResult<PyObject> {
+        match self.item_validator {
+            Some(ref item_validator) => {
+                let extra = Extra {
+                    slots: Some(&item_validator.slots),
+                    ..Extra::new(
+                        item_validator.strict,
+                        item_validator.context.as_ref().map(|c| c.as_ref(py)),
+                    )
+                };
+                item_validator
+                    .validator
+                    .validate(py, item, &extra, &item_validator.slots, &mut RecursionGuard::default())
+                    .map_err(|e| {
+                        ValidationError::from_val_error(
+                            py,
+                            self.title.clone().into_py(py),
+                            e.with_outer_location(index.into()),
+                        )
+                    })
+            }
+            None => Ok(item.to_object(py)),
+        }
+    }
+}
@@ -2,9 +2,9 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, py_error, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{Input, NumberParsing};
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -12,6 +12,7 @@ use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 #[derive(Debug, Clone)]
 pub struct IntValidator {
     strict: bool,
+    parsing: NumberParsing,
 }
 
 impl BuildValidator for IntValidator {
@@ -33,6 +34,7 @@ impl BuildValidator for IntValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                parsing: NumberParsing::from_schema(schema, config)?,
             }
             .into())
         }
@@ -48,7 +50,9 @@ impl Validator for IntValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        Ok(input.validate_int(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        Ok(input
+            .validate_int(extra.strict.unwrap_or(self.strict), self.parsing)?
+            .into_py(py))
     }
 
     fn get_name(&self) -> &str {
@@ -59,6 +63,7 @@ impl Validator for IntValidator {
 #[derive(Debug, Clone)]
 pub struct ConstrainedIntValidator {
     strict: bool,
+    parsing: NumberParsing,
     multiple_of: Option<i64>,
     le: Option<i64>,
     lt: Option<i64>,
@@ -75,7 +80,7 @@ impl Validator for ConstrainedIntValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let int = input.validate_int(extra.strict.unwrap_or(self.strict))?;
+        let int = input.validate_int(extra.strict.unwrap_or(self.strict), self.parsing)?;
         if let Some(multiple_of) = self.multiple_of {
             if int % multiple_of != 0 {
                 return Err(ValError::new(ErrorKind::IntMultipleOf { multiple_of }, input));
@@ -112,9 +117,14 @@ impl Validator for ConstrainedIntValidator {
 impl ConstrainedIntValidator {
     fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
         let py = schema.py();
+        let multiple_of: Option<i64> = schema.get_as(intern!(py, "multiple_of"))?;
+        if matches!(multiple_of, Some(m) if m <= 0) {
+            return py_error!("multiple_of must be a positive integer");
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
-            multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
+            parsing: NumberParsing::from_schema(schema, config)?,
+            multiple_of,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
@@ -0,0 +1,181 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+
+use crate::build_tools::{is_strict, py_error, SchemaDict};
+use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
+use crate::input::{GenericCollection, Input};
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct IntFlagValidator {
+    strict: bool,
+    flag_class: Py<PyType>,
+    members: Py<PyDict>,
+    class_name: String,
+    expected_names: String,
+    name: String,
+}
+
+impl BuildValidator for IntFlagValidator {
+    const EXPECTED_TYPE: &'static str = "int-flag";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let flag_class: &PyType = schema.get_as_req(intern!(py, "flag_type"))?;
+        let int_flag: &PyType = py.import("enum")?.getattr("IntFlag")?.cast_as()?;
+        if !flag_class.is_subclass(int_flag)? {
+            return py_error!("flag_type must be a subclass of enum.IntFlag");
+        }
+
+        // `__members__` includes aliases, so every name which `Flag[name]` accepts is accepted here
+        let members = PyDict::new(py);
+        for item in flag_class
+            .getattr(intern!(py, "__members__"))?
+            .call_method0(intern!(py, "items"))?
+            .iter()?
+        {
+            let (name, member): (&PyAny, &PyAny) = item?.extract()?;
+            members.set_item(name, member)?;
+        }
+        let expected_names = members
+            .keys()
+            .iter()
+            .map(|name| Ok(format!("'{}'", name.extract::<&str>()?)))
+            .collect::<PyResult<Vec<_>>>()?
+            .join(", ");
+        let class_name: String = flag_class.getattr(intern!(py, "__name__"))?.extract()?;
+
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            flag_class: flag_class.into(),
+            members: members.into(),
+            name: format!("{}[{}]", Self::EXPECTED_TYPE, class_name),
+            class_name,
+            expected_names,
+        }
+        .into())
+    }
+}
+
+impl Validator for IntFlagValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        if input.is_instance(self.flag_class.as_ref(py))? {
+            return Ok(input.to_object(py));
+        }
+        if extra.strict.unwrap_or(self.strict) {
+            let int = input
+                .strict_int()
+                .map_err(|_| ValError::new(self.type_error(), input))?;
+            return self.flag_from_int(py, int, input);
+        }
+
+        if let Ok(collection) = input.lax_list() {
+            // members named in a list are combined with `|`
+            let flag = match collection {
+                GenericCollection::List(items) => self.combine(py, items.iter())?,
+                GenericCollection::Tuple(items) => self.combine(py, items.iter())?,
+                GenericCollection::Set(items) => self.combine(py, items.iter())?,
+                GenericCollection::FrozenSet(items) => self.combine(py, items.iter())?,
+                GenericCollection::JsonArray(items) => self.combine(py, items.iter())?,
+            };
+            return Ok(flag);
+        }
+        self.member(py, input)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl IntFlagValidator {
+    /// Validate a single flag value in lax mode: an existing member, an int or a member name
+    fn member<'data>(&self, py: Python<'data>, input: &'data impl Input<'data>) -> ValResult<'data, PyObject> {
+        if input.is_instance(self.flag_class.as_ref(py))? {
+            return Ok(input.to_object(py));
+        }
+        if let Ok(either_str) = input.strict_str() {
+            let name = either_str.as_cow()?;
+            if let Some(member) = self.members.as_ref(py).get_item(name.as_ref()) {
+                return Ok(member.into_py(py));
+            } else if name.trim().parse::<i64>().is_err() {
+                // strings which aren't member names are still allowed if they're ints, e.g. "3"
+                return Err(ValError::new(
+                    ErrorKind::IntFlagName {
+                        class_name: self.class_name.clone(),
+                        expected: self.expected_names.clone(),
+                    },
+                    input,
+                ));
+            }
+        }
+        match input.lax_int(Default::default()) {
+            Ok(int) => self.flag_from_int(py, int, input),
+            Err(_) => Err(ValError::new(self.type_error(), input)),
+        }
+    }
+
+    fn combine<'data, I: Input<'data> + 'data>(
+        &self,
+        py: Python<'data>,
+        items: impl Iterator<Item = &'data I>,
+    ) -> ValResult<'data, PyObject> {
+        let mut flag = self.flag_class.call1(py, (0,))?;
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (index, item) in items.enumerate() {
+            match self.member(py, item) {
+                Ok(member) => flag = flag.call_method1(py, intern!(py, "__or__"), (member,))?,
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        if errors.is_empty() {
+            Ok(flag)
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+
+    fn flag_from_int<'data>(
+        &self,
+        py: Python<'data>,
+        int: i64,
+        input: &'data impl Input<'data>,
+    ) -> ValResult<'data, PyObject> {
+        match self.flag_class.call1(py, (int,)) {
+            Ok(flag) => Ok(flag),
+            // e.g. flags with `boundary=STRICT` reject ints with bits not covered by any member
+            Err(err) if err.is_instance_of::<PyValueError>(py) => Err(ValError::new(
+                ErrorKind::IntFlagValue {
+                    class_name: self.class_name.clone(),
+                    error: err.value(py).to_string(),
+                },
+                input,
+            )),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn type_error(&self) -> ErrorKind {
+        ErrorKind::IntFlagType {
+            class_name: self.class_name.clone(),
+        }
+    }
+}
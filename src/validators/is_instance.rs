@@ -1,18 +1,21 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyDict, PyTuple, PyType};
 
-use crate::build_tools::SchemaDict;
+use crate::build_tools::{py_error, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
+use super::function::convert_err;
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct IsInstanceValidator {
-    class: Py<PyType>,
+    // either a type or a tuple of types, as accepted by `isinstance`
+    class: PyObject,
     class_repr: String,
+    json_function: Option<PyObject>,
     name: String,
 }
 
@@ -24,12 +27,26 @@ impl BuildValidator for IsInstanceValidator {
         _config: Option<&PyDict>,
         _build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
-        let class: &PyType = schema.get_as_req(intern!(schema.py(), "class_"))?;
-        let class_repr = class.name()?.to_string();
+        let py = schema.py();
+        let class: &PyAny = schema.get_as_req(intern!(py, "class_"))?;
+        let class_repr = match class.cast_as::<PyTuple>() {
+            Ok(tuple) => {
+                if tuple.is_empty() {
+                    return py_error!("class_ should be a type or a non-empty tuple of types");
+                }
+                tuple
+                    .iter()
+                    .map(|item| Ok(item.cast_as::<PyType>()?.name()?.to_string()))
+                    .collect::<PyResult<Vec<_>>>()?
+                    .join(" | ")
+            }
+            Err(_) => class.cast_as::<PyType>()?.name()?.to_string(),
+        };
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, class_repr);
         Ok(Self {
             class: class.into(),
             class_repr,
+            json_function: schema.get_as(intern!(py, "json_function"))?,
             name,
         }
         .into())
@@ -45,9 +62,15 @@ impl Validator for IsInstanceValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        match input.is_instance(self.class.as_ref(py))? {
-            true => Ok(input.to_object(py)),
-            false => Err(ValError::new(
+        if input.is_instance(self.class.as_ref(py))? {
+            return Ok(input.to_object(py));
+        }
+        match self.json_function {
+            // JSON can't contain instances of arbitrary classes, so the function is used to create one
+            Some(ref json_function) if !input.is_python() => json_function
+                .call1(py, (input.to_object(py),))
+                .map_err(|e| convert_err(py, e, input)),
+            _ => Err(ValError::new(
                 ErrorKind::IsInstanceOf {
                     class: self.class_repr.clone(),
                 },
@@ -0,0 +1,64 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct JsonValidator {
+    validator: Option<Box<CombinedValidator>>,
+    name: String,
+}
+
+impl BuildValidator for JsonValidator {
+    const EXPECTED_TYPE: &'static str = "json";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let validator = match schema.get_item(intern!(schema.py(), "schema")) {
+            Some(schema) => Some(Box::new(build_validator(schema, config, build_context)?)),
+            None => None,
+        };
+        let inner_name = validator.as_ref().map(|v| v.get_name()).unwrap_or("any");
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, inner_name);
+        Ok(Self { validator, name }.into())
+    }
+}
+
+impl Validator for JsonValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let json_value = input.parse_json()?;
+        match self.validator {
+            // errors refer to the parsed value, which only exists here, so they have to own their input values
+            Some(ref validator) => validator
+                .validate(py, &json_value, extra, slots, recursion_guard)
+                .map_err(|e| e.into_owned(py)),
+            None => Ok(json_value.to_object(py)),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        match self.validator {
+            Some(ref mut v) => v.complete(build_context),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyString};
+
+use crate::build_tools::py_error;
+use crate::errors::{ErrorKind, ValError, ValLineError};
+use crate::input::JsonInput;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{Extra, SchemaValidator, Validator};
+
+#[derive(Debug, Clone)]
+enum LinesSource {
+    // `str` or `bytes` and the position of the start of the next line
+    Data(PyObject, usize),
+    // a file-like object with a `readline()` method
+    File(PyObject),
+}
+
+/// Returned by `SchemaValidator.iter_json_lines`, each line is parsed and validated as it's consumed,
+/// blank lines are skipped. A `ValidationError` with the line number as the first item of its location is raised
+/// for an invalid line, iteration can continue after it.
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Debug, Clone)]
+pub struct JsonLinesIterator {
+    schema_validator: Py<SchemaValidator>,
+    source: LinesSource,
+    line_number: usize,
+    strict: Option<bool>,
+    context: Option<PyObject>,
+}
+
+impl JsonLinesIterator {
+    pub fn new(
+        schema_validator: Py<SchemaValidator>,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<Self> {
+        let py = input.py();
+        let source = if input.cast_as::<PyBytes>().is_ok() || input.cast_as::<PyString>().is_ok() {
+            LinesSource::Data(input.into_py(py), 0)
+        } else if input.hasattr(intern!(py, "readline"))? {
+            LinesSource::File(input.into_py(py))
+        } else {
+            let input_type = input.get_type().name().unwrap_or("unknown");
+            return py_error!(PyTypeError; "JSON lines input should be str, bytes or a file, not {}", input_type);
+        };
+        Ok(Self {
+            schema_validator,
+            source,
+            line_number: 0,
+            strict,
+            context: context.map(|c| c.into_py(py)),
+        })
+    }
+}
+
+#[pymethods]
+impl JsonLinesIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        while let Some(line) = self.next_line(py)? {
+            self.line_number += 1;
+            if !line.iter().all(u8::is_ascii_whitespace) {
+                return self.validate_line(py, &line).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// The number of the last line read, starting from 1
+    #[getter]
+    fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    fn __repr__(&self, py: Python) -> String {
+        let title = self.schema_validator.borrow(py).title.to_string();
+        format!("JsonLinesIterator(line_number={}, schema={})", self.line_number, title)
+    }
+}
+
+impl JsonLinesIterator {
+    fn next_line(&mut self, py: Python) -> PyResult<Option<Vec<u8>>> {
+        match self.source {
+            LinesSource::Data(ref data, ref mut position) => {
+                let data = data.as_ref(py);
+                let bytes = match data.cast_as::<PyBytes>() {
+                    Ok(py_bytes) => py_bytes.as_bytes(),
+                    Err(_) => data.cast_as::<PyString>()?.to_str()?.as_bytes(),
+                };
+                if *position >= bytes.len() {
+                    return Ok(None);
+                }
+                let rest = &bytes[*position..];
+                let line = match rest.iter().position(|b| *b == b'\n') {
+                    Some(end) => &rest[..end],
+                    None => rest,
+                };
+                *position += line.len() + 1;
+                Ok(Some(line.to_vec()))
+            }
+            LinesSource::File(ref file) => {
+                let line = file.as_ref(py).call_method0(intern!(py, "readline"))?;
+                let line = if let Ok(py_bytes) = line.cast_as::<PyBytes>() {
+                    py_bytes.as_bytes().to_vec()
+                } else if let Ok(py_str) = line.cast_as::<PyString>() {
+                    py_str.to_str()?.as_bytes().to_vec()
+                } else {
+                    let line_type = line.get_type().name().unwrap_or("unknown");
+                    return py_error!(PyTypeError; "readline() should return str or bytes, not {}", line_type);
+                };
+                // `readline()` returns an empty string at the end of the file, blank lines still contain "\n"
+                match line.is_empty() {
+                    true => Ok(None),
+                    false => Ok(Some(line)),
+                }
+            }
+        }
+    }
+
+    fn validate_line(&self, py: Python, line: &[u8]) -> PyResult<PyObject> {
+        let schema_validator = self.schema_validator.borrow(py);
+        let error_limit = schema_validator.error_limit(None);
+        let extra = Extra {
+            error_limit: error_limit.as_ref(),
+            slots: Some(&schema_validator.slots),
+            ..Extra::new(self.strict, self.context.as_ref().map(|c| c.as_ref(py)))
+        };
+        let line_error = |e: ValError| {
+            let e = e.with_outer_location(self.line_number.into());
+            schema_validator.prepare_validation_err(py, e, error_limit.as_ref())
+        };
+        match serde_json::from_slice::<JsonInput>(line) {
+            Ok(json_input) => schema_validator
+                .validator
+                .validate(
+                    py,
+                    &json_input,
+                    &extra,
+                    &schema_validator.slots,
+                    &mut RecursionGuard::default(),
+                )
+                .map_err(line_error),
+            Err(e) => {
+                let input: &PyAny = PyString::new(py, String::from_utf8_lossy(line).trim_end());
+                let line_err = ValLineError::new(ErrorKind::InvalidJson { error: e.to_string() }, input);
+                Err(line_error(ValError::LineErrors(vec![line_err])))
+            }
+        }
+    }
+}
@@ -1,8 +1,9 @@
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList};
 
 use crate::build_tools::SchemaDict;
-use crate::errors::ValResult;
+use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::{GenericCollection, Input};
 use crate::recursion_guard::RecursionGuard;
 
@@ -13,14 +14,18 @@ pub struct ListValidator {
     strict: bool,
     item_validator: Option<Box<CombinedValidator>>,
     size_range: Option<(Option<usize>, Option<usize>)>,
+    str_delimiter: Option<String>,
+    unique_items: bool,
+    fail_fast: bool,
     name: String,
 }
 
+/// Trailing idents are extra boolean options read from the schema, e.g. `unique_items`, they default to false
 macro_rules! generic_collection_build {
-    () => {
-        super::list::generic_collection_build!("{}[{}]", Self::EXPECTED_TYPE);
+    ($($bool_option:ident),*) => {
+        super::list::generic_collection_build!("{}[{}]", Self::EXPECTED_TYPE $(, $bool_option)*);
     };
-    ($name_template:literal, $name:expr) => {
+    ($name_template:literal, $name:expr $(, $bool_option:ident)*) => {
         fn build(
             schema: &PyDict,
             config: Option<&PyDict>,
@@ -42,6 +47,8 @@ macro_rules! generic_collection_build {
                     true => Some((min_items, max_items)),
                     false => None,
                 },
+                str_delimiter: schema.get_as(pyo3::intern!(py, "str_delimiter"))?,
+                $($bool_option: schema.get_as(pyo3::intern!(py, stringify!($bool_option)))?.unwrap_or(false),)*
                 name,
             }
             .into())
@@ -50,9 +57,78 @@ macro_rules! generic_collection_build {
 }
 pub(crate) use generic_collection_build;
 
+/// In lax mode, if `str_delimiter` is set, string inputs are split into a list of items, e.g. to populate
+/// a list from an environment variable. Items are stripped of whitespace, a whitespace delimiter splits on any
+/// run of whitespace.
+pub fn split_str_input<'data>(
+    py: Python<'data>,
+    input: &'data impl Input<'data>,
+    str_delimiter: Option<&str>,
+    strict: bool,
+) -> ValResult<'data, Option<GenericCollection<'data>>> {
+    let delimiter = match (strict, str_delimiter) {
+        (false, Some(delimiter)) => delimiter,
+        _ => return Ok(None),
+    };
+    let either_str = match input.strict_str() {
+        Ok(either_str) => either_str,
+        Err(_) => return Ok(None),
+    };
+    let str = either_str.as_cow()?;
+    let items: Vec<&str> = if delimiter.trim().is_empty() {
+        str.split_whitespace().collect()
+    } else if str.trim().is_empty() {
+        Vec::new()
+    } else {
+        str.split(delimiter).map(str::trim).collect()
+    };
+    Ok(Some(PyList::new(py, items).into()))
+}
+
+/// Check that validated items are unique using python hashing and equality, unhashable items are compared
+/// with `==` to the previous unhashable items. The index of the first duplicate is used as the error location.
+pub fn check_unique_items<'data>(py: Python<'data>, items: &[PyObject]) -> ValResult<'data, ()> {
+    let seen = PyDict::new(py);
+    let mut unhashable: Vec<(usize, &PyAny)> = Vec::new();
+    for (index, item) in items.iter().enumerate() {
+        let item = item.clone_ref(py).into_ref(py);
+        let first_index = match item.hash() {
+            Ok(_) => match seen.get_item(item) {
+                Some(first_index) => Some(first_index.extract()?),
+                None => {
+                    seen.set_item(item, index)?;
+                    None
+                }
+            },
+            Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+                let mut first_index = None;
+                for (other_index, other) in &unhashable {
+                    if other.eq(item)? {
+                        first_index = Some(*other_index);
+                        break;
+                    }
+                }
+                if first_index.is_none() {
+                    unhashable.push((index, item));
+                }
+                first_index
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if let Some(first_index) = first_index {
+            return Err(ValError::new_with_loc(
+                ErrorKind::DuplicateItem { first_index },
+                item,
+                index,
+            ));
+        }
+    }
+    Ok(())
+}
+
 impl BuildValidator for ListValidator {
     const EXPECTED_TYPE: &'static str = "list";
-    generic_collection_build!();
+    generic_collection_build!(unique_items, fail_fast);
 }
 
 impl Validator for ListValidator {
@@ -64,17 +140,24 @@ impl Validator for ListValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_list(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let seq = match split_str_input(py, input, self.str_delimiter.as_deref(), strict)? {
+            Some(seq) => seq,
+            None => input.validate_list(strict)?,
+        };
 
         let length = seq.check_len(self.size_range, input)?;
 
         let output = match self.item_validator {
-            Some(ref v) => seq.validate_to_vec(py, length, v, extra, slots, recursion_guard)?,
+            Some(ref v) => seq.validate_to_vec(py, length, v, self.fail_fast, extra, slots, recursion_guard)?,
             None => match seq {
-                GenericCollection::List(list) => return Ok(list.into_py(py)),
+                GenericCollection::List(list) if !self.unique_items => return Ok(list.into_py(py)),
                 _ => seq.to_vec(py),
             },
         };
+        if self.unique_items {
+            check_unique_items(py, &output)?;
+        }
         Ok(output.into_py(py))
     }
 
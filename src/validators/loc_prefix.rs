@@ -0,0 +1,116 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString};
+
+use crate::build_tools::{py_error, SchemaDict};
+use crate::errors::{CurrentLoc, LocItem, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+// `LocItem` only implements `Debug` in debug builds, so we store the prefix separately
+#[derive(Debug, Clone)]
+enum PrefixItem {
+    S(String),
+    I(usize),
+}
+
+impl From<&PrefixItem> for LocItem {
+    fn from(item: &PrefixItem) -> Self {
+        match item {
+            PrefixItem::S(s) => LocItem::S(s.clone()),
+            PrefixItem::I(i) => LocItem::I(*i),
+        }
+    }
+}
+
+/// Validate with the inner schema, adding `loc` to the start of the location of all errors,
+/// e.g. so errors from validators for the different parts of a request can be combined
+#[derive(Debug, Clone)]
+pub struct LocPrefixValidator {
+    validator: Box<CombinedValidator>,
+    loc: Vec<PrefixItem>,
+    name: String,
+}
+
+impl BuildValidator for LocPrefixValidator {
+    const EXPECTED_TYPE: &'static str = "loc-prefix";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let loc_list: &PyList = schema.get_as_req(intern!(py, "loc"))?;
+        let loc = loc_list
+            .iter()
+            .map(|item| match item.cast_as::<PyString>() {
+                Ok(py_str) => Ok(PrefixItem::S(py_str.to_str()?.to_string())),
+                Err(_) => Ok(PrefixItem::I(item.extract()?)),
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        if loc.is_empty() {
+            return py_error!("loc should have at least one item");
+        }
+
+        let sub_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
+        let validator = Box::new(build_validator(sub_schema, config, build_context)?);
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self { validator, loc, name }.into())
+    }
+}
+
+impl Validator for LocPrefixValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let salvage_start = extra.salvage.map(|s| s.len());
+        let coercions_start = extra.coercions.map(|c| c.len());
+        let loc = CurrentLoc::items(self.loc.iter().map(Into::into).collect(), extra.loc);
+        let inner_extra = Extra {
+            loc: Some(&loc),
+            ..*extra
+        };
+        let result = self.validator.validate(py, input, &inner_extra, slots, recursion_guard);
+        if let (Some(salvage), Some(start)) = (extra.salvage, salvage_start) {
+            for item in self.loc.iter().rev() {
+                salvage.with_outer_location(start, &item.into());
+            }
+        }
+        if let (Some(coercions), Some(start)) = (extra.coercions, coercions_start) {
+            for item in self.loc.iter().rev() {
+                coercions.with_outer_location(start, &item.into());
+            }
+        }
+        match result {
+            Err(ValError::LineErrors(line_errors)) => Err(ValError::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|line_error| {
+                        // outer locations are added innermost first
+                        self.loc
+                            .iter()
+                            .rev()
+                            .fold(line_error, |err, item| err.with_outer_location(item.into()))
+                    })
+                    .collect(),
+            )),
+            result => result,
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.validator.complete(build_context)
+    }
+}
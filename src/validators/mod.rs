@@ -1,38 +1,72 @@
+use std::collections::hash_map::DefaultHasher;
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use enum_dispatch::enum_dispatch;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyKeyError, PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyByteArray, PyBytes, PyDict, PyString};
+use pyo3::types::{PyAny, PyByteArray, PyBytes, PyDict, PyString, PyTuple};
 
 use crate::build_tools::{py_error, SchemaDict, SchemaError};
-use crate::errors::{ErrorKind, ValError, ValLineError, ValResult, ValidationError};
-use crate::input::{Input, JsonInput};
+use crate::coercions::Coercions;
+use crate::errors::{
+    CurrentLoc, ErrorKind, ErrorLimit, ValError, ValLineError, ValResult, ValidationError, ValidationResult,
+};
+use crate::example_gen::ExampleGenerator;
+use crate::input::{
+    datetime_warmup, input_python_warmup, parse_json_buffer, parse_json_parallel, parse_json_slice, parse_json_stream,
+    string_data_as_json, Input, JsonInput,
+};
 use crate::recursion_guard::RecursionGuard;
+use crate::salvage::Salvage;
+use crate::schema_dump::{dump_schema, resolved_schema, schema_fingerprint};
+use crate::schema_visitor::{field_metadata, replace_field_schema, schema_path};
 
 mod any;
 mod arguments;
+mod async_validation;
 mod bool;
 mod bytes;
 mod call;
 mod callable;
+mod chain;
+mod counter;
+mod dataclass;
 mod date;
 mod datetime;
+mod decimal;
+mod defaultdict;
+mod deque;
 mod dict;
 mod float;
+mod fraction;
 mod frozenset;
 mod function;
+mod generator;
 mod int;
+mod int_flag;
 mod is_instance;
+mod is_subclass;
+mod json;
+mod json_lines;
 mod list;
 mod literal;
+mod loc_prefix;
 mod new_class;
 mod none;
 mod nullable;
+mod ordered_dict;
+mod path;
+mod pattern;
+mod predicate;
 mod recursive;
+mod secret;
 mod set;
 mod string;
 mod time;
@@ -40,114 +74,368 @@ mod timedelta;
 mod tuple;
 mod typed_dict;
 mod union;
+mod uuid;
+mod with_default;
+mod zoneinfo;
 
+use async_validation::AsyncValidation;
+pub use function::ValidationInfo;
+pub use generator::ValidatorIterator;
+pub use json_lines::JsonLinesIterator;
+pub(crate) use new_class::build_config;
+pub use secret::{SecretBytes, SecretStr};
+pub(crate) use string::resolve_regex_engine;
+
+/// number of values `generate_example` tries before giving up
+const EXAMPLE_ATTEMPTS: usize = 100;
+
+/// Validation only needs `&self`, the validator tree isn't changed once it's built (except by `replace_field` which
+/// python guards with a runtime borrow check) and python objects in it are only used while holding the GIL,
+/// so a single `SchemaValidator` can be shared by many threads, see `assert_send_sync` below
 #[pyclass(module = "pydantic_core._pydantic_core")]
 #[derive(Debug, Clone)]
 pub struct SchemaValidator {
     validator: CombinedValidator,
-    slots: Vec<CombinedValidator>,
+    // shared with values which validate lazily, e.g. generators, see `Extra::shared_slots`
+    slots: Arc<Vec<CombinedValidator>>,
     schema: PyObject,
+    config: Option<Py<PyDict>>,
     title: PyObject,
+    max_errors: Option<usize>,
+    /// called with `title` and `mode` keyword arguments before validation starts
+    #[pyo3(get, set)]
+    on_validate_start: Option<PyObject>,
+    /// called with `title`, `mode`, `duration` in seconds, `success` and `error` keyword arguments once
+    /// validation has finished, `error` is the exception raised or `None`
+    #[pyo3(get, set)]
+    on_validate_end: Option<PyObject>,
 }
 
 #[pymethods]
 impl SchemaValidator {
     #[new]
     pub fn py_new(py: Python, schema: &PyAny, config: Option<&PyDict>) -> PyResult<Self> {
-        let self_schema = Self::get_self_schema(py);
-
-        let schema_obj = self_schema
-            .validator
-            .validate(
-                py,
-                schema,
-                &Extra::default(),
-                &self_schema.slots,
-                &mut RecursionGuard::default(),
-            )
-            .map_err(|e| SchemaError::from_val_error(py, e))?;
-        let schema = schema_obj.as_ref(py);
+        Self::build(py, schema, config)
+    }
 
-        let mut build_context = BuildContext::default();
-        let mut validator = build_validator(schema, config, &mut build_context)?;
-        validator.complete(&build_context)?;
-        let slots = build_context.into_slots()?;
-        let title = validator.get_name().into_py(py);
-        Ok(Self {
-            validator,
-            slots,
-            schema: schema.into_py(py),
-            title,
-        })
+    /// Build a validator from a schema serialized as JSON, the JSON is validated by the self-schema directly
+    /// so the schema is only created as python objects once it's valid
+    #[staticmethod]
+    pub fn from_json(py: Python, schema_json: &PyAny, config: Option<&PyDict>) -> PyResult<Self> {
+        match parse_json(schema_json)? {
+            Ok(json_input) => Self::build(py, &json_input, config),
+            Err(e) => py_error!("Invalid schema JSON: {}", e),
+        }
     }
 
+    /// Pickling rebuilds the validator from the schema and config
     pub fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
-        let args = (self.schema.as_ref(py),);
+        let args = (
+            self.schema.as_ref(py),
+            self.config.as_ref().map(|config| config.as_ref(py)),
+        );
         let cls = Py::new(py, self.to_owned())?.getattr(py, "__class__")?;
         Ok((cls, args).into_py(py))
     }
 
+    pub fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    /// The validator tree isn't changed after it's built, so a clone behaves the same as rebuilding it
+    pub fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+        self.clone()
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn validate_python(
         &self,
         py: Python,
         input: &PyAny,
         strict: Option<bool>,
         context: Option<&PyAny>,
+        timeout: Option<f64>,
+        allow_partial: Option<bool>,
+        fail_fast: Option<bool>,
+        max_errors: Option<usize>,
     ) -> PyResult<PyObject> {
-        let r = self.validator.validate(
-            py,
-            input,
-            &Extra::new(strict, context),
-            &self.slots,
-            &mut RecursionGuard::default(),
-        );
-        r.map_err(|e| self.prepare_validation_err(py, e))
+        let error_limit = self.error_limit(max_errors);
+        let extra = Extra {
+            deadline: deadline_from_timeout(timeout)?,
+            partial: allow_partial.unwrap_or(false),
+            fail_fast: fail_fast.unwrap_or(false),
+            error_limit: error_limit.as_ref(),
+            slots: Some(&self.slots),
+            ..Extra::new(strict, context)
+        };
+        self.run_with_hooks(py, "python", || {
+            let r = self
+                .validator
+                .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default());
+            r.map_err(|e| self.prepare_validation_err(py, e, error_limit.as_ref()))
+        })
     }
 
-    pub fn isinstance_python(
+    /// Returns an awaitable which validates `input` in a worker thread, so function validators may return coroutines
+    /// which are run on `event_loop`, by default the running event loop
+    pub fn validate_python_async(
+        slf: PyRef<Self>,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        event_loop: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let event_loop = match event_loop {
+            Some(event_loop) => event_loop,
+            None => py.import("asyncio")?.call_method0(intern!(py, "get_running_loop"))?,
+        };
+        let validation = AsyncValidation::new(slf.into(), input, strict, context, event_loop);
+        let future = event_loop.call_method1(intern!(py, "run_in_executor"), (py.None(), validation))?;
+        Ok(future.into_py(py))
+    }
+
+    /// Like `validate_python` but returns a `ValidationResult` instead of raising a `ValidationError`
+    pub fn validate_python_result(
         &self,
         py: Python,
         input: &PyAny,
         strict: Option<bool>,
         context: Option<&PyAny>,
-    ) -> PyResult<bool> {
-        match self.validator.validate(
-            py,
-            input,
-            &Extra::new(strict, context),
-            &self.slots,
-            &mut RecursionGuard::default(),
-        ) {
-            Ok(_) => Ok(true),
+    ) -> PyResult<ValidationResult> {
+        let error_limit = self.error_limit(None);
+        let extra = Extra {
+            error_limit: error_limit.as_ref(),
+            slots: Some(&self.slots),
+            ..Extra::new(strict, context)
+        };
+        self.run_with_hooks(py, "python", || {
+            let r = self
+                .validator
+                .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default());
+            ValidationResult::from_val_result(py, self.title.clone_ref(py), r, error_limit.as_ref())
+        })
+    }
+
+    /// Like `validate_python` but also returns a list of the coercions performed on typed-dict fields
+    pub fn validate_python_with_coercions(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<(PyObject, PyObject)> {
+        let coercions = Coercions::default();
+        let error_limit = self.error_limit(None);
+        let extra = Extra {
+            coercions: Some(&coercions),
+            error_limit: error_limit.as_ref(),
+            slots: Some(&self.slots),
+            ..Extra::new(strict, context)
+        };
+        let output = self.run_with_hooks(py, "python", || {
+            let r = self
+                .validator
+                .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default());
+            r.map_err(|e| self.prepare_validation_err(py, e, error_limit.as_ref()))
+        })?;
+        Ok((output, coercions.to_py_list(py)?))
+    }
+
+    /// Like `validate_python` but invalid items of lists, dicts, typed-dicts etc. are replaced by their default or
+    /// `None` instead of failing, returns the best-effort value and a list of all errors.
+    /// The validation hooks aren't called since salvaging doesn't succeed or fail as a whole
+    pub fn validate_python_salvage(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<(PyObject, PyObject)> {
+        let salvage = Salvage::default();
+        let extra = Extra {
+            salvage: Some(&salvage),
+            slots: Some(&self.slots),
+            ..Extra::new(strict, context)
+        };
+        let r = self
+            .validator
+            .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default());
+        match r {
+            Ok(output) => Ok((output, salvage.to_py_list(py)?)),
+            // nothing could be salvaged, e.g. the input isn't a list at all
+            Err(ValError::LineErrors(line_errors)) => {
+                salvage.record(py, line_errors);
+                Ok((py.None(), salvage.to_py_list(py)?))
+            }
             Err(ValError::InternalErr(err)) => Err(err),
-            _ => Ok(false),
         }
     }
 
+    /// Validate each item of `inputs`, returning a `ValidationResult` for every item so invalid items don't
+    /// stop the rest being validated. With `json` the items are JSON strings or bytes, with `parallel_parse` too
+    /// they're parsed on multiple threads with the GIL released. Only parsing is parallel, validation builds python
+    /// objects so it holds the GIL and validates the parsed items one at a time
+    pub fn validate_many(
+        &self,
+        py: Python,
+        inputs: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        json: Option<bool>,
+        parallel_parse: Option<bool>,
+    ) -> PyResult<Vec<ValidationResult>> {
+        let inputs = inputs.iter()?.collect::<PyResult<Vec<&PyAny>>>()?;
+        let validate_result =
+            |input: &PyAny| self.run_with_hooks(py, "python", || self.validate_many_item(py, input, strict, context));
+        match (json.unwrap_or(false), parallel_parse.unwrap_or(false)) {
+            (false, false) => inputs.into_iter().map(validate_result).collect(),
+            (false, true) => py_error!(PyValueError; "`parallel_parse` is only supported with `json=True`"),
+            (true, parallel_parse) => {
+                let parsed = match parallel_parse {
+                    true => parse_json_parallel(py, &inputs)?,
+                    false => inputs
+                        .iter()
+                        .map(|input| match parse_json(input) {
+                            Ok(parsed) => Ok(Some(parsed)),
+                            // the input isn't str, bytes etc., reported as a `json_type` error for the item
+                            Err(err) if err.is_instance_of::<PyTypeError>(py) => Ok(None),
+                            Err(err) => Err(err),
+                        })
+                        .collect::<PyResult<_>>()?,
+                };
+                inputs
+                    .into_iter()
+                    .zip(parsed)
+                    .map(|(input, parsed)| {
+                        self.run_with_hooks(py, "json", || match parsed {
+                            None => {
+                                let r = Err(ValError::new(ErrorKind::JsonType, input));
+                                ValidationResult::from_val_result(py, self.title.clone_ref(py), r, None)
+                            }
+                            Some(Ok(json_input)) => self.validate_many_item(py, &json_input, strict, context),
+                            Some(Err(e)) => {
+                                let line_err =
+                                    ValLineError::new(ErrorKind::InvalidJson { error: e.to_string() }, input);
+                                let r = Err(ValError::LineErrors(vec![line_err]));
+                                ValidationResult::from_val_result(py, self.title.clone_ref(py), r, None)
+                            }
+                        })
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    pub fn isinstance_python(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<bool> {
+        self.run_with_hooks(py, "python", || {
+            match self.validator.validate(
+                py,
+                input,
+                // only whether the input is valid matters, so there's no need to collect all errors
+                &Extra {
+                    fail_fast: true,
+                    slots: Some(&self.slots),
+                    ..Extra::new(strict, context)
+                },
+                &self.slots,
+                &mut RecursionGuard::default(),
+            ) {
+                Ok(_) => Ok(true),
+                Err(ValError::InternalErr(err)) => Err(err),
+                _ => Ok(false),
+            }
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn validate_json(
         &self,
         py: Python,
         input: &PyAny,
         strict: Option<bool>,
         context: Option<&PyAny>,
+        timeout: Option<f64>,
+        allow_partial: Option<bool>,
+        fail_fast: Option<bool>,
+        max_errors: Option<usize>,
     ) -> PyResult<PyObject> {
-        match parse_json(input)? {
-            Ok(input) => {
-                let r = self.validator.validate(
-                    py,
-                    &input,
-                    &Extra::new(strict, context),
-                    &self.slots,
-                    &mut RecursionGuard::default(),
-                );
-                r.map_err(|e| self.prepare_validation_err(py, e))
-            }
-            Err(e) => {
-                let line_err = ValLineError::new(ErrorKind::InvalidJson { error: e.to_string() }, input);
-                let err = ValError::LineErrors(vec![line_err]);
-                Err(self.prepare_validation_err(py, err))
-            }
-        }
+        let error_limit = self.error_limit(max_errors);
+        let extra = Extra {
+            deadline: deadline_from_timeout(timeout)?,
+            partial: allow_partial.unwrap_or(false),
+            fail_fast: fail_fast.unwrap_or(false),
+            error_limit: error_limit.as_ref(),
+            slots: Some(&self.slots),
+            ..Extra::new(strict, context)
+        };
+        self.run_with_hooks(py, "json", || {
+            self.validate_parsed_json(py, input, parse_json(input)?, &extra)
+        })
+    }
+
+    /// Like `validate_json` but reads the JSON incrementally from a path or file-like object
+    #[allow(clippy::too_many_arguments)]
+    pub fn validate_json_stream(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        timeout: Option<f64>,
+        allow_partial: Option<bool>,
+        fail_fast: Option<bool>,
+        max_errors: Option<usize>,
+    ) -> PyResult<PyObject> {
+        let error_limit = self.error_limit(max_errors);
+        let extra = Extra {
+            deadline: deadline_from_timeout(timeout)?,
+            partial: allow_partial.unwrap_or(false),
+            fail_fast: fail_fast.unwrap_or(false),
+            error_limit: error_limit.as_ref(),
+            slots: Some(&self.slots),
+            ..Extra::new(strict, context)
+        };
+        self.run_with_hooks(py, "json", || {
+            self.validate_parsed_json(py, input, parse_json_stream(input)?, &extra)
+        })
+    }
+
+    /// Iterate over newline delimited JSON ("JSON Lines") from `str`, `bytes` or a file-like object, each line is
+    /// validated as it's consumed
+    pub fn iter_json_lines(
+        slf: PyRef<Self>,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<JsonLinesIterator> {
+        JsonLinesIterator::new(slf.into(), input, strict, context)
+    }
+
+    /// Validate data where every leaf value is a string, e.g. query parameters, form data or environment variables,
+    /// validation is always in lax mode so validators apply the same string coercions as with JSON
+    pub fn validate_strings(&self, py: Python, input: &PyAny, context: Option<&PyAny>) -> PyResult<PyObject> {
+        self.run_with_hooks(py, "strings", || {
+            let error_limit = self.error_limit(None);
+            let json_input =
+                string_data_as_json(input).map_err(|e| self.prepare_validation_err(py, e, error_limit.as_ref()))?;
+            let extra = Extra {
+                strings: true,
+                error_limit: error_limit.as_ref(),
+                slots: Some(&self.slots),
+                ..Extra::new(Some(false), context)
+            };
+            let r = self
+                .validator
+                .validate(py, &json_input, &extra, &self.slots, &mut RecursionGuard::default());
+            r.map_err(|e| self.prepare_validation_err(py, e, error_limit.as_ref()))
+        })
     }
 
     pub fn isinstance_json(
@@ -157,12 +445,16 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&PyAny>,
     ) -> PyResult<bool> {
-        match parse_json(input)? {
+        self.run_with_hooks(py, "json", || match parse_json(input)? {
             Ok(input) => {
                 match self.validator.validate(
                     py,
                     &input,
-                    &Extra::new(strict, context),
+                    &Extra {
+                        fail_fast: true,
+                        slots: Some(&self.slots),
+                        ..Extra::new(strict, context)
+                    },
                     &self.slots,
                     &mut RecursionGuard::default(),
                 ) {
@@ -172,28 +464,225 @@ impl SchemaValidator {
                 }
             }
             Err(_) => Ok(false),
-        }
+        })
     }
 
+    /// Validate `input` against the schema of a single field, `obj` is either the data dict, in which case it's
+    /// returned updated, or a model instance whose `__dict__` and `__fields_set__` are updated in place
     pub fn validate_assignment(
         &self,
         py: Python,
+        obj: &PyAny,
         field: String,
         input: &PyAny,
-        data: &PyDict,
         strict: Option<bool>,
         context: Option<&PyAny>,
     ) -> PyResult<PyObject> {
+        let (data, model) = match obj.cast_as::<PyDict>() {
+            Ok(data) => (data, None),
+            Err(_) => (obj.getattr(intern!(py, "__dict__"))?.cast_as::<PyDict>()?, Some(obj)),
+        };
+        let error_limit = self.error_limit(None);
         let extra = Extra {
             data: Some(data),
             field: Some(field.as_str()),
             strict,
             context,
+            coercions: None,
+            salvage: None,
+            deadline: None,
+            loc: None,
+            strings: false,
+            partial: false,
+            fail_fast: false,
+            event_loop: None,
+            error_limit: error_limit.as_ref(),
+            slots: Some(&self.slots),
         };
-        let r = self
-            .validator
-            .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default());
-        r.map_err(|e| self.prepare_validation_err(py, e))
+        let output = self.run_with_hooks(py, "assignment", || {
+            let r = self
+                .validator
+                .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default());
+            r.map_err(|e| self.prepare_validation_err(py, e, error_limit.as_ref()))
+        })?;
+        match model {
+            Some(model) => {
+                // `data` is the model's `__dict__` so it's already been updated
+                if let Ok(fields_set) = model.getattr(intern!(py, "__fields_set__")) {
+                    fields_set.call_method1(intern!(py, "add"), (field,))?;
+                }
+                Ok(model.into_py(py))
+            }
+            None => Ok(output),
+        }
+    }
+
+    /// Build a typed-dict or model from already trusted `data` without running any validators, missing fields are
+    /// filled from their `default` or `default_factory`, `fields_set` defaults to the keys of `data`
+    pub fn construct(&self, py: Python, data: &PyDict, fields_set: Option<&PyAny>) -> PyResult<PyObject> {
+        self.validator.construct(py, data, fields_set, &self.slots)
+    }
+
+    /// Generate a random valid value for the schema, with `seed` the same value is generated each time
+    pub fn generate_example(&self, py: Python, seed: Option<u64>) -> PyResult<PyObject> {
+        let schema = dump_schema(py, self.schema.as_ref(py), "python", true)?;
+        let schema: &PyDict = schema.as_ref(py).cast_as()?;
+        let mut generator = ExampleGenerator::new(seed);
+        // generated inputs can be invalid, e.g. where function validators are used, so retry a few times
+        for _ in 0..EXAMPLE_ATTEMPTS {
+            let input = generator.generate(schema)?;
+            match self.validator.validate(
+                py,
+                input,
+                &Extra::default(),
+                &self.slots,
+                &mut RecursionGuard::default(),
+            ) {
+                Ok(output) => return Ok(output),
+                Err(ValError::InternalErr(err)) => return Err(err),
+                Err(ValError::LineErrors(_)) => continue,
+            }
+        }
+        py_error!(PyValueError; "Unable to generate a valid example in {} attempts", EXAMPLE_ATTEMPTS)
+    }
+
+    /// The default of the typed-dict field at `field_path`, a field name or a sequence of names through nested
+    /// typed-dicts and models. Returns `None` if the field has no default, otherwise a tuple of the default so a
+    /// default of `None` can be told apart
+    pub fn get_default_value(
+        &self,
+        py: Python,
+        field_path: &PyAny,
+        validate: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<Option<PyObject>> {
+        let field_path = extract_field_path(field_path)?;
+        let (default, validator) = match self.validator.find_field(py, &field_path, &self.slots)? {
+            Some(field) => field,
+            None => return field_not_found(&field_path),
+        };
+        let default = match default {
+            Some(default) => default,
+            None => return Ok(None),
+        };
+        let error_limit = self.error_limit(None);
+        let extra = Extra {
+            error_limit: error_limit.as_ref(),
+            slots: Some(&self.slots),
+            ..Extra::new(None, context)
+        };
+        let default = match validate.unwrap_or(false) {
+            true => validator
+                .validate(
+                    py,
+                    default.as_ref(py),
+                    &extra,
+                    &self.slots,
+                    &mut RecursionGuard::default(),
+                )
+                .map_err(|e| {
+                    let e = field_path
+                        .iter()
+                        .rev()
+                        .fold(e, |e, name| e.with_outer_location(name.as_str().into()));
+                    self.prepare_validation_err(py, e, error_limit.as_ref())
+                })?,
+            false => default,
+        };
+        Ok(Some(PyTuple::new(py, [default]).into_py(py)))
+    }
+
+    /// Rebuild the validator of a single field from `schema`, without rebuilding the rest of the validator, e.g. after
+    /// a dynamic enum gains members. `field_path` is as for `get_default_value`, dataclass fields can be replaced too.
+    /// The field's schema is built with the validator's config and can't use or define refs.
+    pub fn replace_field(&mut self, py: Python, field_path: &PyAny, schema: &PyAny) -> PyResult<()> {
+        let field_path = extract_field_path(field_path)?;
+        let field_schema_obj = Self::validate_schema(py, schema)?;
+        let field_schema = field_schema_obj.as_ref(py);
+        let new_schema = match replace_field_schema(self.schema.as_ref(py), &field_path, field_schema)? {
+            Some(new_schema) => new_schema,
+            None => return field_not_found(&field_path),
+        };
+
+        let config = self.config.as_ref().map(|config| config.as_ref(py));
+        let mut build_context = BuildContext::default();
+        let mut validator = build_validator(field_schema, config, &mut build_context)
+            .map_err(|err| build_context.located_error(py, field_schema, err))?;
+        validator.complete(&build_context)?;
+        if !build_context.into_slots()?.is_empty() {
+            return py_error!("Replacement field schemas can't define refs");
+        }
+
+        let mut replacement = self.validator.replace_field(&field_path, validator);
+        // refs are followed here since validators can't borrow the slots mutably while they're inside one
+        while let FieldReplacement::InSlot(slot_id, rest, validator) = replacement {
+            // values still validating lazily keep the slots they were created with
+            replacement = match Arc::make_mut(&mut self.slots).get_mut(slot_id) {
+                Some(slot) => slot.replace_field(&rest, *validator),
+                None => return py_error!(PyRuntimeError; "Slots Error: slot {} not found", slot_id),
+            };
+        }
+        match replacement {
+            FieldReplacement::Replaced => {
+                self.schema = new_schema.into_py(py);
+                Ok(())
+            }
+            _ => field_not_found(&field_path),
+        }
+    }
+
+    /// Export the normalized schema, as python objects or a JSON string, `recursive-ref` schemas
+    /// are replaced by the schema they point to if `inline_refs` is true
+    pub fn dump_schema(&self, py: Python, format: Option<&str>, inline_refs: Option<bool>) -> PyResult<PyObject> {
+        dump_schema(
+            py,
+            self.schema.as_ref(py),
+            format.unwrap_or("python"),
+            inline_refs.unwrap_or(false),
+        )
+    }
+
+    /// The schema as the validator tree sees it, with config and defaults applied, see `resolved_schema`
+    pub fn resolved_schema(&self, py: Python) -> PyResult<PyObject> {
+        let config = self.config.as_ref().map(|config| config.as_ref(py));
+        resolved_schema(py, self.schema.as_ref(py), config)
+    }
+
+    /// Metadata of every typed-dict field in the schema keyed by the field's location, see `field_metadata`
+    pub fn field_metadata(&self, py: Python) -> PyResult<PyObject> {
+        field_metadata(py, self.schema.as_ref(py))
+    }
+
+    /// Perform initialisation which would otherwise happen lazily during the first validation,
+    /// so latency-sensitive applications can pay that cost at startup. Field names, regexes and enum members
+    /// are already prepared when the validator is built.
+    pub fn warmup(&self, py: Python) -> PyResult<()> {
+        Self::get_self_schema(py);
+        datetime_warmup(py)?;
+        input_python_warmup(py);
+        function::function_warmup(py);
+        Ok(())
+    }
+
+    /// A hash of the normalized schema and config, so equal validators can be found and shared
+    pub fn schema_hash(&self, py: Python) -> PyResult<u64> {
+        let mut hasher = DefaultHasher::new();
+        self.fingerprint(py)?.hash(&mut hasher);
+        Ok(hasher.finish())
+    }
+
+    pub fn __hash__(&self, py: Python) -> PyResult<isize> {
+        // shifted so the hash is never -1, which python reserves for errors
+        Ok((self.schema_hash(py)? >> 1) as isize)
+    }
+
+    /// Validators are equal if their normalized schemas and configs are, see `schema_fingerprint`
+    pub fn __richcmp__(&self, py: Python, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        match (op, other.extract::<PyRef<Self>>()) {
+            (CompareOp::Eq, Ok(other)) => Ok((self.fingerprint(py)? == other.fingerprint(py)?).into_py(py)),
+            (CompareOp::Ne, Ok(other)) => Ok((self.fingerprint(py)? != other.fingerprint(py)?).into_py(py)),
+            _ => Ok(py.NotImplemented()),
+        }
     }
 
     pub fn __repr__(&self) -> String {
@@ -207,7 +696,65 @@ impl SchemaValidator {
 
 static SCHEMA_DEFINITION: GILOnceCell<SchemaValidator> = GILOnceCell::new();
 
+// fails to compile if anything added to the validator tree can't be shared between threads,
+// e.g. a `Cell` or `RefCell`, per-validation state belongs in `Extra` instead
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SchemaValidator>();
+    assert_send_sync::<CombinedValidator>();
+};
+
 impl SchemaValidator {
+    /// Validate `schema` with the self-schema, then build the validator from the validated schema
+    fn build<'data>(py: Python<'data>, schema: &'data impl Input<'data>, config: Option<&PyDict>) -> PyResult<Self> {
+        let schema_obj = Self::validate_schema(py, schema)?;
+        let schema = schema_obj.as_ref(py);
+
+        let mut build_context = BuildContext::default();
+        let mut validator = build_validator(schema, config, &mut build_context)
+            .map_err(|err| build_context.located_error(py, schema, err))?;
+        validator.complete(&build_context)?;
+        let slots = Arc::new(build_context.into_slots()?);
+        let title = match config {
+            Some(config) => config.get_as(intern!(py, "title"))?,
+            None => None,
+        }
+        .unwrap_or_else(|| validator.get_name().to_string())
+        .into_py(py);
+        let (max_errors, on_validate_start, on_validate_end) = match config {
+            Some(config) => (
+                config.get_as(intern!(py, "max_errors"))?,
+                config.get_as(intern!(py, "on_validate_start"))?,
+                config.get_as(intern!(py, "on_validate_end"))?,
+            ),
+            None => (None, None, None),
+        };
+        Ok(Self {
+            validator,
+            slots,
+            schema: schema.into_py(py),
+            config: config.map(|config| config.into_py(py)),
+            title,
+            max_errors,
+            on_validate_start,
+            on_validate_end,
+        })
+    }
+
+    fn validate_schema<'data>(py: Python<'data>, schema: &'data impl Input<'data>) -> PyResult<PyObject> {
+        let self_schema = Self::get_self_schema(py);
+        self_schema
+            .validator
+            .validate(
+                py,
+                schema,
+                &Extra::default(),
+                &self_schema.slots,
+                &mut RecursionGuard::default(),
+            )
+            .map_err(|e| SchemaError::from_val_error(py, e))
+    }
+
     fn get_self_schema(py: Python) -> &Self {
         SCHEMA_DEFINITION.get_or_init(py, || Self::build_self_schema(py).unwrap())
     }
@@ -225,31 +772,170 @@ impl SchemaValidator {
         };
         Ok(Self {
             validator,
-            slots: build_context.into_slots()?,
+            slots: Arc::new(build_context.into_slots()?),
             schema: py.None(),
+            config: None,
             title: "Self Schema".into_py(py),
+            max_errors: None,
+            on_validate_start: None,
+            on_validate_end: None,
         })
     }
 
-    fn prepare_validation_err(&self, py: Python, error: ValError) -> PyErr {
-        ValidationError::from_val_error(py, self.title.clone_ref(py), error)
+    /// Run `validate` between calls to the `on_validate_start` and `on_validate_end` hooks, `mode` says which
+    /// method is validating, e.g. "python" or "json". Errors raised by the hooks are propagated.
+    /// `success` is whether the input was valid, `error` is the exception raised, if any, so it's `None` for methods
+    /// which report invalid input without raising, e.g. `isinstance_python` and `validate_python_result`
+    pub(crate) fn run_with_hooks<T: HookResult>(
+        &self,
+        py: Python,
+        mode: &str,
+        validate: impl FnOnce() -> PyResult<T>,
+    ) -> PyResult<T> {
+        if self.on_validate_start.is_none() && self.on_validate_end.is_none() {
+            return validate();
+        }
+        let kwargs = PyDict::new(py);
+        kwargs.set_item(intern!(py, "title"), self.title.as_ref(py))?;
+        kwargs.set_item(intern!(py, "mode"), mode)?;
+        if let Some(ref on_validate_start) = self.on_validate_start {
+            on_validate_start.call(py, (), Some(kwargs.copy()?))?;
+        }
+        let start = Instant::now();
+        let result = validate();
+        if let Some(ref on_validate_end) = self.on_validate_end {
+            kwargs.set_item(intern!(py, "duration"), start.elapsed().as_secs_f64())?;
+            let success = matches!(result, Ok(ref value) if value.is_valid());
+            kwargs.set_item(intern!(py, "success"), success)?;
+            match result {
+                Ok(_) => kwargs.set_item(intern!(py, "error"), py.None())?,
+                Err(ref err) => kwargs.set_item(intern!(py, "error"), err.value(py))?,
+            }
+            on_validate_end.call(py, (), Some(kwargs))?;
+        }
+        result
+    }
+
+    /// The limit on line errors for one call, `max_errors` is the per-call limit which takes precedence over
+    /// `max_errors` from config
+    fn error_limit(&self, max_errors: Option<usize>) -> Option<ErrorLimit> {
+        max_errors.or(self.max_errors).map(ErrorLimit::new)
+    }
+
+    /// `error_limit` should be the limit `error` was collected with, so omitted errors are counted
+    fn prepare_validation_err(&self, py: Python, error: ValError, error_limit: Option<&ErrorLimit>) -> PyErr {
+        ValidationError::from_val_error_limited(py, self.title.clone_ref(py), error, error_limit)
+    }
+
+    /// Validate an item of `validate_many`, `max_errors` applies to each item separately
+    fn validate_many_item<'data>(
+        &'data self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<ValidationResult> {
+        let error_limit = self.error_limit(None);
+        let extra = Extra {
+            error_limit: error_limit.as_ref(),
+            slots: Some(&self.slots),
+            ..Extra::new(strict, context)
+        };
+        let r = self
+            .validator
+            .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default());
+        ValidationResult::from_val_result(py, self.title.clone_ref(py), r, error_limit.as_ref())
+    }
+
+    /// `input` is the original python input, it's used as the input value of JSON parsing errors
+    fn validate_parsed_json(
+        &self,
+        py: Python,
+        input: &PyAny,
+        parsed: serde_json::Result<JsonInput>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        match parsed {
+            Ok(json_input) => {
+                let r = self
+                    .validator
+                    .validate(py, &json_input, extra, &self.slots, &mut RecursionGuard::default());
+                r.map_err(|e| self.prepare_validation_err(py, e, extra.error_limit))
+            }
+            Err(e) => {
+                let line_err = ValLineError::new(ErrorKind::InvalidJson { error: e.to_string() }, input);
+                let err = ValError::LineErrors(vec![line_err]);
+                Err(self.prepare_validation_err(py, err, None))
+            }
+        }
+    }
+
+    /// See `schema_fingerprint`, used by `schema_hash` and comparisons
+    fn fingerprint(&self, py: Python) -> PyResult<String> {
+        let config = self.config.as_ref().map(|config| config.as_ref(py));
+        schema_fingerprint(self.schema.as_ref(py), config)
+    }
+}
+
+/// The outcome of a validation method as reported to the `on_validate_end` hook
+pub(crate) trait HookResult {
+    fn is_valid(&self) -> bool;
+}
+
+impl HookResult for PyObject {
+    fn is_valid(&self) -> bool {
+        true
+    }
+}
+
+impl HookResult for bool {
+    fn is_valid(&self) -> bool {
+        *self
+    }
+}
+
+impl HookResult for ValidationResult {
+    fn is_valid(&self) -> bool {
+        self.ok()
     }
 }
 
 fn parse_json(input: &PyAny) -> PyResult<serde_json::Result<JsonInput>> {
     if let Ok(py_bytes) = input.cast_as::<PyBytes>() {
-        Ok(serde_json::from_slice(py_bytes.as_bytes()))
+        Ok(parse_json_slice(input.py(), py_bytes.as_bytes()))
     } else if let Ok(py_str) = input.cast_as::<PyString>() {
-        let str = py_str.to_str()?;
-        Ok(serde_json::from_str(str))
+        Ok(parse_json_slice(input.py(), py_str.to_str()?.as_bytes()))
     } else if let Ok(py_byte_array) = input.cast_as::<PyByteArray>() {
         Ok(serde_json::from_slice(unsafe { py_byte_array.as_bytes() }))
+    } else if let Some(result) = parse_json_buffer(input) {
+        Ok(result)
     } else {
         let input_type = input.get_type().name().unwrap_or("unknown");
-        py_error!(PyTypeError; "JSON input should be str, bytes or bytearray, not {}", input_type)
+        py_error!(PyTypeError; "JSON input should be str, bytes, bytearray or memoryview, not {}", input_type)
+    }
+}
+
+/// The outcome of `Validator::replace_field`
+pub enum FieldReplacement {
+    Replaced,
+    NotFound,
+    /// the field is in the validator in a slot, `SchemaValidator.replace_field` continues from there
+    /// with the rest of the path
+    InSlot(usize, Vec<String>, Box<CombinedValidator>),
+}
+
+/// A field name or a sequence of names through nested typed-dicts and models
+fn extract_field_path(field_path: &PyAny) -> PyResult<Vec<String>> {
+    match field_path.extract::<String>() {
+        Ok(field_name) => Ok(vec![field_name]),
+        Err(_) => field_path.extract(),
     }
 }
 
+fn field_not_found<T>(field_path: &[String]) -> PyResult<T> {
+    py_error!(PyKeyError; "Field not found: {}", field_path.join(" -> "))
+}
+
 pub trait BuildValidator: Sized {
     const EXPECTED_TYPE: &'static str;
 
@@ -269,13 +955,13 @@ fn build_single_validator<'a, T: BuildValidator>(
     let val: CombinedValidator = if let Some(schema_ref) = schema_dict.get_as::<String>(intern!(py, "ref"))? {
         let slot_id = build_context.prepare_slot(schema_ref)?;
         let inner_val = T::build(schema_dict, config, build_context)
-            .map_err(|err| SchemaError::new_err(format!("Error building \"{}\" validator:\n  {}", val_type, err)))?;
+            .map_err(|err| build_context.build_error(val_type, schema_dict, err))?;
         let name = inner_val.get_name().to_string();
         build_context.complete_slot(slot_id, inner_val)?;
         recursive::RecursiveContainerValidator::create(slot_id, name)
     } else {
         T::build(schema_dict, config, build_context)
-            .map_err(|err| SchemaError::new_err(format!("Error building \"{}\" validator:\n  {}", val_type, err)))?
+            .map_err(|err| build_context.build_error(val_type, schema_dict, err))?
     };
 
     Ok(val)
@@ -320,10 +1006,15 @@ pub fn build_validator<'a>(
         // unions
         union::UnionValidator,
         union::TaggedUnionValidator,
+        // loc-prefix - prefix error locations
+        loc_prefix::LocPrefixValidator,
         // nullables
         nullable::NullableValidator,
+        // defaults
+        with_default::WithDefaultValidator,
         // model classes
         new_class::NewClassValidator,
+        dataclass::DataclassValidator,
         // strings
         string::StrValidator,
         // integers
@@ -332,28 +1023,56 @@ pub fn build_validator<'a>(
         bool::BoolValidator,
         // floats
         float::FloatValidator,
+        // fractions
+        fraction::FractionValidator,
+        // decimals
+        decimal::DecimalValidator,
+        // uuids
+        uuid::UuidValidator,
+        // timezone names
+        zoneinfo::ZoneInfoValidator,
+        int_flag::IntFlagValidator,
         // tuples
         tuple::TupleBuilder,
         // list/arrays
         list::ListValidator,
+        // deques
+        deque::DequeValidator,
         // sets - unique lists
         set::SetValidator,
         // dicts/objects (recursive)
         dict::DictValidator,
+        // dict variants
+        defaultdict::DefaultDictValidator,
+        ordered_dict::OrderedDictValidator,
+        counter::CounterValidator,
         // None/null
         none::NoneValidator,
         // functions - before, after, plain & wrap
         function::FunctionBuilder,
         // function call - validation around a function call
         call::CallValidator,
+        // chain - validators applied in sequence
+        chain::ChainValidator,
+        // predicate - a boolean check with a custom error
+        predicate::PredicateValidator,
         // recursive (self-referencing) models
         recursive::RecursiveRefValidator,
+        recursive::DefinitionsBuilder,
+        recursive::DefinitionRefBuilder,
         // literals
         literal::LiteralBuilder,
         // any
         any::AnyValidator,
         // bytes
         bytes::BytesValidator,
+        // secrets
+        secret::SecretStrValidator,
+        secret::SecretBytesValidator,
+        // paths
+        path::PathValidator,
+        // compiled regex patterns
+        pattern::PatternValidator,
         // dates
         date::DateValidator,
         // times
@@ -362,13 +1081,18 @@ pub fn build_validator<'a>(
         datetime::DateTimeValidator,
         // frozensets
         frozenset::FrozenSetValidator,
+        // generators
+        generator::GeneratorValidator,
         // timedelta
         timedelta::TimeDeltaValidator,
         // introspection types
         is_instance::IsInstanceValidator,
+        is_subclass::IsSubclassValidator,
         callable::CallableValidator,
         // arguments
         arguments::ArgumentsValidator,
+        // json
+        json::JsonValidator,
     )
 }
 
@@ -385,6 +1109,26 @@ pub struct Extra<'a> {
     pub strict: Option<bool>,
     /// context used in validator functions
     pub context: Option<&'a PyAny>,
+    /// if set, coercions performed on typed-dict fields are recorded here
+    pub coercions: Option<&'a Coercions>,
+    /// if set, containers record the errors of invalid items here and return a best-effort value instead of failing
+    pub salvage: Option<&'a Salvage>,
+    /// end of the time budget given by `timeout`, only used to tell function validators how much time remains
+    pub deadline: Option<Instant>,
+    /// location of the value currently being validated, passed to function validators via `ValidationInfo`
+    pub loc: Option<&'a CurrentLoc<'a>>,
+    /// whether the input came from `validate_strings`, reported to function validators as the mode
+    pub strings: bool,
+    /// whether missing typed-dict fields are omitted from the output instead of being an error or using defaults
+    pub partial: bool,
+    /// whether validation stops at the first error, in addition to the `fail_fast` option of collections
+    pub fail_fast: bool,
+    /// set by `validate_python_async`, coroutines returned by function validators are run on this event loop
+    pub event_loop: Option<&'a PyAny>,
+    /// if set, collections stop keeping errors once they hold `max_errors`, see `ErrorLimit`
+    pub error_limit: Option<&'a ErrorLimit>,
+    /// the slots being validated with, see `shared_slots`
+    pub slots: Option<&'a Arc<Vec<CombinedValidator>>>,
 }
 
 impl<'a> Extra<'a> {
@@ -404,8 +1148,84 @@ impl<'a> Extra<'a> {
             field: self.field,
             strict: Some(true),
             context: self.context,
+            coercions: self.coercions,
+            salvage: self.salvage,
+            deadline: self.deadline,
+            loc: self.loc,
+            strings: self.strings,
+            partial: self.partial,
+            fail_fast: self.fail_fast,
+            event_loop: self.event_loop,
+            error_limit: self.error_limit,
+            slots: self.slots,
+        }
+    }
+
+    /// Used where validation of an input is attempted and the error is used, e.g. union choices, so that a
+    /// best-effort value isn't mistaken for success
+    pub fn without_salvage(&self) -> Self {
+        Self {
+            data: self.data,
+            field: self.field,
+            strict: self.strict,
+            context: self.context,
+            coercions: self.coercions,
+            salvage: None,
+            deadline: self.deadline,
+            loc: self.loc,
+            strings: self.strings,
+            partial: self.partial,
+            fail_fast: self.fail_fast,
+            event_loop: self.event_loop,
+            error_limit: self.error_limit,
+            slots: self.slots,
         }
     }
+
+    /// The slots for values which validate lazily after validation has returned, e.g. generators, they're shared
+    /// where possible, otherwise `slots` is copied
+    pub fn shared_slots(&self, slots: &[CombinedValidator]) -> Arc<Vec<CombinedValidator>> {
+        match self.slots {
+            Some(shared) => shared.clone(),
+            None => Arc::new(slots.to_vec()),
+        }
+    }
+
+    /// Add `error` to the errors of a collection, unless the collection already holds `max_errors` errors
+    pub fn push_error<'d>(&self, errors: &mut Vec<ValLineError<'d>>, error: ValLineError<'d>) {
+        match self.error_limit {
+            Some(error_limit) => error_limit.push(errors, error),
+            None => errors.push(error),
+        }
+    }
+
+    /// Like `push_error` for all errors of an invalid item
+    pub fn extend_errors<'d>(
+        &self,
+        errors: &mut Vec<ValLineError<'d>>,
+        new_errors: impl IntoIterator<Item = ValLineError<'d>>,
+    ) {
+        match self.error_limit {
+            Some(error_limit) => new_errors.into_iter().for_each(|error| error_limit.push(errors, error)),
+            None => errors.extend(new_errors),
+        }
+    }
+
+    /// Seconds until the deadline, zero once it has passed
+    pub fn remaining_time(&self) -> Option<f64> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()).as_secs_f64())
+    }
+}
+
+fn deadline_from_timeout(timeout: Option<f64>) -> PyResult<Option<Instant>> {
+    match timeout {
+        Some(timeout) if timeout.is_finite() && timeout >= 0.0 => {
+            Ok(Instant::now().checked_add(Duration::from_secs_f64(timeout)))
+        }
+        Some(timeout) => py_error!(PyValueError; "timeout should be a finite, non-negative number, not {}", timeout),
+        None => Ok(None),
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -416,10 +1236,15 @@ pub enum CombinedValidator {
     // unions
     Union(union::UnionValidator),
     TaggedUnion(union::TaggedUnionValidator),
+    // loc-prefix - prefix error locations
+    LocPrefix(loc_prefix::LocPrefixValidator),
     // nullables
     Nullable(nullable::NullableValidator),
+    // defaults
+    WithDefault(with_default::WithDefaultValidator),
     // model classes
     ModelClass(new_class::NewClassValidator),
+    Dataclass(dataclass::DataclassValidator),
     // strings
     Str(string::StrValidator),
     StrConstrained(string::StrConstrainedValidator),
@@ -431,8 +1256,19 @@ pub enum CombinedValidator {
     // floats
     Float(float::FloatValidator),
     ConstrainedFloat(float::ConstrainedFloatValidator),
+    // fractions
+    Fraction(fraction::FractionValidator),
+    // decimals
+    Decimal(decimal::DecimalValidator),
+    // uuids
+    Uuid(uuid::UuidValidator),
+    // timezone names
+    ZoneInfo(zoneinfo::ZoneInfoValidator),
+    IntFlag(int_flag::IntFlagValidator),
     // lists
     List(list::ListValidator),
+    // deques
+    Deque(deque::DequeValidator),
     // sets - unique lists
     Set(set::SetValidator),
     // tuples
@@ -440,6 +1276,10 @@ pub enum CombinedValidator {
     TupleVariable(tuple::TupleVariableValidator),
     // dicts/objects (recursive)
     Dict(dict::DictValidator),
+    // dict variants
+    DefaultDict(defaultdict::DefaultDictValidator),
+    OrderedDict(ordered_dict::OrderedDictValidator),
+    Counter(counter::CounterValidator),
     // None/null
     None(none::NoneValidator),
     // functions
@@ -449,6 +1289,10 @@ pub enum CombinedValidator {
     FunctionWrap(function::FunctionWrapValidator),
     // function call - validation around a function call
     FunctionCall(call::CallValidator),
+    // chain - validators applied in sequence
+    Chain(chain::ChainValidator),
+    // predicate - a boolean check with a custom error
+    Predicate(predicate::PredicateValidator),
     // recursive (self-referencing) models
     Recursive(recursive::RecursiveContainerValidator),
     RecursiveRef(recursive::RecursiveRefValidator),
@@ -463,6 +1307,13 @@ pub enum CombinedValidator {
     // bytes
     Bytes(bytes::BytesValidator),
     ConstrainedBytes(bytes::BytesConstrainedValidator),
+    // secrets
+    SecretStr(secret::SecretStrValidator),
+    SecretBytes(secret::SecretBytesValidator),
+    // paths
+    Path(path::PathValidator),
+    // compiled regex patterns
+    Pattern(pattern::PatternValidator),
     // dates
     Date(date::DateValidator),
     // times
@@ -471,13 +1322,18 @@ pub enum CombinedValidator {
     Datetime(datetime::DateTimeValidator),
     // frozensets
     FrozenSet(frozenset::FrozenSetValidator),
+    // generators
+    Generator(generator::GeneratorValidator),
     // timedelta
     Timedelta(timedelta::TimeDeltaValidator),
     // introspection types
     IsInstance(is_instance::IsInstanceValidator),
+    IsSubclass(is_subclass::IsSubclassValidator),
     Callable(callable::CallableValidator),
     // arguments
     Arguments(arguments::ArgumentsValidator),
+    // json
+    Json(json::JsonValidator),
 }
 
 /// This trait must be implemented by all validators, it allows various validators to be accessed consistently,
@@ -506,6 +1362,52 @@ pub trait Validator: Send + Sync + Clone + Debug {
         false
     }
 
+    /// the value to use when there's no input at all, e.g. a missing typed-dict field, only `WithDefaultValidator`
+    /// has a default
+    fn default_value<'s, 'data>(
+        &'s self,
+        _py: Python<'data>,
+        _extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, Option<PyObject>> {
+        Ok(None)
+    }
+
+    /// the default without `validate_default` applied, used by `construct` which doesn't run any validators
+    fn unvalidated_default(&self, _py: Python) -> PyResult<Option<PyObject>> {
+        Ok(None)
+    }
+
+    /// build the output from already trusted `data` without running any validators, defaults are filled in
+    /// for missing fields, only typed-dicts, models and references to them support this
+    fn construct(
+        &self,
+        _py: Python,
+        _data: &PyDict,
+        _fields_set: Option<&PyAny>,
+        _slots: &[CombinedValidator],
+    ) -> PyResult<PyObject> {
+        py_error!(PyTypeError; "construct is not supported by \"{}\" validators", self.get_name())
+    }
+
+    /// find the typed-dict field at `field_path`, through models and nested typed-dicts, returns the field's
+    /// unvalidated default and its validator, used by `SchemaValidator.get_default_value`
+    fn find_field<'s>(
+        &'s self,
+        _py: Python,
+        _field_path: &[String],
+        _slots: &'s [CombinedValidator],
+    ) -> PyResult<Option<(Option<PyObject>, &'s CombinedValidator)>> {
+        Ok(None)
+    }
+
+    /// replace the validator of the field at `field_path`, found in the same way as `find_field`,
+    /// used by `SchemaValidator.replace_field`
+    fn replace_field(&mut self, _field_path: &[String], _validator: CombinedValidator) -> FieldReplacement {
+        FieldReplacement::NotFound
+    }
+
     /// this method must be implemented for any validator which holds references to other validators,
     /// it is used by `RecursiveRefValidator` to set its name
     fn complete(&mut self, _build_context: &BuildContext) -> PyResult<()> {
@@ -519,9 +1421,42 @@ pub trait Validator: Send + Sync + Clone + Debug {
 #[derive(Default, Clone)]
 pub struct BuildContext {
     slots: Vec<(String, Option<CombinedValidator>)>,
+    // the innermost schema which failed to build, its type and the error, see `located_error`
+    failed_schema: Option<(PyObject, String, String)>,
 }
 
 impl BuildContext {
+    /// Wrap an error from building `schema`, the first error recorded is from the innermost schema since
+    /// errors propagate up through the schemas containing it
+    fn build_error(&mut self, val_type: &str, schema: &PyDict, err: PyErr) -> PyErr {
+        let error = err.to_string();
+        let err = SchemaError::new_err(format!("Error building \"{}\" validator:\n  {}", val_type, error));
+        if self.failed_schema.is_none() {
+            self.failed_schema = Some((schema.into_py(schema.py()), val_type.to_string(), error));
+        }
+        err
+    }
+
+    /// Replace an error from building `root_schema` with an error from the innermost schema which failed, including
+    /// the path of keys to it, e.g. `Error building "str" validator at fields -> name -> schema: ...`
+    fn located_error(&self, py: Python, root_schema: &PyAny, err: PyErr) -> PyErr {
+        let (failed_schema, val_type, error) = match self.failed_schema {
+            Some((ref failed_schema, ref val_type, ref error)) => (failed_schema.as_ref(py), val_type, error),
+            None => return err,
+        };
+        let path = match schema_path(root_schema, failed_schema) {
+            Ok(Some(path)) if !path.is_empty() => path,
+            _ => return err,
+        };
+        let path_str = path.iter().map(ToString::to_string).collect::<Vec<_>>().join(" -> ");
+        let message = format!(
+            "Error building \"{}\" validator at {}:\n  {}",
+            val_type, path_str, error
+        );
+        let path: Vec<PyObject> = path.into_iter().map(|item| item.into_py(py)).collect();
+        SchemaError::new_err((message, path))
+    }
+
     /// First of two part process to add a new validator slot, we add the `slot_ref` to the array, but not the
     /// actual `validator`, we can't add the validator until it's build.
     /// We need the `id` to build the validator, hence this two-step process.
@@ -8,12 +8,12 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyTuple, PyType};
 use pyo3::{ffi, intern};
 
-use crate::build_tools::SchemaDict;
+use crate::build_tools::{py_error, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, FieldReplacement, Validator};
 
 #[derive(Debug, Clone)]
 pub struct NewClassValidator {
@@ -43,6 +43,18 @@ impl BuildValidator for NewClassValidator {
 
         let expect_fields_set = validator.ask("return_fields_set");
 
+        // Get the class's `__name__`, not using `class.name()` since it uses `__qualname__`
+        // which is not what we want here
+        let name: String = class.getattr(intern!(py, "__name__"))?.extract()?;
+        // classes using `__slots__` only support weak references if `__weakref__` is one of the slots
+        let require_weakref = config.get_as(intern!(py, "require_weakref"))?.unwrap_or(false);
+        if require_weakref && class.getattr(intern!(py, "__weakrefoffset__"))?.extract::<isize>()? == 0 {
+            return py_error!(
+                "{} instances don't support weak references, add '__weakref__' to its `__slots__`",
+                name
+            );
+        }
+
         Ok(Self {
             // we don't use is_strict here since we don't want validation to be strict in this case if
             // `config.strict` is set, only if this specific field is strict
@@ -50,9 +62,7 @@ impl BuildValidator for NewClassValidator {
             revalidate: config.get_as(intern!(py, "revalidate_models"))?.unwrap_or(false),
             validator: Box::new(validator),
             class: class.into(),
-            // Get the class's `__name__`, not using `class.name()` since it uses `__qualname__`
-            // which is not what we want here
-            name: class.getattr(intern!(py, "__name__"))?.extract()?,
+            name,
             expect_fields_set,
         }
         .into())
@@ -68,6 +78,10 @@ impl Validator for NewClassValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        if extra.field.is_some() {
+            // we're validating assignment, the inner validator updates the model's `__dict__`
+            return self.validator.validate(py, input, extra, slots, recursion_guard);
+        }
         let class = self.class.as_ref(py);
         if input.is_type(class)? {
             if self.revalidate {
@@ -101,9 +115,42 @@ impl Validator for NewClassValidator {
         }
     }
 
+    fn construct(
+        &self,
+        py: Python,
+        data: &PyDict,
+        fields_set: Option<&PyAny>,
+        slots: &[CombinedValidator],
+    ) -> PyResult<PyObject> {
+        let output = self.validator.construct(py, data, fields_set, slots)?;
+        if self.expect_fields_set {
+            let (model_dict, fields_set): (&PyAny, &PyAny) = output.extract(py)?;
+            self.create_class(py, model_dict, Some(fields_set))
+        } else {
+            self.create_class(py, output.as_ref(py), fields_set)
+        }
+    }
+
+    fn find_field<'s>(
+        &'s self,
+        py: Python,
+        field_path: &[String],
+        slots: &'s [CombinedValidator],
+    ) -> PyResult<Option<(Option<PyObject>, &'s CombinedValidator)>> {
+        self.validator.find_field(py, field_path, slots)
+    }
+
+    fn replace_field(&mut self, field_path: &[String], validator: CombinedValidator) -> FieldReplacement {
+        self.validator.replace_field(field_path, validator)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.validator.complete(build_context)
+    }
 }
 
 impl NewClassValidator {
@@ -114,7 +161,7 @@ impl NewClassValidator {
         let raw_type = self.class.as_ref(py).as_type_ptr();
         let instance = unsafe {
             // Safety: raw_type is known to be a non-null type object pointer
-            match (*raw_type).tp_new {
+            match get_tp_new(raw_type) {
                 // Safety: the result of new_func is guaranteed to be either an owned pointer or null on error returns.
                 Some(new_func) => PyObject::from_owned_ptr_or_err(
                     py,
@@ -161,7 +208,9 @@ fn error_on_minusone(py: Python<'_>, result: c_int) -> PyResult<()> {
     }
 }
 
-fn build_config<'a>(
+/// Config for a model's schema, `config` on the schema is merged with or replaces the parent config
+/// based on `config_choose_priority` and `config_merge_priority`
+pub(crate) fn build_config<'a>(
     py: Python<'a>,
     schema: &'a PyDict,
     parent_config: Option<&'a PyDict>,
@@ -182,20 +231,33 @@ fn build_config<'a>(
                     let key = intern!(py, "config_merge_priority");
                     let parent_merge: i32 = parent.get_as(key)?.unwrap_or_default();
                     let child_merge: i32 = child.get_as(key)?.unwrap_or_default();
-                    let update = intern!(py, "update");
-                    match parent_merge.cmp(&child_merge) {
-                        Ordering::Greater => {
-                            child.getattr(update)?.call1((parent,))?;
-                            Ok(Some(child))
-                        }
+                    // the merged config is a new dict so neither config is changed for other schemas using it
+                    let (base, winner) = match parent_merge.cmp(&child_merge) {
+                        Ordering::Greater => (child, parent),
                         // otherwise child is the winner
-                        _ => {
-                            parent.getattr(update)?.call1((child,))?;
-                            Ok(Some(parent))
-                        }
-                    }
+                        _ => (parent, child),
+                    };
+                    let merged = base.copy()?;
+                    merged.update(winner.as_mapping())?;
+                    Ok(Some(merged))
                 }
             }
         }
     }
 }
+
+/// Get the `tp_new` slot of a type, with the limited API `PyTypeObject` is opaque so `PyType_GetSlot` is required
+#[cfg(not(Py_LIMITED_API))]
+unsafe fn get_tp_new(raw_type: *mut ffi::PyTypeObject) -> Option<ffi::newfunc> {
+    (*raw_type).tp_new
+}
+
+#[cfg(Py_LIMITED_API)]
+unsafe fn get_tp_new(raw_type: *mut ffi::PyTypeObject) -> Option<ffi::newfunc> {
+    let slot = ffi::PyType_GetSlot(raw_type, ffi::Py_tp_new);
+    if slot.is_null() {
+        None
+    } else {
+        Some(std::mem::transmute::<*mut std::os::raw::c_void, ffi::newfunc>(slot))
+    }
+}
@@ -7,7 +7,7 @@ use crate::errors::ValResult;
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, FieldReplacement, Validator};
 
 #[derive(Debug, Clone)]
 pub struct NullableValidator {
@@ -49,6 +49,19 @@ impl Validator for NullableValidator {
         &self.name
     }
 
+    fn find_field<'s>(
+        &'s self,
+        py: Python,
+        field_path: &[String],
+        slots: &'s [CombinedValidator],
+    ) -> PyResult<Option<(Option<PyObject>, &'s CombinedValidator)>> {
+        self.validator.find_field(py, field_path, slots)
+    }
+
+    fn replace_field(&mut self, field_path: &[String], validator: CombinedValidator) -> FieldReplacement {
+        self.validator.replace_field(field_path, validator)
+    }
+
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         self.validator.complete(build_context)
     }
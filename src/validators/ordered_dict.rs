@@ -0,0 +1,63 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::dict::build_inner_dict_validator;
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct OrderedDictValidator {
+    dict_validator: Box<CombinedValidator>,
+    ordered_dict_type: PyObject,
+    name: String,
+}
+
+impl BuildValidator for OrderedDictValidator {
+    const EXPECTED_TYPE: &'static str = "ordered-dict";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let dict_validator = build_inner_dict_validator(schema, config, build_context, &[])?;
+        let name = format!("{}{}", Self::EXPECTED_TYPE, &dict_validator.get_name()["dict".len()..]);
+        Ok(Self {
+            dict_validator,
+            ordered_dict_type: py
+                .import("collections")?
+                .getattr(intern!(py, "OrderedDict"))?
+                .into_py(py),
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for OrderedDictValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        // dicts preserve insertion order, so the order of the input is kept
+        let dict = self.dict_validator.validate(py, input, extra, slots, recursion_guard)?;
+        Ok(self.ordered_dict_type.call1(py, (dict,))?)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.dict_validator.complete(build_context)
+    }
+}
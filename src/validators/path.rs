@@ -0,0 +1,140 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyString, PyType};
+
+use crate::build_tools::{is_strict, py_error, SchemaDict};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FsCheck {
+    Exists,
+    IsFile,
+    IsDir,
+}
+
+#[derive(Debug, Clone)]
+pub struct PathValidator {
+    strict: bool,
+    class: Py<PyType>,
+    pure_path_class: Py<PyType>,
+    fs_check: Option<FsCheck>,
+    name: String,
+}
+
+impl BuildValidator for PathValidator {
+    const EXPECTED_TYPE: &'static str = "path";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let path_type = schema.get_as(intern!(py, "path_type"))?.unwrap_or("path");
+        let class_name = match path_type {
+            "path" => "Path",
+            "pure-posix" => "PurePosixPath",
+            "pure-windows" => "PureWindowsPath",
+            _ => return py_error!(r#"Invalid path_type: "{}""#, path_type),
+        };
+
+        let exists = schema.get_as(intern!(py, "exists"))?.unwrap_or(false);
+        let is_file = schema.get_as(intern!(py, "is_file"))?.unwrap_or(false);
+        let is_dir = schema.get_as(intern!(py, "is_dir"))?.unwrap_or(false);
+        let fs_check = match (exists, is_file, is_dir) {
+            (_, true, true) => return py_error!("is_file and is_dir cannot be used together"),
+            (_, true, false) => Some(FsCheck::IsFile),
+            (_, false, true) => Some(FsCheck::IsDir),
+            (true, false, false) => Some(FsCheck::Exists),
+            (false, false, false) => None,
+        };
+        if fs_check.is_some() && path_type != "path" {
+            return py_error!("exists, is_file and is_dir can only be used with path_type 'path'");
+        }
+
+        let pathlib = py.import("pathlib")?;
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            class: pathlib.getattr(class_name)?.cast_as::<PyType>()?.into(),
+            pure_path_class: pathlib.getattr("PurePath")?.cast_as::<PyType>()?.into(),
+            fs_check,
+            name: format!("{}[{}]", Self::EXPECTED_TYPE, class_name),
+        }
+        .into())
+    }
+}
+
+impl Validator for PathValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
+        // the path is kept as a python `str` since it may contain surrogate escapes (e.g. from `os.fsdecode`)
+        // which can't be converted to a rust string
+        let path: &PyString = if let Ok(either_str) = input.strict_str() {
+            let py_string: Py<PyString> = either_str.as_py_string(py).into();
+            py_string.into_ref(py)
+        } else if input.is_instance(self.pure_path_class.as_ref(py))? {
+            input.to_object(py).into_ref(py).str()?
+        } else if let (false, Some(fspath)) = (strict, input.get_attr(intern!(py, "__fspath__"))) {
+            // any other `os.PathLike` in lax mode, `bytes` are decoded as `os.fsdecode` does
+            let fs_path = match fspath.call0() {
+                Ok(fs_path) => fs_path,
+                Err(_) => return Err(ValError::new(ErrorKind::PathType, input)),
+            };
+            if let Ok(py_str) = fs_path.cast_as::<PyString>() {
+                py_str
+            } else if fs_path.cast_as::<PyBytes>().is_ok() {
+                let os = py.import("os")?;
+                os.call_method1(intern!(py, "fsdecode"), (fs_path,))?.cast_as()?
+            } else {
+                return Err(ValError::new(ErrorKind::PathType, input));
+            }
+        } else {
+            return Err(ValError::new(ErrorKind::PathType, input));
+        };
+
+        if let Some(fs_check) = self.fs_check {
+            // checked by python's `os.path` so paths are encoded as `os.fsencode` does
+            let os_path = py.import("os.path")?;
+            let check = |name: &PyString| -> PyResult<bool> { os_path.call_method1(name, (path,))?.is_true() };
+            let display_path = || path.to_string_lossy().into_owned();
+            let error_kind = if !check(intern!(py, "exists"))? {
+                Some(ErrorKind::PathNotExists { path: display_path() })
+            } else {
+                match fs_check {
+                    FsCheck::IsFile if !check(intern!(py, "isfile"))? => {
+                        Some(ErrorKind::PathNotFile { path: display_path() })
+                    }
+                    FsCheck::IsDir if !check(intern!(py, "isdir"))? => {
+                        Some(ErrorKind::PathNotDirectory { path: display_path() })
+                    }
+                    _ => None,
+                }
+            };
+            if let Some(error_kind) = error_kind {
+                return Err(ValError::new(error_kind, input));
+            }
+        }
+
+        let class = self.class.as_ref(py);
+        if input.is_instance(class)? {
+            Ok(input.to_object(py))
+        } else {
+            Ok(class.call1((path,))?.into_py(py))
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
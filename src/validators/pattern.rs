@@ -0,0 +1,125 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString, PyType};
+
+use crate::build_tools::{py_error, SchemaDict};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::{BytesEncoding, Input};
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PatternType {
+    Str,
+    Bytes,
+}
+
+#[derive(Debug, Clone)]
+pub struct PatternValidator {
+    pattern_type: Option<PatternType>,
+    pattern_class: Py<PyType>,
+    compile: PyObject,
+    name: String,
+}
+
+impl BuildValidator for PatternValidator {
+    const EXPECTED_TYPE: &'static str = "pattern";
+
+    fn build(
+        schema: &PyDict,
+        _config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let pattern_type = match schema.get_as(intern!(py, "pattern_type"))? {
+            Some("str") => Some(PatternType::Str),
+            Some("bytes") => Some(PatternType::Bytes),
+            Some(s) => return py_error!(r#"Invalid pattern_type: "{}""#, s),
+            None => None,
+        };
+        let name = match pattern_type {
+            Some(PatternType::Str) => format!("{}[str]", Self::EXPECTED_TYPE),
+            Some(PatternType::Bytes) => format!("{}[bytes]", Self::EXPECTED_TYPE),
+            None => Self::EXPECTED_TYPE.to_string(),
+        };
+
+        let re = py.import("re")?;
+        Ok(Self {
+            pattern_type,
+            pattern_class: re.getattr("Pattern")?.cast_as::<PyType>()?.into(),
+            compile: re.getattr("compile")?.into(),
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for PatternValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        _extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        if input.is_instance(self.pattern_class.as_ref(py))? {
+            // existing patterns are returned unchanged, provided their source has the right type
+            let pattern = input.to_object(py);
+            let source = pattern.as_ref(py).getattr(intern!(py, "pattern"))?;
+            return match (self.pattern_type, source.cast_as::<PyString>().is_ok()) {
+                (Some(PatternType::Str), false) | (Some(PatternType::Bytes), true) => Err(self.type_error(input)),
+                _ => Ok(pattern),
+            };
+        }
+
+        let source: PyObject = match self.pattern_type {
+            Some(PatternType::Str) => self.source_str(py, input)?,
+            Some(PatternType::Bytes) => self.source_bytes(py, input)?,
+            None => match self.source_str(py, input) {
+                Ok(source) => source,
+                Err(_) => self.source_bytes(py, input)?,
+            },
+        };
+
+        match self.compile.call1(py, (source,)) {
+            Ok(pattern) => Ok(pattern),
+            Err(err) => Err(ValError::new(
+                ErrorKind::PatternRegex {
+                    error: err.value(py).to_string(),
+                },
+                input,
+            )),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl PatternValidator {
+    fn source_str<'data>(&self, py: Python<'data>, input: &'data impl Input<'data>) -> ValResult<'data, PyObject> {
+        match input.strict_str() {
+            Ok(either_str) => Ok(PyString::new(py, &either_str.as_cow()?).into_py(py)),
+            Err(_) => Err(self.type_error(input)),
+        }
+    }
+
+    fn source_bytes<'data>(&self, py: Python<'data>, input: &'data impl Input<'data>) -> ValResult<'data, PyObject> {
+        match input.strict_bytes(BytesEncoding::Utf8) {
+            Ok(either_bytes) => Ok(either_bytes.into_py(py)),
+            Err(_) => Err(self.type_error(input)),
+        }
+    }
+
+    fn type_error<'data>(&self, input: &'data impl Input<'data>) -> ValError<'data> {
+        let pattern_type = match self.pattern_type {
+            Some(PatternType::Str) => "str",
+            Some(PatternType::Bytes) => "bytes",
+            None => "str or bytes",
+        };
+        ValError::new(ErrorKind::PatternType { pattern_type }, input)
+    }
+}
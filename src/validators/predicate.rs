@@ -0,0 +1,92 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::SchemaDict;
+use crate::errors::{PydanticValueError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::any::AnyValidator;
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// Calls `predicate` with the output of `schema`, if the result is falsy a custom error is raised built
+/// from `error_kind`, `message_template` and `context`, as with `PydanticValueError`
+#[derive(Debug, Clone)]
+pub struct PredicateValidator {
+    validator: Box<CombinedValidator>,
+    predicate: PyObject,
+    error_kind: String,
+    message_template: String,
+    context: Option<Py<PyDict>>,
+    name: String,
+}
+
+impl BuildValidator for PredicateValidator {
+    const EXPECTED_TYPE: &'static str = "predicate";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let validator = match schema.get_item(intern!(py, "schema")) {
+            Some(sub_schema) => build_validator(sub_schema, config, build_context)?,
+            None => AnyValidator::build(schema, config, build_context)?,
+        };
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self {
+            validator: Box::new(validator),
+            predicate: schema.get_as_req::<&PyAny>(intern!(py, "predicate"))?.into_py(py),
+            error_kind: schema.get_as_req(intern!(py, "error_kind"))?,
+            message_template: schema.get_as_req(intern!(py, "message_template"))?,
+            context: schema
+                .get_as::<&PyDict>(intern!(py, "context"))?
+                .map(|context| context.into_py(py)),
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for PredicateValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let output = self.validator.validate(py, input, extra, slots, recursion_guard)?;
+        match self
+            .predicate
+            .call1(py, (output.clone_ref(py),))?
+            .as_ref(py)
+            .is_true()?
+        {
+            true => Ok(output),
+            false => {
+                let value_error = PydanticValueError::new(
+                    self.error_kind.clone(),
+                    self.message_template.clone(),
+                    self.context.as_ref().map(|context| context.clone_ref(py)),
+                );
+                Err(value_error.into_val_error(output.into_ref(py)))
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn ask(&self, question: &str) -> bool {
+        self.validator.ask(question)
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.validator.complete(build_context)
+    }
+}
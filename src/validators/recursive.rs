@@ -1,13 +1,14 @@
+use pyo3::exceptions::PyRuntimeError;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList};
 
-use crate::build_tools::SchemaDict;
+use crate::build_tools::{py_error, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, FieldReplacement, Validator};
 
 #[derive(Debug, Clone)]
 pub struct RecursiveContainerValidator {
@@ -37,6 +38,29 @@ impl Validator for RecursiveContainerValidator {
         guard_validate(self.validator_id, py, input, extra, slots, recursion_guard)
     }
 
+    fn construct(
+        &self,
+        py: Python,
+        data: &PyDict,
+        fields_set: Option<&PyAny>,
+        slots: &[CombinedValidator],
+    ) -> PyResult<PyObject> {
+        construct_slot(self.validator_id, py, data, fields_set, slots)
+    }
+
+    fn find_field<'s>(
+        &'s self,
+        py: Python,
+        field_path: &[String],
+        slots: &'s [CombinedValidator],
+    ) -> PyResult<Option<(Option<PyObject>, &'s CombinedValidator)>> {
+        find_field_slot(self.validator_id, py, field_path, slots)
+    }
+
+    fn replace_field(&mut self, field_path: &[String], validator: CombinedValidator) -> FieldReplacement {
+        FieldReplacement::InSlot(self.validator_id, field_path.to_vec(), Box::new(validator))
+    }
+
     fn get_name(&self) -> &str {
         // we just return the inner validator to make the recursive-container invisible in output messages
         &self.inner_name
@@ -49,6 +73,8 @@ impl Validator for RecursiveContainerValidator {
 #[derive(Debug, Clone)]
 pub struct RecursiveRefValidator {
     validator_id: usize,
+    // set when the ref isn't defined yet while building, `validator_id` is found in `complete`
+    unresolved_ref: Option<String>,
     inner_name: String,
 }
 
@@ -61,9 +87,14 @@ impl BuildValidator for RecursiveRefValidator {
         build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
         let name: String = schema.get_as_req(intern!(schema.py(), "schema_ref"))?;
-        let validator_id = build_context.find_slot_id(&name)?;
+        // the ref may be defined later in the schema, in which case it's linked once everything is built
+        let (validator_id, unresolved_ref) = match build_context.find_slot_id(&name) {
+            Ok(validator_id) => (validator_id, None),
+            Err(_) => (0, Some(name)),
+        };
         Ok(Self {
             validator_id,
+            unresolved_ref,
             inner_name: "...".to_string(),
         }
         .into())
@@ -82,18 +113,100 @@ impl Validator for RecursiveRefValidator {
         guard_validate(self.validator_id, py, input, extra, slots, recursion_guard)
     }
 
+    fn construct(
+        &self,
+        py: Python,
+        data: &PyDict,
+        fields_set: Option<&PyAny>,
+        slots: &[CombinedValidator],
+    ) -> PyResult<PyObject> {
+        construct_slot(self.validator_id, py, data, fields_set, slots)
+    }
+
+    fn find_field<'s>(
+        &'s self,
+        py: Python,
+        field_path: &[String],
+        slots: &'s [CombinedValidator],
+    ) -> PyResult<Option<(Option<PyObject>, &'s CombinedValidator)>> {
+        find_field_slot(self.validator_id, py, field_path, slots)
+    }
+
+    fn replace_field(&mut self, field_path: &[String], validator: CombinedValidator) -> FieldReplacement {
+        FieldReplacement::InSlot(self.validator_id, field_path.to_vec(), Box::new(validator))
+    }
+
     fn get_name(&self) -> &str {
         &self.inner_name
     }
 
     /// don't need to call complete on the inner validator here, complete_validators takes care of that.
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        if let Some(ref schema_ref) = self.unresolved_ref.take() {
+            self.validator_id = build_context.find_slot_id(schema_ref)?;
+        }
         let validator = build_context.find_validator(self.validator_id)?;
         self.inner_name = validator.get_name().to_string();
         Ok(())
     }
 }
 
+/// Builds the schemas in `definitions` into slots before building `schema`, so they can be referenced with
+/// `definition-ref` in any order, including from each other
+pub struct DefinitionsBuilder;
+
+impl BuildValidator for DefinitionsBuilder {
+    const EXPECTED_TYPE: &'static str = "definitions";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let definitions: &PyList = schema.get_as_req(intern!(py, "definitions"))?;
+
+        // all slots are prepared first so definitions can reference those after them
+        let mut slots: Vec<(usize, &PyDict)> = Vec::with_capacity(definitions.len());
+        for definition in definitions {
+            let definition: &PyDict = definition.cast_as()?;
+            let schema_ref: String = match definition.get_as(intern!(py, "ref"))? {
+                Some(schema_ref) => schema_ref,
+                None => return py_error!("Definitions must have a \"ref\""),
+            };
+            if build_context.find_slot_id(&schema_ref).is_ok() {
+                return py_error!("Duplicate ref: \"{}\"", schema_ref);
+            }
+            let slot_id = build_context.prepare_slot(schema_ref)?;
+            // the copy is built without its ref, which would otherwise create another slot
+            let definition = definition.copy()?;
+            definition.del_item(intern!(py, "ref"))?;
+            slots.push((slot_id, definition));
+        }
+        for (slot_id, definition) in slots {
+            let validator = build_validator(definition, config, build_context)?;
+            build_context.complete_slot(slot_id, validator)?;
+        }
+
+        build_validator(schema.get_as_req(intern!(py, "schema"))?, config, build_context)
+    }
+}
+
+/// `definition-ref` schemas reference a schema in `definitions`, they're validated like `recursive-ref` schemas
+pub struct DefinitionRefBuilder;
+
+impl BuildValidator for DefinitionRefBuilder {
+    const EXPECTED_TYPE: &'static str = "definition-ref";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        RecursiveRefValidator::build(schema, config, build_context)
+    }
+}
+
 // see #143 this is a backup in case the identity check recursion guard fails
 // if a single validator "depth" (how many times it's called inside itself) exceeds the limit,
 // we raise a recursion error.
@@ -140,3 +253,28 @@ fn validate<'s, 'data>(
     let validator = unsafe { slots.get_unchecked(validator_id) };
     validator.validate(py, input, extra, slots, recursion_guard)
 }
+
+fn construct_slot(
+    validator_id: usize,
+    py: Python,
+    data: &PyDict,
+    fields_set: Option<&PyAny>,
+    slots: &[CombinedValidator],
+) -> PyResult<PyObject> {
+    match slots.get(validator_id) {
+        Some(validator) => validator.construct(py, data, fields_set, slots),
+        None => py_error!(PyRuntimeError; "Slots Error: slot {} not found", validator_id),
+    }
+}
+
+fn find_field_slot<'s>(
+    validator_id: usize,
+    py: Python,
+    field_path: &[String],
+    slots: &'s [CombinedValidator],
+) -> PyResult<Option<(Option<PyObject>, &'s CombinedValidator)>> {
+    match slots.get(validator_id) {
+        Some(validator) => validator.find_field(py, field_path, slots),
+        None => py_error!(PyRuntimeError; "Slots Error: slot {} not found", validator_id),
+    }
+}
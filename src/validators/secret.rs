@@ -0,0 +1,177 @@
+use pyo3::basic::CompareOp;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyString};
+
+use crate::errors::{ErrorKind, InputValue, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+const MASK: &str = "**********";
+
+macro_rules! secret_class {
+    ($name:ident, $value_type:ty, $repr:expr) => {
+        /// Wrapper around a secret value which hides the value in `repr()` and `str()`,
+        /// the value is available via `get_secret_value()`
+        #[pyclass(module = "pydantic_core._pydantic_core")]
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            value: PyObject,
+        }
+
+        #[pymethods]
+        impl $name {
+            #[new]
+            fn py_new(value: &$value_type) -> Self {
+                Self { value: value.into() }
+            }
+
+            fn get_secret_value(&self, py: Python) -> PyObject {
+                self.value.clone_ref(py)
+            }
+
+            fn __len__(&self, py: Python) -> PyResult<usize> {
+                self.value.as_ref(py).len()
+            }
+
+            fn __hash__(&self, py: Python) -> PyResult<isize> {
+                self.value.as_ref(py).hash()
+            }
+
+            fn __richcmp__(&self, py: Python, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+                match other.extract::<PyRef<Self>>() {
+                    Ok(other) => Ok(self
+                        .value
+                        .as_ref(py)
+                        .rich_compare(other.value.as_ref(py), op)?
+                        .into()),
+                    Err(_) => Ok(py.NotImplemented()),
+                }
+            }
+
+            fn __repr__(&self) -> String {
+                $repr.to_string()
+            }
+
+            fn __str__(&self) -> &'static str {
+                MASK
+            }
+
+            fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+                let cls = py.get_type::<Self>();
+                Ok((cls, (self.value.clone_ref(py),)).into_py(py))
+            }
+        }
+    };
+}
+
+secret_class!(SecretStr, PyString, "SecretStr('**********')");
+secret_class!(SecretBytes, PyBytes, "SecretBytes(b'**********')");
+
+/// Build the validator for the secret value, this uses the same schema but with the `type` changed,
+/// so all `str`/`bytes` constraints are available
+fn build_inner_validator(
+    schema: &PyDict,
+    config: Option<&PyDict>,
+    build_context: &mut BuildContext,
+    inner_type: &str,
+) -> PyResult<Box<CombinedValidator>> {
+    let py = schema.py();
+    let inner_schema = schema.copy()?;
+    inner_schema.set_item(intern!(py, "type"), inner_type)?;
+    if inner_schema.contains(intern!(py, "ref"))? {
+        inner_schema.del_item(intern!(py, "ref"))?;
+    }
+    Ok(Box::new(build_validator(inner_schema, config, build_context)?))
+}
+
+/// Errors whose message and context only contain values from the schema are kept, anything else
+/// (e.g. `bytes_invalid_encoding` which quotes the offending character) could leak part of the secret
+fn mask_kind(kind: ErrorKind) -> ErrorKind {
+    match kind {
+        ErrorKind::StrType
+        | ErrorKind::StrUnicode
+        | ErrorKind::StrTooShort { .. }
+        | ErrorKind::StrTooLong { .. }
+        | ErrorKind::StrPatternMismatch { .. }
+        | ErrorKind::BytesType
+        | ErrorKind::BytesTooShort { .. }
+        | ErrorKind::BytesTooLong { .. } => kind,
+        _ => ErrorKind::SecretInvalid,
+    }
+}
+
+/// Replace the input value and any error which might include part of the input so the secret never
+/// appears in error messages
+fn mask_errors(err: ValError) -> ValError {
+    match err {
+        ValError::LineErrors(line_errors) => ValError::LineErrors(
+            line_errors
+                .into_iter()
+                .map(|mut line_error| {
+                    line_error.kind = mask_kind(line_error.kind);
+                    line_error.input_value = InputValue::String(MASK);
+                    line_error
+                })
+                .collect(),
+        ),
+        ValError::InternalErr(err) => ValError::InternalErr(err),
+    }
+}
+
+macro_rules! secret_validator {
+    ($name:ident, $secret_class:ident, $expected_type:literal, $inner_type:literal) => {
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            validator: Box<CombinedValidator>,
+        }
+
+        impl BuildValidator for $name {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &PyDict,
+                config: Option<&PyDict>,
+                build_context: &mut BuildContext,
+            ) -> PyResult<CombinedValidator> {
+                Ok(Self {
+                    validator: build_inner_validator(schema, config, build_context, $inner_type)?,
+                }
+                .into())
+            }
+        }
+
+        impl Validator for $name {
+            fn validate<'s, 'data>(
+                &'s self,
+                py: Python<'data>,
+                input: &'data impl Input<'data>,
+                extra: &Extra,
+                slots: &'data [CombinedValidator],
+                recursion_guard: &'s mut RecursionGuard,
+            ) -> ValResult<'data, PyObject> {
+                let value = if input.is_instance(py.get_type::<$secret_class>())? {
+                    // revalidate the value of existing secrets, e.g. to apply constraints
+                    let secret: PyRef<$secret_class> = input.to_object(py).into_ref(py).extract()?;
+                    let secret_value: &PyAny = secret.value.clone_ref(py).into_ref(py);
+                    drop(secret);
+                    self.validator
+                        .validate(py, secret_value, extra, slots, recursion_guard)
+                } else {
+                    self.validator.validate(py, input, extra, slots, recursion_guard)
+                }
+                .map_err(mask_errors)?;
+                Ok($secret_class { value }.into_py(py))
+            }
+
+            fn get_name(&self) -> &str {
+                Self::EXPECTED_TYPE
+            }
+        }
+    };
+}
+
+secret_validator!(SecretStrValidator, SecretStr, "secret-str", "str");
+secret_validator!(SecretBytesValidator, SecretBytes, "secret-bytes", "bytes");
@@ -1,12 +1,13 @@
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PySet};
 
 use crate::build_tools::SchemaDict;
-use crate::errors::ValResult;
+use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
 use crate::input::{GenericCollection, Input};
 use crate::recursion_guard::RecursionGuard;
 
-use super::list::generic_collection_build;
+use super::list::{generic_collection_build, split_str_input};
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
@@ -14,12 +15,14 @@ pub struct SetValidator {
     strict: bool,
     item_validator: Option<Box<CombinedValidator>>,
     size_range: Option<(Option<usize>, Option<usize>)>,
+    str_delimiter: Option<String>,
+    fail_fast: bool,
     name: String,
 }
 
 impl BuildValidator for SetValidator {
     const EXPECTED_TYPE: &'static str = "set";
-    generic_collection_build!();
+    generic_collection_build!(fail_fast);
 }
 
 impl Validator for SetValidator {
@@ -31,18 +34,26 @@ impl Validator for SetValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_set(extra.strict.unwrap_or(self.strict))?;
-
-        let length = seq.check_len(self.size_range, input)?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let seq = match split_str_input(py, input, self.str_delimiter.as_deref(), strict)? {
+            Some(seq) => seq,
+            None => input.validate_set(strict)?,
+        };
 
         let output = match self.item_validator {
-            Some(ref v) => seq.validate_to_vec(py, length, v, extra, slots, recursion_guard)?,
+            Some(ref v) => seq.validate_to_vec(py, None, v, self.fail_fast, extra, slots, recursion_guard)?,
             None => match seq {
-                GenericCollection::Set(set) => return Ok(set.into_py(py)),
+                GenericCollection::Set(set) => {
+                    seq.check_len(self.size_range, input)?;
+                    return Ok(set.into_py(py));
+                }
                 _ => seq.to_vec(py),
             },
         };
-        Ok(PySet::new(py, &output)?.into_py(py))
+        let set = PySet::new(py, &output).map_err(|err| unhashable_error(py, err, output, input))?;
+        // length constraints apply after duplicates have been collapsed
+        GenericCollection::from(set).check_len(self.size_range, input)?;
+        Ok(set.into_py(py))
     }
 
     fn get_name(&self) -> &str {
@@ -56,3 +67,30 @@ impl Validator for SetValidator {
         }
     }
 }
+
+/// Convert an error from creating a set into validation errors for each unhashable item, keyed by its position
+pub fn unhashable_error<'data>(
+    py: Python<'data>,
+    err: PyErr,
+    items: Vec<PyObject>,
+    input: &'data impl Input<'data>,
+) -> ValError<'data> {
+    if !err.is_instance_of::<PyTypeError>(py) {
+        return err.into();
+    }
+    let errors: Vec<ValLineError> = items
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+            let item = item.into_ref(py);
+            match item.hash() {
+                Ok(_) => None,
+                Err(_) => Some(ValLineError::new_with_loc(ErrorKind::SetItemNotHashable, item, index)),
+            }
+        })
+        .collect();
+    match errors.is_empty() {
+        true => ValError::new(ErrorKind::SetItemNotHashable, input),
+        false => ValError::LineErrors(errors),
+    }
+}
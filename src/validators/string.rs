@@ -2,6 +2,7 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 use regex::Regex;
+use regex_syntax::ast;
 
 use crate::build_tools::{is_strict, py_error, schema_or_config};
 use crate::errors::{ErrorKind, ValError, ValResult};
@@ -72,7 +73,7 @@ impl Validator for StrValidator {
 #[derive(Debug, Clone)]
 pub struct StrConstrainedValidator {
     strict: bool,
-    pattern: Option<Regex>,
+    pattern: Option<Pattern>,
     max_length: Option<usize>,
     min_length: Option<usize>,
     strip_whitespace: bool,
@@ -91,7 +92,24 @@ impl Validator for StrConstrainedValidator {
     ) -> ValResult<'data, PyObject> {
         let either_str = input.validate_str(extra.strict.unwrap_or(self.strict))?;
         let cow = either_str.as_cow()?;
-        let mut str = cow.as_ref();
+        let original: &str = cow.as_ref();
+        let mut str = original;
+
+        // transformations are applied before constraints are checked
+        if self.strip_whitespace {
+            str = str.trim();
+        }
+        let transformed: Option<String> = if self.to_lower {
+            Some(str.to_lowercase())
+        } else if self.to_upper {
+            Some(str.to_uppercase())
+        } else {
+            None
+        };
+        if let Some(ref transformed) = transformed {
+            str = transformed;
+        }
+
         if let Some(min_length) = self.min_length {
             if str.len() < min_length {
                 // return py_error!("{} is shorter than {}", str, min_length);
@@ -103,31 +121,25 @@ impl Validator for StrConstrainedValidator {
                 return Err(ValError::new(ErrorKind::StrTooLong { max_length }, input));
             }
         }
+
+        // the output string is only created once, and not at all if the input is an unmodified python string,
+        // the python `re` engine matches against it rather than a copy.
+        // JSON strings aren't zero-copy, they're still copied into a `String` when the JSON is parsed
+        let py_string = if transformed.is_some() || str.len() != original.len() {
+            PyString::new(py, str)
+        } else {
+            either_str.as_py_string(py)
+        };
         if let Some(pattern) = &self.pattern {
-            if !pattern.is_match(str) {
+            if !pattern.is_match(py, str, py_string)? {
                 return Err(ValError::new(
                     ErrorKind::StrPatternMismatch {
-                        pattern: pattern.to_string(),
+                        pattern: pattern.pattern.clone(),
                     },
                     input,
                 ));
             }
         }
-
-        if self.strip_whitespace {
-            str = str.trim();
-        }
-
-        let py_string = if self.to_lower {
-            PyString::new(py, &str.to_lowercase())
-        } else if self.to_upper {
-            PyString::new(py, &str.to_uppercase())
-        } else if self.strip_whitespace {
-            PyString::new(py, str)
-        } else {
-            // we haven't modified the string, return the original as it might be a PyString
-            either_str.as_py_string(py)
-        };
         Ok(py_string.into_py(py))
     }
 
@@ -142,7 +154,15 @@ impl StrConstrainedValidator {
         let pattern_str: Option<&str> =
             schema_or_config(schema, config, intern!(py, "pattern"), intern!(py, "str_pattern"))?;
         let pattern = match pattern_str {
-            Some(s) => Some(build_regex(s)?),
+            Some(s) => {
+                let regex_engine: Option<&str> = schema_or_config(
+                    schema,
+                    config,
+                    intern!(py, "regex_engine"),
+                    intern!(py, "str_regex_engine"),
+                )?;
+                Some(Pattern::compile(py, s, regex_engine)?)
+            }
             None => None,
         };
         let min_length: Option<usize> =
@@ -175,9 +195,84 @@ impl StrConstrainedValidator {
     }
 }
 
+#[derive(Debug, Clone)]
+struct Pattern {
+    pattern: String,
+    engine: RegexEngine,
+}
+
+/// The engine used to match a `pattern`, Rust's `regex` is much faster but doesn't support
+/// look-around or backreferences, Python's `re` does
+#[derive(Debug, Clone)]
+enum RegexEngine {
+    RustRegex(Regex),
+    // the `search` method of the compiled python pattern
+    PythonRe(PyObject),
+}
+
+/// The engine used to match `pattern` given the `regex_engine` setting, "auto" uses the rust engine where it
+/// supports the pattern, otherwise python's. Also used for the `regex_engine` of `SchemaValidator.resolved_schema`
+pub fn resolve_regex_engine(pattern: &str, regex_engine: Option<&str>) -> PyResult<&'static str> {
+    match regex_engine {
+        None | Some("rust-regex") => Ok("rust-regex"),
+        Some("python-re") => Ok("python-re"),
+        Some("auto") => match Regex::new(pattern) {
+            Ok(_) => Ok("rust-regex"),
+            Err(_) => Ok("python-re"),
+        },
+        Some(s) => py_error!(r#"Invalid regex_engine: "{}""#, s),
+    }
+}
+
+impl Pattern {
+    fn compile(py: Python, pattern: &str, regex_engine: Option<&str>) -> PyResult<Self> {
+        let engine = match resolve_regex_engine(pattern, regex_engine)? {
+            "python-re" => RegexEngine::PythonRe(compile_python_re(py, pattern)?),
+            _ => RegexEngine::RustRegex(build_regex(pattern)?),
+        };
+        Ok(Self {
+            pattern: pattern.to_string(),
+            engine,
+        })
+    }
+
+    /// `py_string` must hold the same value as `str`, each engine uses whichever form it needs
+    fn is_match(&self, py: Python, str: &str, py_string: &PyString) -> PyResult<bool> {
+        match self.engine {
+            RegexEngine::RustRegex(ref regex) => Ok(regex.is_match(str)),
+            RegexEngine::PythonRe(ref search) => Ok(!search.call1(py, (py_string,))?.is_none(py)),
+        }
+    }
+}
+
 fn build_regex(pattern: &str) -> PyResult<Regex> {
     match Regex::new(pattern) {
         Ok(r) => Ok(r),
+        Err(e) if uses_unsupported_features(pattern) => py_error!(
+            "{}\n\nThe pattern uses features not supported by the rust-regex engine, \
+            set regex_engine to 'python-re' or 'auto' to use Python's re module",
+            e
+        ),
         Err(e) => py_error!("{}", e),
     }
 }
+
+/// Whether `pattern` uses look-around or backreferences, which python's `re` supports but the rust engine doesn't,
+/// `regex::Error` only carries a message so the pattern is parsed again to get the kind of error
+fn uses_unsupported_features(pattern: &str) -> bool {
+    match ast::parse::Parser::new().parse(pattern) {
+        Ok(_) => false,
+        Err(e) => matches!(
+            e.kind(),
+            ast::ErrorKind::UnsupportedLookAround | ast::ErrorKind::UnsupportedBackreference
+        ),
+    }
+}
+
+fn compile_python_re(py: Python, pattern: &str) -> PyResult<PyObject> {
+    let compiled = match py.import("re")?.call_method1("compile", (pattern,)) {
+        Ok(compiled) => compiled,
+        Err(err) => return py_error!("Invalid regular expression: {}", err.value(py)),
+    };
+    Ok(compiled.getattr(intern!(py, "search"))?.into_py(py))
+}
@@ -3,11 +3,11 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
 
 use crate::build_tools::{is_strict, SchemaDict};
-use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
+use crate::errors::{CurrentLoc, ErrorKind, ValError, ValLineError, ValResult};
 use crate::input::{GenericCollection, Input};
 use crate::recursion_guard::RecursionGuard;
 
-use super::list::generic_collection_build;
+use super::list::{check_unique_items, generic_collection_build, split_str_input};
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug)]
@@ -33,11 +33,14 @@ pub struct TupleVariableValidator {
     strict: bool,
     item_validator: Option<Box<CombinedValidator>>,
     size_range: Option<(Option<usize>, Option<usize>)>,
+    str_delimiter: Option<String>,
+    unique_items: bool,
+    fail_fast: bool,
     name: String,
 }
 
 impl TupleVariableValidator {
-    generic_collection_build!("{}[{}, ...]", "tuple");
+    generic_collection_build!("{}[{}, ...]", "tuple", unique_items, fail_fast);
 }
 
 impl Validator for TupleVariableValidator {
@@ -49,17 +52,24 @@ impl Validator for TupleVariableValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_tuple(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let seq = match split_str_input(py, input, self.str_delimiter.as_deref(), strict)? {
+            Some(seq) => seq,
+            None => input.validate_tuple(strict)?,
+        };
 
         let length = seq.check_len(self.size_range, input)?;
 
         let output = match self.item_validator {
-            Some(ref v) => seq.validate_to_vec(py, length, v, extra, slots, recursion_guard)?,
+            Some(ref v) => seq.validate_to_vec(py, length, v, self.fail_fast, extra, slots, recursion_guard)?,
             None => match seq {
-                GenericCollection::Tuple(tuple) => return Ok(tuple.into_py(py)),
+                GenericCollection::Tuple(tuple) if !self.unique_items => return Ok(tuple.into_py(py)),
                 _ => seq.to_vec(py),
             },
         };
+        if self.unique_items {
+            check_unique_items(py, &output)?;
+        }
         Ok(PyTuple::new(py, &output).into_py(py))
     }
 
@@ -80,6 +90,8 @@ pub struct TuplePositionalValidator {
     strict: bool,
     items_validators: Vec<CombinedValidator>,
     extra_validator: Option<Box<CombinedValidator>>,
+    unique_items: bool,
+    fail_fast: bool,
     name: String,
 }
 
@@ -96,15 +108,23 @@ impl TuplePositionalValidator {
             .map(|item| build_validator(item, config, build_context))
             .collect::<PyResult<Vec<CombinedValidator>>>()?;
 
-        let descr = validators.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(", ");
+        let extra_validator = match schema.get_item(intern!(py, "extra_schema")) {
+            Some(v) => Some(Box::new(build_validator(v, config, build_context)?)),
+            None => None,
+        };
+
+        let mut descr: Vec<String> = validators.iter().map(|v| v.get_name().to_string()).collect();
+        if let Some(ref extra_validator) = extra_validator {
+            // as in `Tuple[int, str, *Tuple[float, ...]]`
+            descr.push(format!("*tuple[{}, ...]", extra_validator.get_name()));
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
             items_validators: validators,
-            extra_validator: match schema.get_item(intern!(py, "extra_schema")) {
-                Some(v) => Some(Box::new(build_validator(v, config, build_context)?)),
-                None => None,
-            },
-            name: format!("tuple[{}]", descr),
+            extra_validator,
+            unique_items: schema.get_as(intern!(py, "unique_items"))?.unwrap_or(false),
+            fail_fast: schema.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
+            name: format!("tuple[{}]", descr.join(", ")),
         }
         .into())
     }
@@ -123,42 +143,56 @@ impl Validator for TuplePositionalValidator {
         let expected_length = self.items_validators.len();
 
         let col_length = collection.generic_len();
-        if col_length < expected_length {
-            return Err(ValError::LineErrors(
-                (col_length..expected_length)
-                    .map(|index| ValLineError::new_with_loc(ErrorKind::Missing, input, index))
-                    .collect(),
+        if col_length > expected_length && self.extra_validator.is_none() {
+            return Err(ValError::new(
+                ErrorKind::TooLong {
+                    max_length: expected_length,
+                    input_length: col_length,
+                },
+                input,
             ));
         }
-        let mut output: Vec<PyObject> = Vec::with_capacity(expected_length);
+        let fail_fast = self.fail_fast || extra.fail_fast;
+        let mut output: Vec<PyObject> = Vec::with_capacity(col_length);
         let mut errors: Vec<ValLineError> = Vec::new();
         macro_rules! iter {
             ($collection:expr) => {
                 for (index, item) in $collection.iter().enumerate() {
+                    // items after the positional items are validated by `extra_validator`, the length check above means it exists
                     let validator = match self.items_validators.get(index) {
                         Some(ref v) => v,
-                        None => match self.extra_validator {
-                            Some(ref v) => v.as_ref(),
-                            None => {
-                                return Err(ValError::new(
-                                    ErrorKind::TooLong {
-                                        max_length: expected_length,
-                                        input_length: col_length,
-                                    },
-                                    input,
-                                ));
-                            }
-                        },
+                        None => self.extra_validator.as_ref().unwrap().as_ref(),
                     };
 
-                    match validator.validate(py, item, extra, slots, recursion_guard) {
+                    let salvage_start = extra.salvage.map(|s| s.len());
+                    let coercions_start = extra.coercions.map(|c| c.len());
+                    let loc = CurrentLoc::index(index, extra.loc);
+                    let item_extra = Extra {
+                        loc: Some(&loc),
+                        ..*extra
+                    };
+                    let result = validator.validate(py, item, &item_extra, slots, recursion_guard);
+                    if let (Some(salvage), Some(start)) = (extra.salvage, salvage_start) {
+                        salvage.with_outer_location(start, &index.into());
+                    }
+                    if let (Some(coercions), Some(start)) = (extra.coercions, coercions_start) {
+                        coercions.with_outer_location(start, &index.into());
+                    }
+                    match result {
                         Ok(item) => output.push(item),
                         Err(ValError::LineErrors(line_errors)) => {
-                            errors.extend(
+                            extra.extend_errors(
+                                &mut errors,
                                 line_errors
                                     .into_iter()
                                     .map(|err| err.with_outer_location(index.into())),
                             );
+                            if extra.salvage.is_some() {
+                                output.push(py.None());
+                            }
+                            if fail_fast {
+                                break;
+                            }
                         }
                         Err(err) => return Err(err),
                     }
@@ -171,7 +205,21 @@ impl Validator for TuplePositionalValidator {
             GenericCollection::JsonArray(collection) => iter!(collection),
             _ => unreachable!(),
         }
+        // errors for the items provided are kept, followed by an error for each missing item
+        if errors.is_empty() || !fail_fast {
+            errors.extend(
+                (col_length..expected_length).map(|index| ValLineError::new_with_loc(ErrorKind::Missing, input, index)),
+            );
+        }
         if errors.is_empty() {
+            if self.unique_items {
+                check_unique_items(py, &output)?;
+            }
+            Ok(PyTuple::new(py, &output).into_py(py))
+        } else if let Some(salvage) = extra.salvage {
+            // missing items are filled with `None` so the tuple has the expected length
+            output.extend((col_length..expected_length).map(|_| py.None()));
+            salvage.record(py, errors);
             Ok(PyTuple::new(py, &output).into_py(py))
         } else {
             Err(ValError::LineErrors(errors))
@@ -185,6 +233,10 @@ impl Validator for TuplePositionalValidator {
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         self.items_validators
             .iter_mut()
-            .try_for_each(|v| v.complete(build_context))
+            .try_for_each(|v| v.complete(build_context))?;
+        match self.extra_validator {
+            Some(ref mut v) => v.complete(build_context),
+            None => Ok(()),
+        }
     }
 }
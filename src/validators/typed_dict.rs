@@ -1,23 +1,24 @@
 use std::borrow::Cow;
 
+use pyo3::exceptions::PyBaseException;
 use pyo3::intern;
 use pyo3::prelude::*;
-#[cfg(not(PyPy))]
+#[cfg(not(any(PyPy, Py_LIMITED_API)))]
 use pyo3::types::PyFunction;
-use pyo3::types::{PyDict, PyList, PySet, PyString};
-#[cfg(not(PyPy))]
+use pyo3::types::{PyDict, PyList, PySet, PyString, PyTuple, PyType};
+#[cfg(not(any(PyPy, Py_LIMITED_API)))]
 use pyo3::PyTypeInfo;
 
 use ahash::AHashSet;
 
 use crate::build_tools::{is_strict, py_error, schema_or_config, schema_or_config_same, SchemaDict};
-use crate::errors::{py_err_string, ErrorKind, ValError, ValLineError, ValResult};
+use crate::errors::{py_err_string, CurrentLoc, ErrorKind, LocItem, ValError, ValLineError, ValResult};
 use crate::input::{GenericMapping, Input};
 use crate::lookup_key::LookupKey;
 use crate::recursion_guard::RecursionGuard;
 use crate::SchemaError;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, FieldReplacement, Validator};
 
 #[derive(Debug, Clone)]
 enum OnError {
@@ -37,6 +38,9 @@ struct TypedDictField {
     default_factory: Option<PyObject>,
     validator: CombinedValidator,
     frozen: bool,
+    description: Option<String>,
+    // set if values provided via more than one alias should be an error
+    lookup_choices: Option<Vec<LookupKey>>,
 }
 
 impl TypedDictField {
@@ -60,6 +64,12 @@ pub struct TypedDictValidator {
     strict: bool,
     from_attributes: bool,
     return_fields_set: bool,
+    error_descriptions: bool,
+    nested_delimiter: Option<String>,
+    // tuple of exception types treated like `AttributeError` when getting attributes
+    attribute_missing_errors: Option<Py<PyTuple>>,
+    // the schema's `title` if set, used in error messages and as the location of union choices
+    name: String,
 }
 
 impl BuildValidator for TypedDictValidator {
@@ -85,6 +95,53 @@ impl BuildValidator for TypedDictValidator {
         let populate_by_name = schema_or_config_same(schema, config, intern!(py, "populate_by_name"))?.unwrap_or(false);
 
         let return_fields_set = schema.get_as(intern!(py, "return_fields_set"))?.unwrap_or(false);
+        let forbid_multiple_values = schema_or_config(
+            schema,
+            config,
+            intern!(py, "forbid_multiple_values"),
+            intern!(py, "typed_dict_forbid_multiple_values"),
+        )?
+        .unwrap_or(false);
+        let error_descriptions = schema_or_config(
+            schema,
+            config,
+            intern!(py, "error_descriptions"),
+            intern!(py, "typed_dict_error_descriptions"),
+        )?
+        .unwrap_or(false);
+        let nested_delimiter: Option<String> = schema_or_config(
+            schema,
+            config,
+            intern!(py, "nested_delimiter"),
+            intern!(py, "typed_dict_nested_delimiter"),
+        )?;
+        if let Some(ref delimiter) = nested_delimiter {
+            if delimiter.is_empty() {
+                return py_error!("nested_delimiter cannot be an empty string");
+            }
+        }
+
+        let attribute_missing_errors: Option<&PyList> = schema_or_config(
+            schema,
+            config,
+            intern!(py, "attribute_missing_errors"),
+            intern!(py, "typed_dict_attribute_missing_errors"),
+        )?;
+        let attribute_missing_errors = match attribute_missing_errors {
+            Some(errors) => {
+                for error in errors {
+                    let is_exception = match error.cast_as::<PyType>() {
+                        Ok(error_type) => error_type.is_subclass_of::<PyBaseException>()?,
+                        Err(_) => false,
+                    };
+                    if !is_exception {
+                        return py_error!("attribute_missing_errors should only contain exception types");
+                    }
+                }
+                Some(PyTuple::new(py, errors).into())
+            }
+            None => None,
+        };
 
         let (check_extra, forbid_extra) = match extra_behavior {
             Some(s) => match s {
@@ -175,6 +232,11 @@ impl BuildValidator for TypedDictValidator {
                 None => OnError::Raise,
             };
 
+            let lookup_choices = match forbid_multiple_values {
+                true => Some(lookup_key.split_choices()).filter(|choices| choices.len() > 1),
+                false => None,
+            };
+
             fields.push(TypedDictField {
                 name: field_name.to_string(),
                 lookup_key,
@@ -188,6 +250,8 @@ impl BuildValidator for TypedDictValidator {
                 default_factory,
                 on_error,
                 frozen: field_info.get_as::<bool>(intern!(py, "frozen"))?.unwrap_or(false),
+                description: field_info.get_as(intern!(py, "description"))?,
+                lookup_choices,
             });
         }
 
@@ -199,6 +263,12 @@ impl BuildValidator for TypedDictValidator {
             strict,
             from_attributes,
             return_fields_set,
+            error_descriptions,
+            nested_delimiter,
+            attribute_missing_errors,
+            name: schema
+                .get_as(intern!(py, "title"))?
+                .unwrap_or_else(|| Self::EXPECTED_TYPE.to_string()),
         }
         .into())
     }
@@ -218,7 +288,13 @@ impl Validator for TypedDictValidator {
             return self.validate_assignment(py, field, input, extra, slots, recursion_guard);
         }
         let strict = extra.strict.unwrap_or(self.strict);
-        let dict = input.validate_typed_dict(strict, self.from_attributes)?;
+        let dict = match (
+            input.validate_typed_dict(strict, self.from_attributes)?,
+            &self.nested_delimiter,
+        ) {
+            (GenericMapping::PyDict(d), Some(delimiter)) => GenericMapping::PyDict(self.nest_flat_items(d, delimiter)?),
+            (dict, _) => dict,
+        };
 
         let output_dict = PyDict::new(py);
         let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
@@ -239,15 +315,28 @@ impl Validator for TypedDictValidator {
             field: None,
             strict: extra.strict,
             context: extra.context,
+            coercions: extra.coercions,
+            salvage: extra.salvage,
+            deadline: extra.deadline,
+            loc: extra.loc,
+            strings: extra.strings,
+            partial: extra.partial,
+            fail_fast: extra.fail_fast,
+            event_loop: extra.event_loop,
+            error_limit: extra.error_limit,
+            slots: extra.slots,
         };
 
         macro_rules! process {
-            ($dict:ident, $get_method:ident, $iter_method:ident) => {{
+            ($dict:ident, $get_method:ident $(($get_arg:expr))?, $iter_method:ident) => {{
                 for field in &self.fields {
-                    let op_key_value = match field.lookup_key.$get_method($dict) {
+                    if extra.fail_fast && !errors.is_empty() {
+                        break;
+                    }
+                    let op_key_value = match field.lookup_key.$get_method($dict $(, $get_arg)?) {
                         Ok(v) => v,
                         Err(err) => {
-                            errors.push(ValLineError::new_with_loc(
+                            extra.push_error(&mut errors, ValLineError::new_with_loc(
                                 ErrorKind::GetAttributeError {
                                     error: py_err_string(py, err),
                                 },
@@ -263,12 +352,65 @@ impl Validator for TypedDictValidator {
                             // extra logic either way
                             used_keys.insert(used_key);
                         }
-                        match field
-                            .validator
-                            .validate(py, value, &extra, slots, recursion_guard)
+                        if let Some(ref lookup_choices) = field.lookup_choices {
+                            let mut found: Vec<&LookupKey> = Vec::new();
+                            for choice in lookup_choices {
+                                if let Ok(Some((key, _))) = choice.$get_method($dict $(, $get_arg)?) {
+                                    if let Some(ref mut used_keys) = used_keys {
+                                        used_keys.insert(key);
+                                    }
+                                    found.push(choice);
+                                }
+                            }
+                            if found.len() > 1 {
+                                let keys = found.iter().map(|k| k.to_string()).collect::<Vec<_>>().join(", ");
+                                let err = ValLineError::new_with_loc(
+                                    ErrorKind::MultipleValues { keys },
+                                    input,
+                                    field.name.clone(),
+                                );
+                                extra.push_error(&mut errors, self.describe_error(field, err));
+                                continue;
+                            }
+                        }
+                        let coercions_start = extra.coercions.map(|c| c.len());
+                        let salvage_start = extra.salvage.map(|s| s.len());
+                        let omitted_start = extra.error_limit.map(|l| l.omitted());
+                        let field_loc = CurrentLoc::field(&field.name, extra.loc);
+                        let field_extra = Extra {
+                            loc: Some(&field_loc),
+                            ..extra
+                        };
+                        let result = match field.on_error {
+                            OnError::Raise => field
+                                .validator
+                                .validate(py, value, &field_extra, slots, recursion_guard),
+                            // errors are handled by the field, so there's nothing to salvage
+                            _ => field
+                                .validator
+                                .validate(py, value, &field_extra.without_salvage(), slots, recursion_guard),
+                        };
+                        if let (Some(salvage), Some(start)) = (extra.salvage, salvage_start) {
+                            salvage.with_outer_location(start, &field.name.clone().into());
+                        }
+                        // coercions within a field which failed didn't make it into the output
+                        if let (Some(coercions), Some(start), Err(_)) = (extra.coercions, coercions_start, &result) {
+                            coercions.truncate(start);
+                        }
+                        // errors of a field with `on_error` set are discarded, so are the errors omitted from them
+                        if let (Some(error_limit), Some(start), Err(_), OnError::Omit | OnError::FallbackOnDefault) =
+                            (extra.error_limit, omitted_start, &result, &field.on_error)
                         {
-                            Ok(value) => {
-                                output_dict.set_item(&field.name_pystring, value)?;
+                            error_limit.reset(start);
+                        }
+                        match result {
+                            Ok(output) => {
+                                if let (Some(coercions), Some(start)) = (extra.coercions, coercions_start) {
+                                    let loc_item: LocItem = field.name.clone().into();
+                                    coercions.with_outer_location(start, &loc_item);
+                                    coercions.record(loc_item, value.to_object(py).as_ref(py), output.as_ref(py))?;
+                                }
+                                output_dict.set_item(&field.name_pystring, output)?;
                                 if let Some(ref mut fs) = fields_set_vec {
                                     fs.push(field.name_pystring.clone_ref(py));
                                 }
@@ -276,7 +418,11 @@ impl Validator for TypedDictValidator {
                             Err(ValError::LineErrors(line_errors)) => match field.on_error {
                                 OnError::Raise => {
                                     for err in line_errors {
-                                        errors.push(err.with_outer_location(field.name.clone().into()));
+                                        let err = err.with_outer_location(field.name.clone().into());
+                                        extra.push_error(&mut errors, self.describe_error(field, err));
+                                    }
+                                    if extra.salvage.is_some() {
+                                        self.set_salvaged(py, output_dict, field)?;
                                     }
                                 }
                                 OnError::Omit => continue,
@@ -288,16 +434,36 @@ impl Validator for TypedDictValidator {
                             },
                             Err(err) => return Err(err),
                         }
+                    } else if extra.partial {
+                        // only the fields present are returned
+                        continue;
                     } else if let Some(default_value) = field.default_value(py)? {
                         output_dict.set_item(&field.name_pystring, default_value.as_ref())?
-                    } else if !field.required {
-                        continue;
                     } else {
-                        errors.push(ValLineError::new_with_loc(
-                            ErrorKind::Missing,
-                            input,
-                            field.name.clone(),
-                        ));
+                        let field_loc = CurrentLoc::field(&field.name, extra.loc);
+                        let field_extra = Extra {
+                            loc: Some(&field_loc),
+                            ..extra
+                        };
+                        match field.validator.default_value(py, &field_extra, slots, recursion_guard) {
+                            Ok(Some(default_value)) => output_dict.set_item(&field.name_pystring, default_value)?,
+                            Ok(None) if !field.required => continue,
+                            Ok(None) => {
+                                let err = ValLineError::new_with_loc(ErrorKind::Missing, input, field.name.clone());
+                                extra.push_error(&mut errors, self.describe_error(field, err));
+                                if extra.salvage.is_some() {
+                                    self.set_salvaged(py, output_dict, field)?;
+                                }
+                            }
+                            // the default failed validation with `validate_default`
+                            Err(ValError::LineErrors(line_errors)) => {
+                                for err in line_errors {
+                                    let err = err.with_outer_location(field.name.clone().into());
+                                    extra.push_error(&mut errors, self.describe_error(field, err));
+                                }
+                            }
+                            Err(err) => return Err(err),
+                        }
                     }
                 }
 
@@ -307,11 +473,14 @@ impl Validator for TypedDictValidator {
                         None => unreachable!(),
                     };
                     for (raw_key, value) in $dict.$iter_method() {
+                        if extra.fail_fast && !errors.is_empty() {
+                            break;
+                        }
                         let either_str = match raw_key.strict_str() {
                             Ok(k) => k,
                             Err(ValError::LineErrors(line_errors)) => {
                                 for err in line_errors {
-                                    errors.push(
+                                    extra.push_error(&mut errors,
                                         err.with_outer_location(raw_key.as_loc_item())
                                             .with_kind(ErrorKind::InvalidKey),
                                     );
@@ -325,7 +494,7 @@ impl Validator for TypedDictValidator {
                         }
 
                         if self.forbid_extra {
-                            errors.push(ValLineError::new_with_loc(
+                            extra.push_error(&mut errors, ValLineError::new_with_loc(
                                 ErrorKind::ExtraForbidden,
                                 value,
                                 raw_key.as_loc_item(),
@@ -334,10 +503,6 @@ impl Validator for TypedDictValidator {
                         }
 
                         let py_key = either_str.as_py_string(py);
-                        if let Some(ref mut fs) = fields_set_vec {
-                            fs.push(py_key.into_py(py));
-                        }
-
                         if let Some(ref validator) = self.extra_validator {
                             match validator.validate(py, value, &extra, slots, recursion_guard) {
                                 Ok(value) => {
@@ -348,7 +513,7 @@ impl Validator for TypedDictValidator {
                                 }
                                 Err(ValError::LineErrors(line_errors)) => {
                                     for err in line_errors {
-                                        errors.push(err.with_outer_location(raw_key.as_loc_item()));
+                                        extra.push_error(&mut errors, err.with_outer_location(raw_key.as_loc_item()));
                                     }
                                 }
                                 Err(err) => return Err(err),
@@ -365,13 +530,21 @@ impl Validator for TypedDictValidator {
         }
         match dict {
             GenericMapping::PyDict(d) => process!(d, py_get_item, iter),
-            GenericMapping::PyGetAttr(d) => process!(d, py_get_attr, iter_attrs),
+            GenericMapping::PyGetAttr(d) => process!(
+                d,
+                py_get_attr(self.attribute_missing_errors.as_ref().map(|e| e.as_ref(py).as_ref())),
+                iter_attrs
+            ),
             GenericMapping::JsonObject(d) => process!(d, json_get, iter),
         }
 
         if !errors.is_empty() {
-            Err(ValError::LineErrors(errors))
-        } else if let Some(fs) = fields_set_vec {
+            match extra.salvage {
+                Some(salvage) => salvage.record(py, errors),
+                None => return Err(ValError::LineErrors(errors)),
+            }
+        }
+        if let Some(fs) = fields_set_vec {
             let fields_set = PySet::new(py, &fs)?;
             Ok((output_dict, fields_set).to_object(py))
         } else {
@@ -379,8 +552,78 @@ impl Validator for TypedDictValidator {
         }
     }
 
+    fn construct(
+        &self,
+        py: Python,
+        data: &PyDict,
+        fields_set: Option<&PyAny>,
+        _slots: &[CombinedValidator],
+    ) -> PyResult<PyObject> {
+        // all values provided are kept as they are, including extra keys
+        let output_dict = data.copy()?;
+        for field in &self.fields {
+            if output_dict.contains(&field.name_pystring)? {
+                continue;
+            }
+            if let Some(default_value) = field.default_value(py)? {
+                output_dict.set_item(&field.name_pystring, default_value.as_ref())?;
+            } else if let Some(default_value) = field.validator.unvalidated_default(py)? {
+                output_dict.set_item(&field.name_pystring, default_value)?;
+            }
+        }
+        if self.return_fields_set {
+            let fields_set = match fields_set {
+                Some(fields_set) => fields_set,
+                None => PySet::new(py, &data.keys().iter().collect::<Vec<_>>())?,
+            };
+            Ok((output_dict, fields_set).to_object(py))
+        } else {
+            Ok(output_dict.to_object(py))
+        }
+    }
+
+    fn find_field<'s>(
+        &'s self,
+        py: Python,
+        field_path: &[String],
+        slots: &'s [CombinedValidator],
+    ) -> PyResult<Option<(Option<PyObject>, &'s CombinedValidator)>> {
+        let (name, rest) = match field_path.split_first() {
+            Some(split) => split,
+            None => return Ok(None),
+        };
+        let field = match self.fields.iter().find(|field| &field.name == name) {
+            Some(field) => field,
+            None => return Ok(None),
+        };
+        if !rest.is_empty() {
+            return field.validator.find_field(py, rest, slots);
+        }
+        let default = match field.default_value(py)? {
+            Some(default_value) => Some(default_value.as_ref().clone_ref(py)),
+            None => field.validator.unvalidated_default(py)?,
+        };
+        Ok(Some((default, &field.validator)))
+    }
+
+    fn replace_field(&mut self, field_path: &[String], validator: CombinedValidator) -> FieldReplacement {
+        let (name, rest) = match field_path.split_first() {
+            Some(split) => split,
+            None => return FieldReplacement::NotFound,
+        };
+        let field = match self.fields.iter_mut().find(|field| &field.name == name) {
+            Some(field) => field,
+            None => return FieldReplacement::NotFound,
+        };
+        if !rest.is_empty() {
+            return field.validator.replace_field(rest, validator);
+        }
+        field.validator = validator;
+        FieldReplacement::Replaced
+    }
+
     fn get_name(&self) -> &str {
-        Self::EXPECTED_TYPE
+        &self.name
     }
 
     fn ask(&self, question: &str) -> bool {
@@ -399,6 +642,44 @@ impl Validator for TypedDictValidator {
 }
 
 impl TypedDictValidator {
+    /// Add the field's description to an error if `error_descriptions` is enabled
+    fn describe_error<'data>(&self, field: &TypedDictField, error: ValLineError<'data>) -> ValLineError<'data> {
+        match (self.error_descriptions, &field.description) {
+            (true, Some(description)) => error.with_field_description(description),
+            _ => error,
+        }
+    }
+
+    /// Group the items of a flat dict like `{'DB__HOST': 'x'}` under the key of the field they belong to, e.g.
+    /// `{'DB': {'HOST': 'x'}}`, fields which are present in the dict are left unchanged
+    fn nest_flat_items<'data>(&self, dict: &'data PyDict, delimiter: &str) -> PyResult<&'data PyDict> {
+        let mut output: Option<&PyDict> = None;
+        for field in &self.fields {
+            if field.lookup_key.py_get_item(dict)?.is_some() {
+                continue;
+            }
+            if let Some((key, nested)) = field.lookup_key.py_get_nested(dict, delimiter)? {
+                let output = match output {
+                    Some(output) => output,
+                    None => output.insert(dict.copy()?),
+                };
+                for sub_key in nested.keys() {
+                    output.del_item(format!("{}{}{}", key, delimiter, sub_key))?;
+                }
+                output.set_item(key, nested)?;
+            }
+        }
+        Ok(output.unwrap_or(dict))
+    }
+
+    /// In salvage mode, invalid and missing fields are set to their default or `None`
+    fn set_salvaged(&self, py: Python, output_dict: &PyDict, field: &TypedDictField) -> PyResult<()> {
+        match field.default_value(py)? {
+            Some(default_value) => output_dict.set_item(&field.name_pystring, default_value.as_ref()),
+            None => output_dict.set_item(&field.name_pystring, py.None()),
+        }
+    }
+
     fn validate_assignment<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -427,28 +708,53 @@ impl TypedDictValidator {
             }
         };
 
-        let prepare_result = |result: ValResult<'data, PyObject>| match result {
-            Ok(output) => prepare_tuple(output),
-            Err(ValError::LineErrors(line_errors)) => {
-                let errors = line_errors
-                    .into_iter()
-                    .map(|e| e.with_outer_location(field.to_string().into()))
-                    .collect();
-                Err(ValError::LineErrors(errors))
-            }
-            Err(err) => Err(err),
-        };
+        let prepare_result =
+            |result: ValResult<'data, PyObject>, typed_dict_field: Option<&TypedDictField>| match result {
+                Ok(output) => prepare_tuple(output),
+                Err(ValError::LineErrors(line_errors)) => {
+                    let errors = line_errors
+                        .into_iter()
+                        .map(|e| {
+                            let e = e.with_outer_location(field.to_string().into());
+                            match typed_dict_field {
+                                Some(typed_dict_field) => self.describe_error(typed_dict_field, e),
+                                None => e,
+                            }
+                        })
+                        .collect();
+                    Err(ValError::LineErrors(errors))
+                }
+                Err(err) => Err(err),
+            };
 
         if let Some(field) = self.fields.iter().find(|f| f.name == field) {
             if field.frozen {
                 Err(ValError::new_with_loc(ErrorKind::Frozen, input, field.name.to_string()))
             } else {
-                prepare_result(field.validator.validate(py, input, extra, slots, recursion_guard))
+                let field_loc = CurrentLoc::field(&field.name, extra.loc);
+                // the field's own validator isn't validating assignment, e.g. where the field is a nested model
+                let field_extra = Extra {
+                    field: None,
+                    loc: Some(&field_loc),
+                    ..*extra
+                };
+                prepare_result(
+                    field
+                        .validator
+                        .validate(py, input, &field_extra, slots, recursion_guard),
+                    Some(field),
+                )
             }
         } else if self.check_extra && !self.forbid_extra {
             // this is the "allow" case of extra_behavior
             match self.extra_validator {
-                Some(ref validator) => prepare_result(validator.validate(py, input, extra, slots, recursion_guard)),
+                Some(ref validator) => {
+                    let validator_extra = Extra { field: None, ..*extra };
+                    prepare_result(
+                        validator.validate(py, input, &validator_extra, slots, recursion_guard),
+                        None,
+                    )
+                }
                 None => prepare_tuple(input.to_object(py)),
             }
         } else {
@@ -492,9 +798,9 @@ impl<'a> Iterator for AttributesIterator<'a> {
         // or we get to the end of the list of attributes
         loop {
             if self.index < self.attributes.len() {
-                #[cfg(PyPy)]
+                #[cfg(any(PyPy, Py_LIMITED_API))]
                 let name: &PyAny = self.attributes.get_item(self.index).unwrap();
-                #[cfg(not(PyPy))]
+                #[cfg(not(any(PyPy, Py_LIMITED_API)))]
                 let name: &PyAny = unsafe { self.attributes.get_item_unchecked(self.index) };
                 self.index += 1;
                 // from benchmarks this is 14x faster than using the python `startswith` method
@@ -511,14 +817,14 @@ impl<'a> Iterator for AttributesIterator<'a> {
                         // the PyFunction::is_type_of(attr) catches `staticmethod`, but also any other function,
                         // I think that's better than including static methods in the yielded attributes,
                         // if someone really wants fields, they can use an explicit field, or a function to modify input
-                        #[cfg(not(PyPy))]
+                        #[cfg(not(any(PyPy, Py_LIMITED_API)))]
                         if !is_bound && !PyFunction::is_type_of(attr) {
                             return Some((name, attr));
                         }
-                        // MASSIVE HACK! PyFunction doesn't exist for PyPy,
+                        // MASSIVE HACK! PyFunction doesn't exist for PyPy or the limited API,
                         // is_instance_of::<PyFunction> crashes with a null pointer, hence this hack, see
                         // https://github.com/pydantic/pydantic-core/pull/161#discussion_r917257635
-                        #[cfg(PyPy)]
+                        #[cfg(any(PyPy, Py_LIMITED_API))]
                         if !is_bound && attr.get_type().to_string() != "<class 'function'>" {
                             return Some((name, attr));
                         }
@@ -7,7 +7,7 @@ use pyo3::types::{PyDict, PyList, PyString};
 
 use ahash::AHashMap;
 
-use crate::build_tools::{is_strict, schema_or_config, SchemaDict};
+use crate::build_tools::{is_strict, py_error, schema_or_config, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
 use crate::input::{GenericMapping, Input};
 use crate::lookup_key::LookupKey;
@@ -15,10 +15,19 @@ use crate::recursion_guard::RecursionGuard;
 
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
+#[derive(Debug, Clone)]
+enum UnionMode {
+    // try all choices in strict mode first, so exact matches win over coercion, then in lax mode
+    Smart,
+    // return the first choice which succeeds, in lax mode
+    LeftToRight,
+}
+
 #[derive(Debug, Clone)]
 pub struct UnionValidator {
     choices: Vec<CombinedValidator>,
     strict: bool,
+    mode: UnionMode,
     name: String,
 }
 
@@ -38,9 +47,16 @@ impl BuildValidator for UnionValidator {
 
         let descr = choices.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(",");
 
+        let mode = match schema.get_as::<&str>(intern!(schema.py(), "mode"))? {
+            Some("smart") | None => UnionMode::Smart,
+            Some("left_to_right") => UnionMode::LeftToRight,
+            Some(s) => return py_error!(r#"Invalid union mode: "{}""#, s),
+        };
+
         Ok(Self {
             choices,
             strict: is_strict(schema, config)?,
+            mode,
             name: format!("{}[{}]", Self::EXPECTED_TYPE, descr),
         }
         .into())
@@ -56,6 +72,22 @@ impl Validator for UnionValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        // a salvaged value would always be picked, even where another choice is valid
+        let extra = &extra.without_salvage();
+        // coercions recorded by a choice which fails are discarded
+        let coercions_start = extra.coercions.map(|c| c.len());
+        let discard_coercions = || {
+            if let (Some(coercions), Some(start)) = (extra.coercions, coercions_start) {
+                coercions.truncate(start);
+            }
+        };
+        // errors of choices which fail are discarded when another choice succeeds, so are the errors omitted from them
+        let omitted_start = extra.error_limit.map(|l| l.omitted());
+        let discard_omitted = || {
+            if let (Some(error_limit), Some(start)) = (extra.error_limit, omitted_start) {
+                error_limit.reset(start);
+            }
+        };
         if extra.strict.unwrap_or(self.strict) {
             let mut errors: Vec<ValLineError> = Vec::with_capacity(self.choices.len());
             let strict_extra = extra.as_strict();
@@ -63,8 +95,13 @@ impl Validator for UnionValidator {
             for validator in &self.choices {
                 let line_errors = match validator.validate(py, input, &strict_extra, slots, recursion_guard) {
                     Err(ValError::LineErrors(line_errors)) => line_errors,
-                    otherwise => return otherwise,
+                    Ok(output) => {
+                        discard_omitted();
+                        return Ok(output);
+                    }
+                    Err(err) => return Err(err),
                 };
+                discard_coercions();
 
                 errors.extend(
                     line_errors
@@ -75,16 +112,27 @@ impl Validator for UnionValidator {
 
             Err(ValError::LineErrors(errors))
         } else {
-            // 1st pass: check if the value is an exact instance of one of the Union types,
-            // e.g. use validate in strict mode
-            let strict_extra = extra.as_strict();
-            if let Some(res) = self
-                .choices
-                .iter()
-                .map(|validator| validator.validate(py, input, &strict_extra, slots, recursion_guard))
-                .find(ValResult::is_ok)
-            {
-                return res;
+            if let UnionMode::Smart = self.mode {
+                // 1st pass: check if the value is an exact instance of one of the Union types,
+                // e.g. use validate in strict mode
+                let strict_extra = extra.as_strict();
+                if let Some(res) = self
+                    .choices
+                    .iter()
+                    .map(|validator| {
+                        let res = validator.validate(py, input, &strict_extra, slots, recursion_guard);
+                        if res.is_err() {
+                            discard_coercions();
+                        }
+                        res
+                    })
+                    .find(ValResult::is_ok)
+                {
+                    discard_omitted();
+                    return res;
+                }
+                // the errors of the strict pass are never reported
+                discard_omitted();
             }
 
             let mut errors: Vec<ValLineError> = Vec::with_capacity(self.choices.len());
@@ -93,8 +141,13 @@ impl Validator for UnionValidator {
             for validator in &self.choices {
                 let line_errors = match validator.validate(py, input, extra, slots, recursion_guard) {
                     Err(ValError::LineErrors(line_errors)) => line_errors,
-                    success => return success,
+                    Ok(output) => {
+                        discard_omitted();
+                        return Ok(output);
+                    }
+                    Err(err) => return Err(err),
                 };
+                discard_coercions();
 
                 errors.extend(
                     line_errors
@@ -224,13 +277,14 @@ impl Validator for TaggedUnionValidator {
     ) -> ValResult<'data, PyObject> {
         match self.discriminator {
             Discriminator::LookupKey(ref lookup_key) => {
+                let strict = extra.strict.unwrap_or(self.strict);
                 macro_rules! find_validator {
-                    ($dict:ident, $get_method:ident) => {{
+                    ($dict:ident, $get_method:ident $(, $get_arg:expr)?) => {{
                         // note all these methods return PyResult<Option<(data, data)>>, the outer Err is just for
                         // errors when getting attributes which should be "raised"
-                        match lookup_key.$get_method($dict)? {
+                        match lookup_key.$get_method($dict $(, $get_arg)?)? {
                             Some((_, value)) => {
-                                if self.strict {
+                                if strict {
                                     value.strict_str()
                                 } else {
                                     value.lax_str()
@@ -240,10 +294,10 @@ impl Validator for TaggedUnionValidator {
                         }
                     }};
                 }
-                let dict = input.validate_typed_dict(self.strict, self.from_attributes)?;
+                let dict = input.validate_typed_dict(strict, self.from_attributes)?;
                 let tag = match dict {
                     GenericMapping::PyDict(dict) => find_validator!(dict, py_get_item),
-                    GenericMapping::PyGetAttr(obj) => find_validator!(obj, py_get_attr),
+                    GenericMapping::PyGetAttr(obj) => find_validator!(obj, py_get_attr, None),
                     GenericMapping::JsonObject(mapping) => find_validator!(mapping, json_get),
                 }?;
                 self.find_call_validator(py, tag.as_cow()?, input, extra, slots, recursion_guard)
@@ -299,6 +353,10 @@ impl TaggedUnionValidator {
                     Some(t) => t.strict_str()?,
                     None => return Err(self.tag_not_found(input)),
                 },
+                GenericMapping::JsonObject(dict) => match dict.get("type") {
+                    Some(t) => t.strict_str()?,
+                    None => return Err(self.tag_not_found(input)),
+                },
                 _ => unreachable!(),
             };
             let tag_cow = either_tag.as_cow()?;
@@ -310,6 +368,10 @@ impl TaggedUnionValidator {
                         Some(m) => Some(m.strict_str()?),
                         None => None,
                     },
+                    GenericMapping::JsonObject(dict) => match dict.get("mode") {
+                        Some(m) => Some(m.strict_str()?),
+                        None => None,
+                    },
                     _ => unreachable!(),
                 };
                 if tag == "function" {
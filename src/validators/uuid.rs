@@ -0,0 +1,111 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::{BytesEncoding, EitherBytes, Input};
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// the length of the raw bytes of a UUID, as used by `UUID(bytes=...)`
+const UUID_BYTES_LENGTH: usize = 16;
+
+#[derive(Debug, Clone)]
+pub struct UuidValidator {
+    strict: bool,
+    uuid_class: Py<PyType>,
+}
+
+impl BuildValidator for UuidValidator {
+    const EXPECTED_TYPE: &'static str = "uuid";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            uuid_class: py.import("uuid")?.getattr("UUID")?.cast_as::<PyType>()?.into(),
+        }
+        .into())
+    }
+}
+
+impl Validator for UuidValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let uuid_class = self.uuid_class.as_ref(py);
+        if input.is_instance(uuid_class)? {
+            return Ok(input.to_object(py));
+        } else if extra.strict.unwrap_or(self.strict) && input.is_python() {
+            return Err(ValError::new(ErrorKind::UuidType, input));
+        }
+
+        if let Ok(either_str) = input.strict_str() {
+            let str = either_str.as_cow()?;
+            return match uuid_class.call1((str.as_ref(),)) {
+                Ok(uuid) => Ok(uuid.into_py(py)),
+                Err(err) if err.is_instance_of::<PyValueError>(py) => {
+                    // JSON has no bytes type, so raw UUID bytes are accepted base64 encoded
+                    if !input.is_python() {
+                        if let Ok(either_bytes) = input.strict_bytes(BytesEncoding::Base64) {
+                            if let Some(uuid) = self.uuid_from_bytes(py, either_bytes)? {
+                                return Ok(uuid);
+                            }
+                        }
+                    }
+                    Err(ValError::new(
+                        ErrorKind::UuidParsing {
+                            error: err.value(py).to_string(),
+                        },
+                        input,
+                    ))
+                }
+                Err(err) => Err(err.into()),
+            };
+        }
+
+        // raw bytes, e.g. from a database driver
+        if let Ok(either_bytes) = input.strict_bytes(BytesEncoding::Utf8) {
+            let length = either_bytes.len()?;
+            return match self.uuid_from_bytes(py, either_bytes)? {
+                Some(uuid) => Ok(uuid),
+                None => Err(ValError::new(
+                    ErrorKind::UuidParsing {
+                        error: format!("expected {} bytes, got {}", UUID_BYTES_LENGTH, length),
+                    },
+                    input,
+                )),
+            };
+        }
+        Err(ValError::new(ErrorKind::UuidType, input))
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+impl UuidValidator {
+    /// Build a UUID from its raw bytes, `None` if there aren't exactly 16 bytes
+    fn uuid_from_bytes(&self, py: Python, either_bytes: EitherBytes) -> PyResult<Option<PyObject>> {
+        if either_bytes.len()? != UUID_BYTES_LENGTH {
+            return Ok(None);
+        }
+        let kwargs = PyDict::new(py);
+        kwargs.set_item(intern!(py, "bytes"), either_bytes.into_py(py))?;
+        let uuid = self.uuid_class.as_ref(py).call((), Some(kwargs))?;
+        Ok(Some(uuid.into_py(py)))
+    }
+}
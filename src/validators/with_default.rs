@@ -0,0 +1,160 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::{py_error, SchemaDict};
+use crate::errors::{ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, FieldReplacement, Validator};
+
+#[derive(Debug, Clone)]
+enum DefaultType {
+    None,
+    Default(PyObject),
+    DefaultFactory(PyObject),
+}
+
+#[derive(Debug, Clone)]
+enum OnError {
+    Raise,
+    Default,
+}
+
+/// Wraps a schema with a default value, used by typed-dicts when the field is missing and optionally
+/// when validation of the field fails
+#[derive(Debug, Clone)]
+pub struct WithDefaultValidator {
+    default: DefaultType,
+    on_error: OnError,
+    validator: Box<CombinedValidator>,
+    validate_default: bool,
+    name: String,
+}
+
+impl BuildValidator for WithDefaultValidator {
+    const EXPECTED_TYPE: &'static str = "with-default";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let default = match (
+            schema.get_as(intern!(py, "default"))?,
+            schema.get_as(intern!(py, "default_factory"))?,
+        ) {
+            (Some(_), Some(_)) => return py_error!("'default' and 'default_factory' cannot be used together"),
+            (Some(default), None) => DefaultType::Default(default),
+            (None, Some(default_factory)) => DefaultType::DefaultFactory(default_factory),
+            (None, None) => DefaultType::None,
+        };
+
+        let on_error = match schema.get_as::<&str>(intern!(py, "on_error"))? {
+            Some("default") => match default {
+                DefaultType::None => {
+                    return py_error!("'on_error = default' requires a `default` or `default_factory`")
+                }
+                _ => OnError::Default,
+            },
+            _ => OnError::Raise,
+        };
+
+        let sub_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
+        let validator = Box::new(build_validator(sub_schema, config, build_context)?);
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+
+        Ok(Self {
+            default,
+            on_error,
+            validator,
+            validate_default: schema.get_as(intern!(py, "validate_default"))?.unwrap_or(false),
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for WithDefaultValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let omitted_start = extra.error_limit.map(|l| l.omitted());
+        match self.validator.validate(py, input, extra, slots, recursion_guard) {
+            Err(ValError::LineErrors(line_errors)) => match self.on_error {
+                OnError::Raise => Err(ValError::LineErrors(line_errors)),
+                OnError::Default => {
+                    // the errors are discarded, so are the errors omitted from them
+                    if let (Some(error_limit), Some(start)) = (extra.error_limit, omitted_start) {
+                        error_limit.reset(start);
+                    }
+                    // the default is known to exist since it's checked in `build`
+                    Ok(self.default_value(py, extra, slots, recursion_guard)?.unwrap())
+                }
+            },
+            result => result,
+        }
+    }
+
+    fn default_value<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, Option<PyObject>> {
+        let default = match self.unvalidated_default(py)? {
+            Some(default) => default,
+            None => return Ok(None),
+        };
+        match self.validate_default {
+            true => {
+                let output = self
+                    .validator
+                    .validate(py, default.into_ref(py), extra, slots, recursion_guard)?;
+                Ok(Some(output))
+            }
+            false => Ok(Some(default)),
+        }
+    }
+
+    fn unvalidated_default(&self, py: Python) -> PyResult<Option<PyObject>> {
+        match self.default {
+            DefaultType::None => Ok(None),
+            DefaultType::Default(ref default) => Ok(Some(default.clone_ref(py))),
+            DefaultType::DefaultFactory(ref default_factory) => Ok(Some(default_factory.call0(py)?)),
+        }
+    }
+
+    fn find_field<'s>(
+        &'s self,
+        py: Python,
+        field_path: &[String],
+        slots: &'s [CombinedValidator],
+    ) -> PyResult<Option<(Option<PyObject>, &'s CombinedValidator)>> {
+        self.validator.find_field(py, field_path, slots)
+    }
+
+    fn replace_field(&mut self, field_path: &[String], validator: CombinedValidator) -> FieldReplacement {
+        self.validator.replace_field(field_path, validator)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn ask(&self, question: &str) -> bool {
+        self.validator.ask(question)
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.validator.complete(build_context)
+    }
+}
@@ -0,0 +1,83 @@
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+
+use crate::build_tools::{is_strict, py_error};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// Validates IANA timezone names like "Europe/London" into `zoneinfo.ZoneInfo` objects
+#[derive(Debug, Clone)]
+pub struct ZoneInfoValidator {
+    strict: bool,
+    zone_info_class: Py<PyType>,
+    // shared between clones of the validator, only names which were found are cached
+    cache: Py<PyDict>,
+}
+
+impl BuildValidator for ZoneInfoValidator {
+    const EXPECTED_TYPE: &'static str = "zoneinfo";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let zone_info_module = match py.import("zoneinfo") {
+            Ok(module) => module,
+            Err(_) => return py_error!("zoneinfo validators require python 3.9 or later"),
+        };
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            zone_info_class: zone_info_module.getattr("ZoneInfo")?.cast_as::<PyType>()?.into(),
+            cache: PyDict::new(py).into(),
+        }
+        .into())
+    }
+}
+
+impl Validator for ZoneInfoValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let zone_info_class = self.zone_info_class.as_ref(py);
+        if input.is_instance(zone_info_class)? {
+            return Ok(input.to_object(py));
+        } else if extra.strict.unwrap_or(self.strict) && input.is_python() {
+            return Err(ValError::new(ErrorKind::TimezoneType, input));
+        }
+
+        let either_str = input
+            .strict_str()
+            .map_err(|_| ValError::new(ErrorKind::TimezoneType, input))?;
+        let name = either_str.as_py_string(py);
+        let cache = self.cache.as_ref(py);
+        if let Some(zone_info) = cache.get_item(name) {
+            return Ok(zone_info.into_py(py));
+        }
+        match zone_info_class.call1((name,)) {
+            Ok(zone_info) => {
+                cache.set_item(name, zone_info)?;
+                Ok(zone_info.into_py(py))
+            }
+            // `ZoneInfoNotFoundError` is a subclass of `KeyError`, malformed names like "../x" raise `ValueError`
+            Err(err) if err.is_instance_of::<PyKeyError>(py) || err.is_instance_of::<PyValueError>(py) => {
+                Err(ValError::new(ErrorKind::TimezoneNotFound, input))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
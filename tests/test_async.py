@@ -0,0 +1,129 @@
+import asyncio
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+
+async def double(input_value, **kwargs):
+    await asyncio.sleep(0)
+    if input_value == 0:
+        raise ValueError('zero is not allowed')
+    return input_value * 2
+
+
+def test_after():
+    v = SchemaValidator(
+        {'type': 'list', 'items_schema': {'type': 'function', 'mode': 'after', 'function': double, 'schema': 'int'}}
+    )
+
+    async def main():
+        assert await v.validate_python_async([1, '2']) == [2, 4]
+        with pytest.raises(ValidationError) as exc_info:
+            await v.validate_python_async([1, 0, 'x'])
+        assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [
+            ('value_error', [1]),
+            ('int_parsing', [2]),
+        ]
+
+    asyncio.run(main())
+
+
+def test_before_and_plain():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': {'type': 'function', 'mode': 'before', 'function': double, 'schema': 'str'}},
+                'b': {'schema': {'type': 'function', 'mode': 'plain', 'function': double}},
+            },
+        }
+    )
+
+    async def main():
+        return await v.validate_python_async({'a': 'x', 'b': 3})
+
+    assert asyncio.run(main()) == {'a': 'xx', 'b': 6}
+
+
+def test_wrap():
+    async def f(input_value, validator, **kwargs):
+        await asyncio.sleep(0)
+        return f'wrapped {input_value}'
+
+    def sync_wrap(input_value, validator, **kwargs):
+        return validator(input_value) + 1
+
+    v = SchemaValidator(
+        {
+            'type': 'tuple',
+            'mode': 'positional',
+            'items_schema': [
+                {'type': 'function', 'mode': 'wrap', 'function': f, 'schema': 'int'},
+                {
+                    'type': 'function',
+                    'mode': 'wrap',
+                    'function': sync_wrap,
+                    'schema': {'type': 'function', 'mode': 'after', 'function': double, 'schema': 'int'},
+                },
+            ],
+        }
+    )
+
+    async def main():
+        return await v.validate_python_async((1, 2))
+
+    assert asyncio.run(main()) == ('wrapped 1', 5)
+
+
+def test_handler_on_event_loop():
+    async def f(input_value, validator, **kwargs):
+        return validator(input_value)
+
+    v = SchemaValidator(
+        {
+            'type': 'function',
+            'mode': 'wrap',
+            'function': f,
+            'schema': {'type': 'function', 'mode': 'after', 'function': double, 'schema': 'int'},
+        }
+    )
+
+    async def main():
+        await v.validate_python_async(1)
+
+    with pytest.raises(RuntimeError, match="^Coroutines can't be awaited by validation running on the event loop$"):
+        asyncio.run(main())
+
+
+def test_strict_and_context():
+    async def f(input_value, context, **kwargs):
+        return input_value + context
+
+    v = SchemaValidator({'type': 'function', 'mode': 'after', 'function': f, 'schema': 'int'})
+
+    async def main():
+        assert await v.validate_python_async('1', context=10) == 11
+        with pytest.raises(ValidationError, match=r'Input should be a valid integer \[kind=int_type'):
+            await v.validate_python_async('1', strict=True, context=10)
+
+    asyncio.run(main())
+
+
+def test_event_loop():
+    v = SchemaValidator({'type': 'function', 'mode': 'after', 'function': double, 'schema': 'int'})
+    loop = asyncio.new_event_loop()
+    try:
+        assert loop.run_until_complete(v.validate_python_async(2, event_loop=loop)) == 4
+    finally:
+        loop.close()
+
+    with pytest.raises(RuntimeError, match='no running event loop'):
+        v.validate_python_async(2)
+
+
+def test_sync_validation_unchanged():
+    v = SchemaValidator({'type': 'function', 'mode': 'after', 'function': double, 'schema': 'int'})
+    coroutine = v.validate_python(1)
+    assert asyncio.iscoroutine(coroutine)
+    assert asyncio.run(coroutine) == 2
@@ -1,8 +1,11 @@
+import copy
+import json
 import pickle
+from collections import deque
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 
 def test_build_error_type():
@@ -39,6 +42,48 @@ def test_schema_wrong_type():
     )
 
 
+def test_build_error_path():
+    schema = {
+        'type': 'typed-dict',
+        'fields': {'address': {'schema': {'type': 'list', 'items_schema': {'type': 'str', 'pattern': '(abc'}}}},
+    }
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(schema)
+    assert exc_info.value.schema_path == ['fields', 'address', 'schema', 'items_schema']
+    assert exc_info.value.args[0] == (
+        'Error building "str" validator at fields -> address -> schema -> items_schema:\n'
+        '  SchemaError: regex parse error:\n'
+        '    (abc\n'
+        '    ^\n'
+        'error: unclosed group'
+    )
+
+
+def test_build_error_path_index():
+    schema = {'type': 'union', 'choices': ['int', {'type': 'with-default', 'schema': 'int', 'on_error': 'default'}]}
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(schema)
+    assert exc_info.value.schema_path == ['choices', 1]
+    assert exc_info.value.args[0].startswith('Error building "with-default" validator at choices -> 1:\n')
+
+
+def test_build_error_root():
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator({'type': 'with-default', 'schema': 'int', 'on_error': 'default'})
+    assert exc_info.value.schema_path == []
+    assert SchemaError('test').schema_path == []
+
+
+def test_schema_unknown_key():
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator({'type': 'typed-dict', 'fields': {'name': {'schema': {'type': 'str', 'min_len': 1}}}})
+    assert exc_info.value.args[0] == (
+        'Invalid Schema:\n'
+        'typed-dict -> fields -> name -> schema -> str -> min_len\n'
+        '  Extra inputs are not permitted [kind=extra_forbidden, input_value=1, input_type=int]'
+    )
+
+
 @pytest.mark.parametrize('pickle_protocol', range(1, pickle.HIGHEST_PROTOCOL + 1))
 def test_pickle(pickle_protocol: int) -> None:
     v1 = SchemaValidator({'type': 'bool'})
@@ -49,6 +94,79 @@ def test_pickle(pickle_protocol: int) -> None:
     assert repr(v1) == repr(v2)
 
 
+@pytest.mark.parametrize('pickle_protocol', range(1, pickle.HIGHEST_PROTOCOL + 1))
+def test_pickle_config(pickle_protocol: int) -> None:
+    v1 = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'str'}}},
+        {'strict': True, 'max_errors': 1},
+    )
+    v2 = pickle.loads(pickle.dumps(v1, protocol=pickle_protocol))
+    assert repr(v1) == repr(v2)
+    assert v2.validate_python({'a': 1, 'b': 'x'}) == {'a': 1, 'b': 'x'}
+    with pytest.raises(ValidationError) as exc_info:
+        v2.validate_python({'a': '1', 'b': b'x'})
+    assert exc_info.value.error_count() == 1
+    assert exc_info.value.omitted_error_count() == 1
+
+
+def test_copy():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        return input_value
+
+    v1 = SchemaValidator(
+        {'type': 'list', 'items_schema': {'type': 'function', 'mode': 'plain', 'function': f}}, {'max_errors': 3}
+    )
+    for v2 in (copy.copy(v1), copy.deepcopy(v1), copy.deepcopy({'v': v1})['v']):
+        assert v2 is not v1
+        assert repr(v2) == repr(v1)
+        assert v2.validate_python([1, 2]) == [1, 2]
+    assert calls == [1, 2] * 3
+
+
+def test_equality():
+    def f(input_value, **kwargs):
+        return input_value
+
+    def schema(function=f):
+        return {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': {'type': 'function', 'mode': 'plain', 'function': function}},
+            },
+        }
+
+    v1 = SchemaValidator(schema())
+    v2 = SchemaValidator(schema())
+    assert v1 == v2
+    assert not v1 != v2
+    assert hash(v1) == hash(v2)
+    assert v1.schema_hash() == v2.schema_hash()
+    assert v1 == copy.copy(v1)
+    assert pickle.loads(pickle.dumps(SchemaValidator('int'))) == SchemaValidator('int')
+    assert len({v1, v2, SchemaValidator(schema())}) == 1
+
+    # string schemas are the same as their dict form
+    assert SchemaValidator('int') == SchemaValidator({'type': 'int'})
+    # functions are compared by identity
+    assert v1 != SchemaValidator(schema(lambda input_value, **kwargs: input_value))
+    # the config matters
+    assert v1 != SchemaValidator(schema(), {'strict': True})
+    assert v1.schema_hash() != SchemaValidator(schema(), {'strict': True}).schema_hash()
+    # values of different types differ even if python considers them equal
+    literal_1 = SchemaValidator({'type': 'literal', 'expected': [1]})
+    assert literal_1 != SchemaValidator({'type': 'literal', 'expected': [True]})
+    assert literal_1 != SchemaValidator({'type': 'literal', 'expected': [1.0]})
+    assert literal_1 == SchemaValidator({'type': 'literal', 'expected': [1]})
+
+    assert v1 != 1
+    with pytest.raises(TypeError):
+        v1 < v2
+
+
 def test_schema_recursive_error():
     schema = {'type': 'union', 'choices': []}
     schema['choices'].append({'type': 'nullable', 'schema': schema})
@@ -84,3 +202,199 @@ def test_try_self_schema_discriminator():
     """Trying to use self-schema when it shouldn't be used"""
     v = SchemaValidator({'type': 'tagged-union', 'choices': {'int': 'int'}, 'discriminator': 'self-schema'})
     assert 'discriminator: LookupKey' in repr(v)
+
+
+def test_warmup():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'dt': {'schema': 'datetime'}, 'x': {'schema': 'int'}}})
+    assert v.warmup() is None
+    # calling warmup again is a no-op
+    v.warmup()
+    assert v.validate_python({'dt': '2022-06-08T12:13:14+01:00', 'x': '1'})['x'] == 1
+
+
+def test_warmup_deque():
+    v = SchemaValidator({'type': 'deque', 'items_schema': 'int'}, {'strict': True})
+    v.warmup()
+    assert v.validate_python(deque([1, 2])) == deque([1, 2])
+
+
+def test_dump_schema():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}, 'b': {'schema': {'type': 'list'}}}})
+    assert v.dump_schema() == {
+        'type': 'typed-dict',
+        'fields': {
+            'a': {'schema': {'type': 'int'}},
+            'b': {'schema': {'type': 'list', 'items_schema': {'type': 'any'}}},
+        },
+    }
+    assert json.loads(v.dump_schema('json')) == v.dump_schema()
+    assert SchemaValidator(v.dump_schema()).validate_python({'a': '1', 'b': (2,)}) == {'a': 1, 'b': [2]}
+
+
+def test_dump_schema_defaults():
+    assert SchemaValidator('int').dump_schema() == {'type': 'int'}
+    assert SchemaValidator({'type': 'tuple'}).dump_schema() == {
+        'type': 'tuple',
+        'mode': 'variable',
+        'items_schema': {'type': 'any'},
+    }
+    assert SchemaValidator({'type': 'dict', 'keys_schema': 'str'}).dump_schema() == {
+        'type': 'dict',
+        'keys_schema': {'type': 'str'},
+        'values_schema': {'type': 'any'},
+    }
+
+
+def test_dump_schema_refs():
+    schema = {
+        'type': 'typed-dict',
+        'ref': 'Branch',
+        'fields': {
+            'width': {'schema': {'type': 'int', 'ref': 'Width'}},
+            'height': {'schema': {'type': 'recursive-ref', 'schema_ref': 'Width'}},
+            'sub': {'schema': {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}}},
+        },
+    }
+    v = SchemaValidator(schema)
+    assert v.dump_schema()['fields']['height'] == {'schema': {'type': 'recursive-ref', 'schema_ref': 'Width'}}
+
+    inlined = v.dump_schema(inline_refs=True)
+    assert inlined == {
+        'type': 'typed-dict',
+        # kept since it's used recursively
+        'ref': 'Branch',
+        'fields': {
+            'width': {'schema': {'type': 'int'}},
+            'height': {'schema': {'type': 'int'}},
+            'sub': {'schema': {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}}},
+        },
+    }
+    input_value = {'width': 1, 'height': '2', 'sub': {'width': 3, 'height': 4, 'sub': None}}
+    assert SchemaValidator(inlined).validate_python(input_value) == {
+        'width': 1,
+        'height': 2,
+        'sub': {'width': 3, 'height': 4, 'sub': None},
+    }
+
+
+def test_dump_schema_json_callable():
+    v = SchemaValidator({'type': 'function', 'mode': 'plain', 'function': repr})
+    assert json.loads(v.dump_schema(format='json')) == {
+        'type': 'function',
+        'mode': 'plain',
+        'function': '<built-in function repr>',
+    }
+
+
+def test_dump_schema_invalid_format():
+    v = SchemaValidator('int')
+    with pytest.raises(ValueError, match='Invalid format "yaml", should be "python" or "json"'):
+        v.dump_schema('yaml')
+
+
+def test_resolved_schema():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int', 'alias': 'A'},
+                'b': {'schema': {'type': 'list'}, 'alias': [['B', 0], ['c', 'd']]},
+                'c': {'schema': 'str', 'default': 'x'},
+                'd': {'schema': {'type': 'with-default', 'schema': 'str', 'default_factory': str}},
+            },
+        },
+        {'typed_dict_extra_behavior': 'forbid', 'populate_by_name': True},
+    )
+    assert v.resolved_schema() == {
+        'type': 'typed-dict',
+        'strict': False,
+        'extra_behavior': 'forbid',
+        'total': True,
+        'from_attributes': False,
+        'populate_by_name': True,
+        'forbid_multiple_values': False,
+        'error_descriptions': False,
+        'fields': {
+            'a': {'schema': {'type': 'int'}, 'alias': 'A', 'required': True, 'lookup': [['A'], ['a']]},
+            'b': {
+                'schema': {'type': 'list', 'items_schema': {'type': 'any'}},
+                'alias': [['B', 0], ['c', 'd']],
+                'required': True,
+                'lookup': [['B', 0], ['c', 'd'], ['b']],
+            },
+            'c': {'schema': {'type': 'str'}, 'default': 'x', 'required': False, 'lookup': [['c']]},
+            'd': {
+                'schema': {'type': 'with-default', 'schema': {'type': 'str'}, 'default_factory': str},
+                'required': False,
+                'lookup': [['d']],
+            },
+        },
+    }
+
+
+def test_resolved_schema_model_config():
+    class MyModel:
+        pass
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'm': {
+                    'schema': {
+                        'type': 'new-class',
+                        'class_type': MyModel,
+                        'config': {'typed_dict_total': False, 'strict': True},
+                        'schema': {
+                            'type': 'typed-dict',
+                            'fields': {'x': {'schema': 'int', 'alias': ['y', {'regex': '(\\d+)'}]}},
+                        },
+                    }
+                },
+                'n': {'schema': {'type': 'typed-dict', 'fields': {'z': {'schema': 'int'}}}},
+            },
+        },
+        {'typed_dict_nested_delimiter': '__'},
+    )
+    resolved = v.resolved_schema()
+    assert resolved['nested_delimiter'] == '__'
+    assert resolved['total'] is True
+    # the model's config doesn't leak into the config of schemas built after it
+    assert resolved['fields']['n']['schema']['total'] is True
+    with pytest.raises(ValidationError, match=r'n -> z\n +Field required'):
+        v.validate_python({'m': {}, 'n': {}})
+    inner = resolved['fields']['m']['schema']['schema']
+    assert inner['total'] is False
+    assert inner['strict'] is True
+    # configs with the same priority are merged
+    assert inner['nested_delimiter'] == '__'
+    assert inner['fields']['x']['required'] is False
+    assert inner['fields']['x']['lookup'] == [['y', {'regex': '(\\d+)'}]]
+
+
+def test_from_json():
+    schema = {
+        'type': 'typed-dict',
+        'title': 'Thing',
+        'fields': {
+            'a': {'schema': {'type': 'list', 'items_schema': 'int'}},
+            'b': {'schema': {'type': 'with-default', 'schema': 'str', 'default': 'x'}, 'required': False},
+        },
+    }
+    v = SchemaValidator.from_json(json.dumps(schema).encode())
+    assert v == SchemaValidator(schema)
+    assert repr(v).startswith('SchemaValidator(name="Thing"')
+    assert v.validate_python({'a': ['1', 2]}) == {'a': [1, 2], 'b': 'x'}
+    assert SchemaValidator.from_json('"int"').validate_python('1') == 1
+    v = SchemaValidator.from_json(bytearray(b'{"type": "int"}'), {'title': 'Int'})
+    with pytest.raises(ValidationError, match=r'^1 validation error for Int\n'):
+        v.validate_python('x')
+
+
+def test_from_json_errors():
+    with pytest.raises(SchemaError, match='^Invalid schema JSON: EOF while parsing an object at line 1 column 14$'):
+        SchemaValidator.from_json(b'{"type": "int"')
+    with pytest.raises(SchemaError, match="Input tag 'foobar' found using self-schema does not match any of the"):
+        SchemaValidator.from_json(b'{"type": "foobar"}')
+    with pytest.raises(SchemaError, match=r'Invalid Schema:\nlist -> items_schema\n  Input tag'):
+        SchemaValidator.from_json(b'{"type": "list", "items_schema": {"type": "nope"}}')
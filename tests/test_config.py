@@ -242,3 +242,17 @@ def test_allow_inf_nan(config: Config, float_field_schema, input_value, expected
     else:
         output_dict = v.validate_python(input_value)
         assert output_dict == expected
+
+
+def test_title():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'}, {'title': 'Numbers'})
+    with pytest.raises(ValidationError, match=r'^2 validation errors for Numbers\n') as exc_info:
+        v.validate_python(['x', 'y'])
+    assert exc_info.value.title == 'Numbers'
+    assert v.validate_python_result(['x']).title == 'Numbers'
+    # the title only applies to the top level validator
+    assert repr(v).startswith('SchemaValidator(name="list[int]"')
+
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    with pytest.raises(ValidationError, match=r'^1 validation error for list\[int\]\n'):
+        v.validate_python(['x'])
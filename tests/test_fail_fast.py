@@ -0,0 +1,93 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+
+@pytest.fixture(scope='module')
+def validator():
+    return SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': {'type': 'list', 'items_schema': 'int'}},
+                'b': {'schema': {'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'}},
+                'c': {'schema': {'type': 'tuple', 'mode': 'positional', 'items_schema': ['int', 'int']}},
+            },
+        }
+    )
+
+
+def errors(exc_info):
+    return [(e['kind'], e['loc']) for e in exc_info.value.errors()]
+
+
+def test_collect_all(validator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_python({'a': ['x', 'y'], 'b': {'x': 'x', 'y': 'y'}})
+    assert errors(exc_info) == [
+        ('int_parsing', ['a', 0]),
+        ('int_parsing', ['a', 1]),
+        ('int_parsing', ['b', 'x']),
+        ('int_parsing', ['b', 'y']),
+        ('missing', ['c']),
+    ]
+
+
+def test_python(validator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_python({'a': ['x', 'y'], 'b': {'x': 'x', 'y': 'y'}}, fail_fast=True)
+    assert errors(exc_info) == [('int_parsing', ['a', 0])]
+
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_python({'a': [], 'b': {'x': 'x', 'y': 'y'}, 'c': ['x']}, fail_fast=True)
+    assert errors(exc_info) == [('int_parsing', ['b', 'x'])]
+
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_python({'a': [], 'b': {}, 'c': ['x']}, fail_fast=True)
+    assert errors(exc_info) == [('int_parsing', ['c', 0])]
+
+    assert validator.validate_python({'a': ['1'], 'b': {}, 'c': [1, '2']}, fail_fast=True) == {
+        'a': [1],
+        'b': {},
+        'c': (1, 2),
+    }
+
+
+def test_json(validator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_json('{"a": ["x", "y"], "b": {"x": "x"}}', fail_fast=True)
+    assert errors(exc_info) == [('int_parsing', ['a', 0])]
+
+
+def test_stops_validating():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        return input_value
+
+    v = SchemaValidator(
+        {'type': 'list', 'items_schema': {'type': 'function', 'mode': 'after', 'function': f, 'schema': 'int'}}
+    )
+    with pytest.raises(ValidationError):
+        v.validate_python([1, 'x', 3], fail_fast=True)
+    assert calls == [1]
+
+    calls.clear()
+    assert v.isinstance_python([1, 'x', 3]) is False
+    assert calls == [1]
+
+    calls.clear()
+    assert v.isinstance_json('[1, "x", 3]') is False
+    assert calls == [1]
+
+
+def test_extra_forbidden():
+    v = SchemaValidator({'type': 'typed-dict', 'extra_behavior': 'forbid', 'fields': {'a': {'schema': 'int'}}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 2, 'c': 3}, fail_fast=True)
+    assert errors(exc_info) == [('extra_forbidden', ['b'])]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 2}, fail_fast=True)
+    assert errors(exc_info) == [('int_parsing', ['a'])]
@@ -0,0 +1,60 @@
+from pydantic_core import SchemaValidator
+
+
+def test_field_metadata():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'name': {'schema': 'str', 'description': 'The name', 'examples': ['Alice', 'Bob']},
+                'age': {'schema': 'int', 'required': False, 'default': 0},
+            },
+        }
+    )
+    assert v.field_metadata() == {
+        ('name',): {'description': 'The name', 'examples': ['Alice', 'Bob']},
+        ('age',): {'required': False, 'default': 0},
+    }
+    # examples don't affect validation
+    assert v.validate_python({'name': 'Carol'}) == {'name': 'Carol', 'age': 0}
+
+
+def test_nested():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'fields': {
+                    'owner': {
+                        'schema': {
+                            'type': 'typed-dict',
+                            'fields': {'email': {'schema': 'str', 'examples': ['a@example.com']}},
+                        },
+                        'description': 'The owner',
+                    },
+                    'pets': {
+                        'schema': {
+                            'type': 'list',
+                            'items_schema': {'type': 'typed-dict', 'fields': {'species': {'schema': 'str'}}},
+                        }
+                    },
+                },
+            },
+        }
+    )
+    assert v.field_metadata() == {
+        ('owner',): {'description': 'The owner'},
+        ('owner', 'email'): {'examples': ['a@example.com']},
+        ('pets',): {},
+        ('pets', 'species'): {},
+    }
+
+
+def test_no_fields():
+    assert SchemaValidator('int').field_metadata() == {}
+    assert SchemaValidator({'type': 'list', 'items_schema': 'int'}).field_metadata() == {}
@@ -0,0 +1,176 @@
+import re
+from datetime import date, datetime, timezone
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaValidator
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        'int',
+        {'type': 'int', 'ge': 5, 'lt': 10, 'multiple_of': 2},
+        {'type': 'float', 'gt': 0, 'le': 1},
+        {'type': 'str', 'min_length': 3, 'max_length': 5, 'to_upper': True},
+        {'type': 'bytes', 'max_length': 3},
+        {'type': 'list', 'items_schema': 'int', 'min_items': 2, 'max_items': 3},
+        {'type': 'set', 'items_schema': {'type': 'int', 'ge': 0, 'le': 1000}},
+        {'type': 'frozenset', 'items_schema': 'str'},
+        {'type': 'tuple', 'mode': 'positional', 'items_schema': ['int', 'str'], 'extra_schema': 'bool'},
+        {'type': 'dict', 'keys_schema': 'str', 'values_schema': {'type': 'nullable', 'schema': 'int'}},
+        {'type': 'union', 'choices': ['int', 'str']},
+        {'type': 'literal', 'expected': ['a', 'b', 1]},
+        {'type': 'date', 'ge': date(2020, 1, 1)},
+        {'type': 'datetime', 'lt': datetime(2020, 1, 1, tzinfo=timezone.utc)},
+        {'type': 'decimal'},
+        'uuid',
+        'any',
+    ],
+    ids=repr,
+)
+def test_valid_examples(schema):
+    v = SchemaValidator(schema)
+    for seed in range(20):
+        example = v.generate_example(seed=seed)
+        assert v.isinstance_python(example)
+
+
+def test_constraints():
+    v = SchemaValidator({'type': 'int', 'ge': 5, 'lt': 10, 'multiple_of': 2})
+    assert {v.generate_example(seed=seed) for seed in range(50)} == {6, 8}
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'min_items': 2, 'max_items': 3})
+    assert all(2 <= len(v.generate_example(seed=seed)) <= 3 for seed in range(20))
+
+
+def test_seed():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'union', 'choices': ['int', 'str', 'float']}})
+    assert v.generate_example(seed=123) == v.generate_example(seed=123)
+    assert len({repr(v.generate_example(seed=seed)) for seed in range(10)}) > 1
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': 'str', 'default': 'default'},
+                'c': {'schema': {'type': 'float', 'ge': 0}, 'alias': ['foo', 'bar']},
+                'd': {'schema': 'bool', 'alias': 'D'},
+            },
+        }
+    )
+    examples = [v.generate_example(seed=seed) for seed in range(20)]
+    for example in examples:
+        assert example.keys() == {'a', 'b', 'c', 'd'}
+        assert example['c'] >= 0
+    # optional fields are sometimes omitted so the default is used
+    assert any(example['b'] == 'default' for example in examples)
+    assert any(example['b'] != 'default' for example in examples)
+
+
+def test_tagged_union():
+    v = SchemaValidator(
+        {
+            'type': 'tagged-union',
+            'discriminator': 'kind',
+            'choices': {
+                'cat': {
+                    'type': 'typed-dict',
+                    'fields': {
+                        'kind': {'schema': {'type': 'literal', 'expected': ['cat']}},
+                        'lives': {'schema': 'int'},
+                    },
+                },
+                'dog': {
+                    'type': 'typed-dict',
+                    'fields': {
+                        'kind': {'schema': {'type': 'literal', 'expected': ['dog']}},
+                        'good': {'schema': 'bool'},
+                    },
+                },
+            },
+        }
+    )
+    kinds = {v.generate_example(seed=seed)['kind'] for seed in range(20)}
+    assert kinds == {'cat', 'dog'}
+
+
+def test_recursive():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Branch',
+            'fields': {
+                'name': {'schema': 'str'},
+                'sub_branches': {
+                    'schema': {'type': 'list', 'items_schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}}
+                },
+            },
+        }
+    )
+    example = v.generate_example(seed=1)
+    assert isinstance(example['name'], str)
+    assert isinstance(example['sub_branches'], list)
+
+
+def test_new_class():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': MyModel,
+            'schema': {'type': 'typed-dict', 'return_fields_set': True, 'fields': {'x': {'schema': 'int'}}},
+        }
+    )
+    example = v.generate_example(seed=1)
+    assert isinstance(example, MyModel)
+    assert isinstance(example.x, int)
+
+
+def test_function_after():
+    v = SchemaValidator({'type': 'function', 'mode': 'after', 'function': lambda v, **kwargs: -v, 'schema': 'int'})
+    assert v.generate_example(seed=1) == -SchemaValidator('int').generate_example(seed=1)
+
+
+def test_json():
+    v = SchemaValidator({'type': 'json', 'schema': {'type': 'list', 'items_schema': 'int'}})
+    example = v.generate_example(seed=1)
+    assert isinstance(example, list)
+    assert all(isinstance(item, int) for item in example)
+
+
+def test_decimal():
+    v = SchemaValidator({'type': 'decimal'})
+    assert isinstance(v.generate_example(seed=1), Decimal)
+
+
+@pytest.mark.parametrize(
+    'schema,error',
+    [
+        ({'type': 'function', 'mode': 'plain', 'function': lambda v, **kwargs: v}, 'plain function schemas'),
+        ({'type': 'str', 'pattern': 'a+'}, 'strings with a pattern'),
+        ({'type': 'int', 'gt': 5, 'lt': 6}, "int constraints can't be satisfied"),
+        (
+            {
+                'type': 'typed-dict',
+                'ref': 'A',
+                'fields': {'a': {'schema': {'type': 'recursive-ref', 'schema_ref': 'A'}}},
+            },
+            'the schema is infinitely recursive',
+        ),
+        (
+            {'type': 'function', 'mode': 'before', 'function': lambda v, **kwargs: 'x', 'schema': 'int'},
+            'Unable to generate a valid example in 100 attempts',
+        ),
+        ({'type': 'is-instance', 'class_': int}, 'Unable to generate examples for "is-instance" schemas'),
+    ],
+)
+def test_errors(schema, error):
+    v = SchemaValidator(schema)
+    with pytest.raises(ValueError, match=re.escape(error)):
+        v.generate_example()
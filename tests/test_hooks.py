@@ -0,0 +1,108 @@
+import asyncio
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+
+def test_config_hooks():
+    calls = []
+
+    def on_start(**kwargs):
+        calls.append(('start', kwargs))
+
+    def on_end(**kwargs):
+        calls.append(('end', kwargs))
+
+    v = SchemaValidator(
+        {'type': 'list', 'items_schema': 'int'},
+        {'title': 'Numbers', 'on_validate_start': on_start, 'on_validate_end': on_end},
+    )
+    assert v.on_validate_start is on_start
+    assert v.on_validate_end is on_end
+    assert v.validate_python(['1', 2]) == [1, 2]
+    assert [c[0] for c in calls] == ['start', 'end']
+    assert calls[0][1] == {'title': 'Numbers', 'mode': 'python'}
+    end = calls[1][1]
+    assert end.pop('duration') >= 0
+    assert end == {'title': 'Numbers', 'mode': 'python', 'success': True, 'error': None}
+
+    calls.clear()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('["x"]')
+    assert [c[0] for c in calls] == ['start', 'end']
+    assert calls[0][1] == {'title': 'Numbers', 'mode': 'json'}
+    assert calls[1][1]['success'] is False
+    assert calls[1][1]['error'] is exc_info.value
+
+
+def test_set_on_validator():
+    durations = []
+
+    def on_end(mode, duration, success, **kwargs):
+        durations.append((mode, success))
+
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}}})
+    assert v.on_validate_start is None
+    assert v.on_validate_end is None
+    v.validate_python({'a': 1})
+    v.on_validate_end = on_end
+    v.validate_python({'a': 1})
+    v.validate_strings({'a': '1'})
+    assert v.validate_assignment({'a': 1}, 'a', '2') == {'a': 2}
+    with pytest.raises(ValidationError):
+        v.validate_json('{"a": "x"}')
+    assert durations == [('python', True), ('strings', True), ('assignment', True), ('json', False)]
+
+    v.on_validate_end = None
+    v.validate_python({'a': 1})
+    assert len(durations) == 4
+
+
+def test_hook_error():
+    def on_start(**kwargs):
+        raise RuntimeError('hook failed')
+
+    v = SchemaValidator('int', {'on_validate_start': on_start})
+    with pytest.raises(RuntimeError, match='^hook failed$'):
+        v.validate_python(1)
+
+
+def test_hooks_other_methods():
+    calls = []
+
+    def on_end(mode, success, error, **kwargs):
+        calls.append((mode, success, error))
+
+    v = SchemaValidator('int', {'on_validate_end': on_end})
+    assert v.validate_python_result('x').ok is False
+    assert v.isinstance_python(1) is True
+    assert v.isinstance_json('"x"') is False
+    assert v.validate_python_with_coercions('1') == (1, [])
+    assert [r.ok for r in v.validate_many([1, 'x'])] == [True, False]
+    assert [r.ok for r in v.validate_many(['1', 'x'], json=True)] == [True, False]
+
+    async def main():
+        return await v.validate_python_async(1)
+
+    assert asyncio.run(main()) == 1
+    # invalid input is reported by `success`, `error` is only set when an exception is raised
+    assert calls == [
+        ('python', False, None),
+        ('python', True, None),
+        ('json', False, None),
+        ('python', True, None),
+        ('python', True, None),
+        ('python', False, None),
+        ('json', True, None),
+        ('json', False, None),
+        ('python', True, None),
+    ]
+
+
+def test_hooks_not_called_when_salvaging():
+    calls = []
+    config = {'on_validate_start': lambda **kwargs: calls.append(1)}
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'}, config)
+    assert v.validate_python_salvage([1, 'x'])[0] == [1, None]
+    assert calls == []
@@ -1,4 +1,8 @@
+import io
+import json
 import re
+from array import array
+from concurrent.futures import ThreadPoolExecutor
 
 import pytest
 
@@ -16,15 +20,38 @@ def test_bool(input_value, output_value):
     assert v.validate_json(input_value) == output_value
 
 
-@pytest.mark.parametrize('input_value', ['[1, 2, 3]', b'[1, 2, 3]', bytearray(b'[1, 2, 3]')])
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        '[1, 2, 3]',
+        b'[1, 2, 3]',
+        bytearray(b'[1, 2, 3]'),
+        memoryview(b'[1, 2, 3]'),
+        memoryview(b'xx[1, 2, 3]xx')[2:-2],
+        array('B', b'[1, 2, 3]'),
+    ],
+)
 def test_input_types(input_value):
     v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
     assert v.validate_json(input_value) == [1, 2, 3]
+    assert v.isinstance_json(input_value) is True
+
+
+def test_input_buffer_not_contiguous():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
+    with pytest.raises(TypeError, match='^JSON input should be str, bytes, bytearray or memoryview, not memoryview$'):
+        v.validate_json(memoryview(b'[1, 2, 3]')[::2])
+
+
+def test_input_buffer_invalid_json():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
+    with pytest.raises(ValidationError, match=r'Invalid JSON: EOF while parsing a list'):
+        v.validate_json(memoryview(b'[1, 2'))
 
 
 def test_input_type_invalid():
     v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
-    with pytest.raises(TypeError, match='^JSON input should be str, bytes or bytearray, not list$'):
+    with pytest.raises(TypeError, match='^JSON input should be str, bytes, bytearray or memoryview, not list$'):
         v.validate_json([])
 
 
@@ -169,3 +196,149 @@ def test_invalid_json():
             'context': {'error': 'trailing comma at line 3 column 3'},
         }
     ]
+
+
+@pytest.mark.parametrize('input_value', [io.BytesIO(b'[1, 2, 3]'), io.StringIO('[1, 2, 3]')])
+def test_stream_file_like(input_value):
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    assert v.validate_json_stream(input_value) == [1, 2, 3]
+
+
+def test_stream_chunks():
+    class File:
+        def __init__(self, data: bytes):
+            self.data = data
+            self.reads = 0
+
+        def read(self, size: int) -> bytes:
+            self.reads += 1
+            chunk, self.data = self.data[:size], self.data[size:]
+            return chunk
+
+    data = [{'id': i, 'name': f'item {i}'} for i in range(20_000)]
+    f = File(json.dumps(data).encode())
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'dict', 'keys_schema': 'str'}})
+    assert v.validate_json_stream(f) == data
+    assert f.reads > 2
+
+
+def test_stream_path(tmp_path):
+    path = tmp_path / 'data.json'
+    path.write_text('{"a": "1", "b": "2"}')
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'})
+    assert v.validate_json_stream(path) == {'a': 1, 'b': 2}
+    assert v.validate_json_stream(str(path)) == {'a': 1, 'b': 2}
+    with pytest.raises(FileNotFoundError):
+        v.validate_json_stream(tmp_path / 'missing.json')
+
+
+def test_stream_errors():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_stream(io.BytesIO(b'[1, "x"]'))
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', [1])]
+    with pytest.raises(ValidationError, match=r'Invalid JSON: EOF while parsing a list at line 1 column 5'):
+        v.validate_json_stream(io.BytesIO(b'[1, 2'))
+    with pytest.raises(TypeError, match='^JSON stream input should be a path or a file-like object, not int$'):
+        v.validate_json_stream(123)
+
+
+def test_stream_read_error():
+    class File:
+        def read(self, size: int):
+            raise RuntimeError('read failed')
+
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    with pytest.raises(RuntimeError, match='read failed'):
+        v.validate_json_stream(File())
+
+
+def test_stream_read_wrong_type():
+    class File:
+        def read(self, size: int):
+            return [1, 2, 3]
+
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    with pytest.raises(TypeError, match=r'^read\(\) should return str or bytes, not list$'):
+        v.validate_json_stream(File())
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        '{"a": 1}\n\n{"a": "2"}\r\n  \n{"a": 3}',
+        b'{"a": 1}\n\n{"a": "2"}\r\n  \n{"a": 3}\n',
+        io.BytesIO(b'{"a": 1}\n\n{"a": "2"}\r\n  \n{"a": 3}\n'),
+        io.StringIO('{"a": 1}\n\n{"a": "2"}\r\n  \n{"a": 3}'),
+    ],
+)
+def test_json_lines(input_value):
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'})
+    lines = v.iter_json_lines(input_value)
+    assert repr(lines) == 'JsonLinesIterator(line_number=0, schema=dict[str,int])'
+    assert next(lines) == {'a': 1}
+    assert lines.line_number == 1
+    assert list(lines) == [{'a': 2}, {'a': 3}]
+    assert lines.line_number == 5
+
+
+def test_json_lines_errors():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'})
+    lines = v.iter_json_lines('{"a": 1}\n{"a": "x"}\n{"a": \n{"a": 4}\n')
+    assert next(lines) == {'a': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        next(lines)
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', [2, 'a'])]
+    with pytest.raises(ValidationError) as exc_info:
+        next(lines)
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'invalid_json',
+            'loc': [3],
+            'message': 'Invalid JSON: EOF while parsing a value at line 1 column 6',
+            'input_value': '{"a":',
+            'context': {'error': 'EOF while parsing a value at line 1 column 6'},
+        }
+    ]
+    # iteration continues after an invalid line
+    assert list(lines) == [{'a': 4}]
+
+
+def test_json_lines_strict():
+    v = SchemaValidator('int')
+    assert list(v.iter_json_lines('1\n"2"')) == [1, 2]
+    with pytest.raises(ValidationError, match=r'2\n +Input should be a valid integer \[kind=int_type'):
+        list(v.iter_json_lines('1\n"2"', strict=True))
+
+
+def test_json_lines_input_type():
+    v = SchemaValidator('int')
+    with pytest.raises(TypeError, match='^JSON lines input should be str, bytes or a file, not int$'):
+        v.iter_json_lines(123)
+
+    class File:
+        def readline(self):
+            return [1]
+
+    with pytest.raises(TypeError, match=r'^readline\(\) should return str or bytes, not list$'):
+        next(v.iter_json_lines(File()))
+
+
+def test_large_input():
+    # inputs this long are parsed with the GIL released
+    data = {f'key_{i}': i for i in range(5_000)}
+    json_str = json.dumps(data)
+    assert len(json_str) > 16 * 1024
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'})
+    assert v.validate_json(json_str) == data
+    assert v.validate_json(json_str.encode()) == data
+    with pytest.raises(ValidationError, match=r'Invalid JSON: EOF while parsing an object at line 1 column \d+'):
+        v.validate_json(json_str[:-1])
+
+
+def test_large_input_threads():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    inputs = [json.dumps(list(range(i, i + 5_000))).encode() for i in range(8)]
+    with ThreadPoolExecutor(max_workers=4) as executor:
+        results = list(executor.map(v.validate_json, inputs))
+    assert results == [list(range(i, i + 5_000)) for i in range(8)]
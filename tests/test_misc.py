@@ -81,6 +81,137 @@ def test_validation_error_multiple():
     )
 
 
+def test_structured_errors():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': {'type': 'list', 'items_schema': {'type': 'int', 'gt': 0}, 'min_items': 1}},
+                'b': {'schema': 'str'},
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': [1, -1, 'x']})
+    assert exc_info.value.structured_errors() == [
+        {
+            'type': 'greater_than',
+            'loc': ('a', 1),
+            'msg': 'Input should be greater than 0',
+            'input': -1,
+            'ctx': {'gt': 0},
+        },
+        {
+            'type': 'int_parsing',
+            'loc': ('a', 2),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {},
+        },
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'a': [1, -1, 'x']}, 'ctx': {}},
+    ]
+    # `errors()` is unchanged
+    assert exc_info.value.errors()[0] == {
+        'kind': 'greater_than',
+        'loc': ['a', 1],
+        'message': 'Input should be greater than 0',
+        'input_value': -1,
+        'context': {'gt': 0},
+    }
+
+    result = v.validate_python_result({'a': []})
+    assert result.structured_errors() == [
+        {
+            'type': 'too_short',
+            'loc': ('a',),
+            'msg': 'Input should have at least 1 item, got 0 items',
+            'input': [],
+            'ctx': {'min_length': 1, 'input_length': 0},
+        },
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'a': []}, 'ctx': {}},
+    ]
+    assert SchemaValidator('int').validate_python_result('x').structured_errors()[0]['loc'] == ()
+
+
+def test_max_errors():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'}, {'max_errors': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a', 'b', 'c', 'd'])
+    assert exc_info.value.error_count() == 2
+    assert exc_info.value.omitted_error_count() == 2
+    assert [e['loc'] for e in exc_info.value.errors()] == [[0], [1]]
+    assert str(exc_info.value).startswith('4 validation errors for list[int]\n0\n')
+    assert str(exc_info.value).endswith('\n[2 more errors omitted]')
+
+    # the per-call limit takes precedence
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('["a", "b", "c", "d"]', max_errors=3)
+    assert exc_info.value.error_count() == 3
+    assert str(exc_info.value).endswith('\n[1 more error omitted]')
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a', 'b'])
+    assert exc_info.value.error_count() == 2
+    assert exc_info.value.omitted_error_count() == 0
+    assert 'omitted' not in str(exc_info.value)
+
+
+def test_max_errors_per_call():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a'] * 1000, max_errors=10)
+    assert exc_info.value.error_count() == 10
+    assert exc_info.value.omitted_error_count() == 990
+
+
+def test_max_errors_nested():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'list', 'items_schema': 'int'}}, {'max_errors': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([['a'] * 5, ['b'] * 5])
+    assert exc_info.value.error_count() == 3
+    assert exc_info.value.omitted_error_count() == 7
+    assert [e['loc'] for e in exc_info.value.errors()] == [[0, 0], [0, 1], [0, 2]]
+
+
+def test_max_errors_result():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'}, {'max_errors': 2})
+    result = v.validate_python_result(['a'] * 5)
+    assert result.error_count() == 2
+    assert result.omitted_error_count() == 3
+    assert v.validate_python_result([1]).omitted_error_count() == 0
+
+    # the limit applies to each item
+    results = v.validate_many([['a'] * 4, [1], ['a']])
+    assert [(r.error_count(), r.omitted_error_count()) for r in results] == [(2, 2), (0, 0), (1, 0)]
+    results = v.validate_many(['["a", "b", "c"]', b'["a", "b", "c"]'], json=True)
+    assert [(r.error_count(), r.omitted_error_count()) for r in results] == [(2, 1), (2, 1)]
+
+
+def test_max_errors_discarded():
+    # errors omitted from a union choice or a field with `on_error` which are discarded aren't counted
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {
+                    'schema': {
+                        'type': 'union',
+                        'choices': [{'type': 'list', 'items_schema': 'int'}, {'type': 'list', 'items_schema': 'str'}],
+                    }
+                },
+                'b': {'schema': {'type': 'list', 'items_schema': 'int'}, 'on_error': 'omit', 'required': False},
+                'c': {'schema': 'int'},
+            },
+        },
+        {'max_errors': 1},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': ['x'] * 5, 'b': ['x'] * 5, 'c': 'x'})
+    assert exc_info.value.error_count() == 1
+    assert exc_info.value.omitted_error_count() == 0
+    assert exc_info.value.errors()[0]['loc'] == ['c']
+
+
 @pytest.mark.skipif(sys.platform == 'emscripten', reason='README.md is not mounted in wasm file system')
 def test_readme(import_execute):
     this_dir = Path(__file__).parent
@@ -0,0 +1,118 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, partial_schema
+
+
+def test_partial_fields():
+    schema = {
+        'type': 'typed-dict',
+        'fields': {
+            'name': {'schema': 'str'},
+            'age': {'schema': {'type': 'int', 'ge': 0}, 'default': 18},
+            'tags': {'schema': {'type': 'list', 'items_schema': 'str'}, 'default_factory': list},
+            'note': {'schema': {'type': 'nullable', 'schema': 'str'}},
+        },
+    }
+    assert partial_schema(schema) == {
+        'type': 'typed-dict',
+        'fields': {
+            'name': {'schema': {'type': 'nullable', 'schema': 'str'}, 'required': False, 'default': None},
+            'age': {
+                'schema': {'type': 'nullable', 'schema': {'type': 'int', 'ge': 0}},
+                'required': False,
+                'default': None,
+            },
+            'tags': {
+                'schema': {'type': 'nullable', 'schema': {'type': 'list', 'items_schema': 'str'}},
+                'required': False,
+                'default': None,
+            },
+            'note': {'schema': {'type': 'nullable', 'schema': 'str'}, 'required': False, 'default': None},
+        },
+    }
+    # the input schema is not modified
+    assert schema['fields']['name'] == {'schema': 'str'}
+
+    v = SchemaValidator(partial_schema(schema))
+    assert v.validate_python({}) == {'name': None, 'age': None, 'tags': None, 'note': None}
+    assert v.validate_python({'age': '42', 'note': None}) == {'name': None, 'age': 42, 'tags': None, 'note': None}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'age': -1})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'greater_than_equal',
+            'loc': ['age'],
+            'message': 'Input should be greater than or equal to 0',
+            'input_value': -1,
+            'context': {'ge': 0},
+        }
+    ]
+
+
+def test_partial_new_class():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    schema = {
+        'type': 'new-class',
+        'class_type': MyModel,
+        'schema': {
+            'type': 'typed-dict',
+            'return_fields_set': True,
+            'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'str'}},
+        },
+    }
+    v = SchemaValidator(partial_schema(schema))
+    m = v.validate_python({'b': 'hello'})
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'a': None, 'b': 'hello'}
+    assert m.__fields_set__ == {'b'}
+
+
+def test_partial_shared_definitions():
+    schema = {
+        'type': 'typed-dict',
+        'fields': {
+            'root': {
+                'schema': {
+                    'type': 'typed-dict',
+                    'ref': 'Branch',
+                    'fields': {
+                        'width': {'schema': 'int'},
+                        'sub_branch': {
+                            'schema': {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}},
+                            'default': None,
+                        },
+                    },
+                }
+            },
+            'other': {'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}},
+        },
+    }
+    partial = partial_schema(schema)
+    # nested models, and the definitions they hold, are unchanged
+    assert partial['fields']['root']['schema'] == {'type': 'nullable', 'schema': schema['fields']['root']['schema']}
+
+    v = SchemaValidator(partial)
+    assert v.validate_python({'other': {'width': 1, 'sub_branch': {'width': 2}}}) == {
+        'root': None,
+        'other': {'width': 1, 'sub_branch': {'width': 2, 'sub_branch': None}},
+    }
+    with pytest.raises(ValidationError, match=r'other -> width\n  Field required'):
+        v.validate_python({'other': {}})
+
+
+@pytest.mark.parametrize(
+    'schema,message',
+    [
+        ('int', 'partial_schema requires a typed-dict schema, got "int"'),
+        ({'type': 'list'}, 'partial_schema requires a typed-dict schema, got "list"'),
+        (
+            {'type': 'function', 'mode': 'plain', 'function': int},
+            'partial_schema requires a typed-dict schema, got "function"',
+        ),
+    ],
+)
+def test_partial_invalid(schema, message):
+    with pytest.raises(ValueError, match=message):
+        partial_schema(schema)
@@ -0,0 +1,155 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+
+def error_locs(errors):
+    return [(e['loc'], e['kind']) for e in errors]
+
+
+def test_valid():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    assert v.validate_python_salvage(['1', 2]) == ([1, 2], [])
+
+
+def test_list():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    value, errors = v.validate_python_salvage([1, 'x', '3', 'y'])
+    assert value == [1, None, 3, None]
+    assert errors == [
+        {
+            'kind': 'int_parsing',
+            'loc': [1],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+        {
+            'kind': 'int_parsing',
+            'loc': [3],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'y',
+        },
+    ]
+    # normal validation is unchanged
+    with pytest.raises(ValidationError):
+        v.validate_python([1, 'x'])
+
+
+def test_nothing_salvaged():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    value, errors = v.validate_python_salvage('foobar')
+    assert value is None
+    assert error_locs(errors) == [([], 'list_type')]
+
+
+def test_dict():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int'})
+    value, errors = v.validate_python_salvage({'1': '2', 'x': 3, 4: 'y'})
+    # entries with invalid keys are dropped, invalid values are replaced by None
+    assert value == {1: 2, 4: None}
+    assert error_locs(errors) == [(['x', '[key]'], 'int_parsing'), ([4], 'int_parsing')]
+
+
+def test_tuple_positional():
+    v = SchemaValidator({'type': 'tuple', 'mode': 'positional', 'items_schema': ['int', 'str', 'int']})
+    value, errors = v.validate_python_salvage(('x', 'a'))
+    assert value == (None, 'a', None)
+    assert error_locs(errors) == [([0], 'int_parsing'), ([2], 'missing')]
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': 'int', 'default': 42},
+                'c': {'schema': 'str'},
+                'd': {'schema': 'int'},
+            },
+        }
+    )
+    value, errors = v.validate_python_salvage({'a': 'x', 'b': 'y', 'c': 'hello'})
+    assert value == {'a': None, 'b': 42, 'c': 'hello', 'd': None}
+    assert error_locs(errors) == [(['a'], 'int_parsing'), (['b'], 'int_parsing'), (['d'], 'missing')]
+
+
+def test_typed_dict_on_error():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': {'type': 'list', 'items_schema': 'int'}, 'on_error': 'omit', 'required': False},
+                'b': {'schema': {'type': 'list', 'items_schema': 'int'}},
+            },
+        }
+    )
+    value, errors = v.validate_python_salvage({'a': [1, 'x'], 'b': [2, 'y']})
+    # errors for fields with `on_error` are handled by the field as usual
+    assert value == {'b': [2, None]}
+    assert error_locs(errors) == [(['b', 1], 'int_parsing')]
+
+
+def test_nested():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'name': {'schema': 'str'},
+                'friends': {
+                    'schema': {
+                        'type': 'list',
+                        'items_schema': {
+                            'type': 'typed-dict',
+                            'fields': {
+                                'id': {'schema': 'int'},
+                                'tags': {'schema': {'type': 'list', 'items_schema': 'str'}},
+                            },
+                        },
+                    }
+                },
+            },
+        }
+    )
+    value, errors = v.validate_python_salvage(
+        {'name': 'Anna', 'friends': [{'id': 1, 'tags': ['a']}, {'id': 'x', 'tags': ['b', 2]}, 'not-a-dict']}
+    )
+    assert value == {
+        'name': 'Anna',
+        'friends': [{'id': 1, 'tags': ['a']}, {'id': None, 'tags': ['b', None]}, None],
+    }
+    assert error_locs(errors) == [
+        # errors from nested containers come first since they're recorded as soon as the container is validated
+        (['friends', 1, 'tags', 1], 'str_type'),
+        (['friends', 1, 'id'], 'int_parsing'),
+        (['friends', 2], 'dict_type'),
+    ]
+
+
+def test_union_not_salvaged():
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': {
+                'type': 'union',
+                'choices': [{'type': 'list', 'items_schema': 'int'}, {'type': 'list', 'items_schema': 'str'}],
+            },
+        }
+    )
+    value, errors = v.validate_python_salvage([['a', 'b'], [1, 2], [1, []]])
+    # a partly valid choice isn't picked, the union as a whole is invalid
+    assert value == [['a', 'b'], [1, 2], None]
+    assert error_locs(errors) == [
+        ([2, 'list[int]', 1], 'int_type'),
+        ([2, 'list[str]', 0], 'str_type'),
+        ([2, 'list[str]', 1], 'str_type'),
+    ]
+
+
+def test_dict_of_lists():
+    v = SchemaValidator(
+        {'type': 'dict', 'keys_schema': 'str', 'values_schema': {'type': 'list', 'items_schema': 'int'}}
+    )
+    value, errors = v.validate_python_salvage({'a': [1, 'x'], 'b': [2]})
+    assert value == {'a': [1, None], 'b': [2]}
+    assert error_locs(errors) == [(['a', 1], 'int_parsing')]
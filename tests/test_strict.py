@@ -2,7 +2,7 @@ import re
 
 import pytest
 
-from pydantic_core import ValidationError
+from pydantic_core import SchemaValidator, ValidationError
 
 from .conftest import Err, PyAndJson
 
@@ -27,3 +27,40 @@ def test_int_strict_argument(py_and_json: PyAndJson, strict, input_value, expect
     else:
         assert v.isinstance_test(input_value, strict) is True
         assert v.validate_test(input_value, strict) == expected
+
+
+def test_strict_argument_nested():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'a': {'schema': {'type': 'list', 'items_schema': {'type': 'dict', 'values_schema': 'int'}}}},
+        }
+    )
+    assert v.validate_python({'a': [{'x': '1'}]}) == {'a': [{'x': 1}]}
+    assert v.validate_python({'a': [{'x': 1}]}, strict=True) == {'a': [{'x': 1}]}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': [{'x': '1'}]}, strict=True)
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('int_type', ['a', 0, 'x'])]
+
+
+def test_strict_argument_overrides_config():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'}, {'strict': True})
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(['1'])
+    assert v.validate_python(['1'], strict=False) == [1]
+    assert v.validate_json('["1"]', strict=False) == [1]
+
+
+def test_strict_argument_tagged_union():
+    v = SchemaValidator(
+        {
+            'type': 'tagged-union',
+            'discriminator': 'foo',
+            'choices': {
+                'apple': {'type': 'typed-dict', 'fields': {'foo': {'schema': 'any'}, 'bar': {'schema': 'int'}}},
+            },
+        }
+    )
+    assert v.validate_python({'foo': b'apple', 'bar': '1'}) == {'foo': b'apple', 'bar': 1}
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python({'foo': b'apple', 'bar': 1}, strict=True)
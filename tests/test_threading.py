@@ -0,0 +1,84 @@
+import threading
+import time
+from concurrent.futures import ThreadPoolExecutor
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+
+def slow_double(input_value, **kwargs):
+    # sleeping releases the GIL so other threads validate in the meantime
+    time.sleep(0.001)
+    return input_value * 2
+
+
+@pytest.fixture(scope='module')
+def validator():
+    return SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'id': {'schema': 'int'},
+                'values': {
+                    'schema': {
+                        'type': 'list',
+                        'items_schema': {'type': 'function', 'mode': 'after', 'function': slow_double, 'schema': 'int'},
+                    }
+                },
+                'name': {'schema': {'type': 'str', 'to_upper': True}, 'default': 'x'},
+            },
+        }
+    )
+
+
+def validate(validator, i):
+    if i % 3 == 0:
+        try:
+            validator.validate_python({'id': i, 'values': ['x']})
+        except ValidationError as e:
+            return e.errors()[0]['loc']
+    elif i % 3 == 1:
+        return validator.validate_json(f'{{"id": {i}, "values": [{i}, 1], "name": "n{i}"}}')
+    else:
+        return validator.validate_python({'id': str(i), 'values': [i]})
+
+
+def test_shared_between_threads(validator):
+    with ThreadPoolExecutor(max_workers=8) as executor:
+        results = list(executor.map(lambda i: validate(validator, i), range(60)))
+
+    for i, result in enumerate(results):
+        if i % 3 == 0:
+            assert result == ['values', 0]
+        elif i % 3 == 1:
+            assert result == {'id': i, 'values': [i * 2, 2], 'name': f'N{i}'}
+        else:
+            assert result == {'id': i, 'values': [i * 2], 'name': 'x'}
+
+
+def test_replace_field_while_validating():
+    started = threading.Event()
+    finish = threading.Event()
+
+    def f(input_value, **kwargs):
+        started.set()
+        finish.wait(5)
+        return input_value
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'a': {'schema': {'type': 'function', 'mode': 'after', 'function': f, 'schema': 'int'}}},
+        }
+    )
+    with ThreadPoolExecutor(max_workers=1) as executor:
+        future = executor.submit(v.validate_python, {'a': 1})
+        assert started.wait(5)
+        # a validator can't be changed while another thread is using it
+        with pytest.raises(RuntimeError, match='Already borrowed'):
+            v.replace_field('a', 'str')
+        finish.set()
+        assert future.result() == {'a': 1}
+    v.replace_field('a', 'str')
+    assert v.validate_python({'a': 'x'}) == {'a': 'x'}
@@ -0,0 +1,86 @@
+from types import MappingProxyType
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': 'float'},
+                'c': {'schema': 'bool'},
+                'd': {'schema': {'type': 'list', 'items_schema': 'int'}},
+                'e': {'schema': 'str'},
+            },
+        }
+    )
+    assert v.validate_strings({'a': '1', 'b': '2.5', 'c': 'true', 'd': ['1', '2'], 'e': 'x'}) == {
+        'a': 1,
+        'b': 2.5,
+        'c': True,
+        'd': [1, 2],
+        'e': 'x',
+    }
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'a': 'x', 'b': '2.5', 'c': 'true', 'd': ['1', 'y'], 'e': 'x'})
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ['a']),
+        ('int_parsing', ['d', 1]),
+    ]
+
+
+def test_strict_schema():
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'bool'}}}, {'strict': True}
+    )
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python({'a': '1', 'b': 'false'})
+    assert v.validate_strings({'a': '1', 'b': 'false'}) == {'a': 1, 'b': False}
+
+
+def test_mapping():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'})
+    assert v.validate_strings(MappingProxyType({'PORT': '8000'})) == {'PORT': 8000}
+
+
+def test_validation_info_mode():
+    modes = []
+
+    def f(input_value, info, **kwargs):
+        modes.append(info.mode)
+        return input_value
+
+    v = SchemaValidator({'type': 'function', 'mode': 'after', 'function': f, 'schema': 'int'})
+    assert v.validate_strings('1') == 1
+    assert v.validate_python(1) == 1
+    assert v.validate_json('1') == 1
+    assert modes == ['strings', 'python', 'json']
+
+
+STRING_DATA_MSG = 'String data should only contain str values, and dicts, lists, tuples or mappings of them'
+
+
+@pytest.mark.parametrize(
+    'input_value,errors',
+    [
+        (1, [{'kind': 'string_data_type', 'loc': [], 'message': STRING_DATA_MSG, 'input_value': 1}]),
+        ({'a': None}, [{'kind': 'string_data_type', 'loc': ['a'], 'message': STRING_DATA_MSG, 'input_value': None}]),
+        (
+            {'a': ['1', 2], 'b': ('x', None)},
+            [
+                {'kind': 'string_data_type', 'loc': ['a', 1], 'message': STRING_DATA_MSG, 'input_value': 2},
+                {'kind': 'string_data_type', 'loc': ['b', 1], 'message': STRING_DATA_MSG, 'input_value': None},
+            ],
+        ),
+        ({1: '1'}, [{'kind': 'invalid_key', 'loc': [1], 'message': 'Keys should be strings', 'input_value': 1}]),
+    ],
+)
+def test_invalid_input(input_value, errors):
+    v = SchemaValidator({'type': 'any'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings(input_value)
+    assert exc_info.value.errors() == errors
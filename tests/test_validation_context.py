@@ -113,5 +113,23 @@ def test_validate_assignment_with_context():
     m1 = v.validate_python({'f1': '1', 'f2': '2'}, None, {'x': 'y'})
     assert m1 == {'f1': "1| context: {'x': 'y', 'f1': '1'}", 'f2': "2| context: {'x': 'y', 'f1': '1', 'f2': '2'}"}
 
-    m2 = v.validate_assignment('f1', '3', m1, None, {'x': 'y'})
+    m2 = v.validate_assignment(m1, 'f1', '3', None, {'x': 'y'})
     assert m2 == {'f1': "3| context: {'x': 'y', 'f1': '3'}", 'f2': "2| context: {'x': 'y', 'f1': '1', 'f2': '2'}"}
+
+
+def test_context_keyword():
+    contexts = []
+
+    def f(input_value, info, **kwargs):
+        contexts.append(info.context)
+        return input_value
+
+    v = SchemaValidator({'type': 'function', 'mode': 'after', 'function': f, 'schema': 'str'})
+    context = {'user': 'alice'}
+    assert v.validate_python('a', context=context) == 'a'
+    assert v.validate_json('"b"', context=context) == 'b'
+    assert v.validate_strings('c', context=context) == 'c'
+    assert v.isinstance_python('d', context=context) is True
+    assert v.validate_python('e') == 'e'
+    assert contexts == [context, context, context, context, None]
+    assert all(c is context for c in contexts[:4])
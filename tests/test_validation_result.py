@@ -0,0 +1,112 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationResult
+
+
+def test_valid():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    result = v.validate_python_result(['1', 2])
+    assert isinstance(result, ValidationResult)
+    assert result.ok is True
+    assert result.value == [1, 2]
+    assert result.errors == []
+    assert result.error_count() == 0
+    assert repr(result) == 'ValidationResult(ok=True, value=[1, 2])'
+
+
+def test_invalid():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    result = v.validate_python_result([1, 'x', 'y'])
+    assert result.ok is False
+    assert result.value is None
+    assert result.title == 'list[int]'
+    assert result.error_count() == 2
+    assert result.errors == [
+        {
+            'kind': 'int_parsing',
+            'loc': [1],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+        {
+            'kind': 'int_parsing',
+            'loc': [2],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'y',
+        },
+    ]
+    assert repr(result) == 'ValidationResult(ok=False, error_count=2)'
+
+
+def test_none_value():
+    v = SchemaValidator('none')
+    result = v.validate_python_result(None)
+    assert result.ok is True
+    assert result.value is None
+
+
+def test_strict():
+    v = SchemaValidator('int')
+    assert v.validate_python_result('1').value == 1
+    result = v.validate_python_result('1', strict=True)
+    assert result.ok is False
+    assert result.errors[0]['kind'] == 'int_type'
+
+
+def test_internal_error():
+    def raise_error(input_value, **kwargs):
+        raise RuntimeError('internal error')
+
+    v = SchemaValidator({'type': 'function', 'mode': 'plain', 'function': raise_error})
+    with pytest.raises(RuntimeError, match='internal error'):
+        v.validate_python_result(1)
+
+
+def test_validate_many():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    results = v.validate_many(iter([[1, '2'], [1, 'x'], 'wrong']))
+    assert [r.ok for r in results] == [True, False, False]
+    assert results[0].value == [1, 2]
+    assert [(e['kind'], e['loc']) for e in results[1].errors] == [('int_parsing', [1])]
+    assert [(e['kind'], e['loc']) for e in results[2].errors] == [('list_type', [])]
+    assert v.validate_many([]) == []
+
+
+def test_validate_many_strict():
+    v = SchemaValidator('int')
+    assert [r.ok for r in v.validate_many(['1', 1], strict=True)] == [False, True]
+
+
+@pytest.mark.parametrize('parallel_parse', [False, True])
+def test_validate_many_json(parallel_parse):
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    inputs = ['[1, 2]', b'[1, "x"]', bytearray(b'[3]'), memoryview(b'[4]'), '[1,']
+    results = v.validate_many(inputs, json=True, parallel_parse=parallel_parse)
+    assert [r.value for r in results] == [[1, 2], None, [3], [4], None]
+    assert [(e['kind'], e['loc']) for e in results[1].errors] == [('int_parsing', [1])]
+    assert [e['kind'] for e in results[4].errors] == ['invalid_json']
+
+    many = [f'[{i}]' for i in range(1000)]
+    results = v.validate_many(many, json=True, parallel_parse=parallel_parse)
+    assert [r.value for r in results] == [[i] for i in range(1000)]
+
+
+@pytest.mark.parametrize('parallel_parse', [False, True])
+def test_validate_many_json_wrong_type(parallel_parse):
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    results = v.validate_many(['[1]', 1, '[2]'], json=True, parallel_parse=parallel_parse)
+    assert [r.value for r in results] == [[1], None, [2]]
+    assert results[1].errors == [
+        {
+            'kind': 'json_type',
+            'loc': [],
+            'message': 'JSON input should be string, bytes, bytearray or memoryview',
+            'input_value': 1,
+        }
+    ]
+
+
+def test_validate_many_parallel_parse_requires_json():
+    v = SchemaValidator('int')
+    with pytest.raises(ValueError, match='`parallel_parse` is only supported with `json=True`'):
+        v.validate_many([1], parallel_parse=True)
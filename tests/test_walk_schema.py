@@ -0,0 +1,125 @@
+import dataclasses
+
+import pytest
+
+from pydantic_core import SchemaValidator, walk_schema
+
+
+def test_walk_order():
+    schema = {
+        'type': 'typed-dict',
+        'fields': {
+            'a': {'schema': 'int'},
+            'b': {'schema': {'type': 'list', 'items_schema': {'type': 'nullable', 'schema': 'str'}}},
+        },
+    }
+    visited = []
+
+    def visitor(node):
+        visited.append(node['type'])
+
+    assert walk_schema(schema, visitor) == {
+        'type': 'typed-dict',
+        'fields': {
+            'a': {'schema': {'type': 'int'}},
+            'b': {'schema': {'type': 'list', 'items_schema': {'type': 'nullable', 'schema': {'type': 'str'}}}},
+        },
+    }
+    # children are visited before their parents
+    assert visited == ['int', 'str', 'nullable', 'list', 'typed-dict']
+
+
+def test_make_strings_strict():
+    schema = {'type': 'list', 'items_schema': {'type': 'union', 'choices': ['str', 'int']}}
+
+    def visitor(node):
+        if node['type'] == 'str':
+            return {**node, 'strict': True}
+
+    new_schema = walk_schema(schema, visitor)
+    assert new_schema['items_schema']['choices'] == [{'type': 'str', 'strict': True}, {'type': 'int'}]
+    # the original schema is unchanged
+    assert schema == {'type': 'list', 'items_schema': {'type': 'union', 'choices': ['str', 'int']}}
+
+    v = SchemaValidator(new_schema)
+    assert v.validate_python(['a', 1]) == ['a', 1]
+    assert v.validate_python([b'1']) == [1]
+
+
+def test_partial_typed_dict():
+    schema = {
+        'type': 'typed-dict',
+        'fields': {
+            'name': {'schema': {'type': 'str', 'min_length': 3}},
+            'sub': {'schema': {'type': 'typed-dict', 'fields': {'x': {'schema': 'int'}}}},
+        },
+    }
+
+    def visitor(node):
+        node.pop('min_length', None)
+        if node['type'] == 'typed-dict':
+            node['fields'] = {name: {**field, 'required': False} for name, field in node['fields'].items()}
+        return node
+
+    v = SchemaValidator(walk_schema(schema, visitor))
+    assert v.validate_python({}) == {}
+    assert v.validate_python({'name': 'a', 'sub': {}}) == {'name': 'a', 'sub': {}}
+
+
+def test_tagged_union_and_arguments():
+    schema = {
+        'type': 'call',
+        'function': dict,
+        'arguments_schema': {
+            'type': 'arguments',
+            'arguments_schema': [
+                {'name': 'x', 'schema': {'type': 'tagged-union', 'discriminator': 't', 'choices': {'a': 'any'}}}
+            ],
+        },
+    }
+    visited = []
+    walk_schema(schema, lambda node: visited.append(node['type']))
+    assert visited == ['any', 'tagged-union', 'arguments', 'call']
+
+
+def test_visitor_error():
+    def visitor(node):
+        raise RuntimeError('boom')
+
+    with pytest.raises(RuntimeError, match='boom'):
+        walk_schema('int', visitor)
+
+
+def test_return_node():
+    assert walk_schema({'type': 'list', 'items_schema': 'int'}, lambda node: node) == {
+        'type': 'list',
+        'items_schema': {'type': 'int'},
+    }
+
+
+@dataclasses.dataclass
+class Foo:
+    a: str
+    b: int
+
+
+def test_dataclass_fields():
+    schema = {'type': 'dataclass', 'dataclass_type': Foo, 'fields': {'a': 'str', 'b': {'type': 'int'}}}
+    visited = []
+
+    def visitor(node):
+        visited.append(node['type'])
+        if node['type'] == 'int':
+            return {**node, 'strict': True}
+
+    new_schema = walk_schema(schema, visitor)
+    assert new_schema == {
+        'type': 'dataclass',
+        'dataclass_type': Foo,
+        'fields': {'a': {'type': 'str'}, 'b': {'type': 'int', 'strict': True}},
+    }
+    assert visited == ['str', 'int', 'dataclass']
+
+    v = SchemaValidator(new_schema)
+    assert v.validate_python({'a': 'x', 'b': 1}) == Foo(a='x', b=1)
+    assert v.dump_schema() == new_schema
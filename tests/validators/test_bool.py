@@ -2,7 +2,7 @@ import re
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -76,9 +76,11 @@ def test_bool_error():
 
 def test_bool_repr():
     v = SchemaValidator({'type': 'bool'})
-    assert plain_repr(v) == 'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:false}))'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:false,extra_strings:None}))'
+    )
     v = SchemaValidator({'type': 'bool', 'strict': True})
-    assert plain_repr(v) == 'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:true}))'
+    assert plain_repr(v) == 'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:true,extra_strings:None}))'
 
 
 def test_bool_key(py_and_json: PyAndJson):
@@ -88,3 +90,38 @@ def test_bool_key(py_and_json: PyAndJson):
     assert v.validate_test({'true': 1, 'off': 2}, strict=False) == {True: 1, False: 2}
     with pytest.raises(ValidationError, match='Input should be a valid boolean'):
         v.validate_test({'true': 1, 'off': 2}, strict=True)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('ja', True),
+        ('JA', True),
+        ('Nein', False),
+        ('да', True),
+        ('НЕТ', False),
+        ('yes', True),
+        ('off', False),
+        ('maybe', Err('Input should be a valid boolean, unable to interpret input [kind=bool_parsing,')),
+    ],
+)
+def test_bool_extra_strings(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'bool', 'true_strings': ['ja', 'да'], 'false_strings': ['nein', 'нет']})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) is expected
+
+
+def test_bool_extra_strings_config():
+    v = SchemaValidator({'type': 'bool'}, {'bool_true_strings': ['sure']})
+    assert v.validate_python('Sure') is True
+    assert v.validate_python('no') is False
+    with pytest.raises(ValidationError, match='Input should be a valid boolean'):
+        v.validate_python('sure', strict=True)
+
+
+def test_bool_extra_strings_conflict():
+    with pytest.raises(SchemaError, match='"Y" can\'t be in both true_strings and false_strings'):
+        SchemaValidator({'type': 'bool', 'true_strings': ['y'], 'false_strings': ['Y']})
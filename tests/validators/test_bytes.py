@@ -3,7 +3,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson
 
@@ -121,3 +121,93 @@ def test_length_ctx():
             'context': {'max_length': 3},
         }
     ]
+
+
+@pytest.mark.parametrize(
+    'encoding,input,expected',
+    [
+        ('utf8', 'café', b'caf\xc3\xa9'),
+        ('ascii', 'foo', b'foo'),
+        ('ascii', 'café', Err("Data should be valid ascii, invalid character 'é' at index 3")),
+        ('latin1', 'café', b'caf\xe9'),
+        ('latin1', 'caf🐈', Err("Data should be valid latin1, invalid character '🐈' at index 3")),
+    ],
+)
+def test_encoding(py_and_json: PyAndJson, encoding, input, expected):
+    v = py_and_json({'type': 'bytes', 'encoding': encoding})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input)
+    else:
+        assert v.validate_test(input) == expected
+
+
+def test_encoding_config():
+    v = SchemaValidator({'type': 'bytes', 'max_length': 10}, {'bytes_encoding': 'latin1'})
+    assert v.validate_python('café') == b'caf\xe9'
+    assert v.validate_json('"café"') == b'caf\xe9'
+    # bytes are never re-encoded
+    assert v.validate_python(b'caf\xc3\xa9') == b'caf\xc3\xa9'
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"ā"')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'bytes_invalid_encoding',
+            'loc': [],
+            'message': "Data should be valid latin1, invalid character 'ā' at index 0",
+            'input_value': 'ā',
+            'context': {'encoding': 'latin1', 'error': "invalid character 'ā' at index 0"},
+        }
+    ]
+
+
+def test_strict_encoding():
+    v = SchemaValidator({'type': 'bytes', 'encoding': 'ascii', 'strict': True})
+    with pytest.raises(ValidationError, match='Input should be a valid bytes'):
+        v.validate_python('foo')
+    assert v.validate_json('"foo"') == b'foo'
+
+
+def test_invalid_encoding():
+    with pytest.raises(SchemaError, match="Input should be one of: 'utf8', 'ascii', 'latin1', 'base64', 'base64url'"):
+        SchemaValidator({'type': 'bytes', 'encoding': 'utf16'})
+
+
+@pytest.mark.parametrize(
+    'encoding,input,expected',
+    [
+        ('base64', 'aGVsbG8gd29ybGQ=', b'hello world'),
+        ('base64', 'aGVsbG8gd29ybGQ', b'hello world'),
+        ('base64', '', b''),
+        ('base64', '+/+/', b'\xfb\xff\xbf'),
+        ('base64', '-_-_', Err("Data should be valid base64, invalid character '-' at index 0")),
+        ('base64url', '-_-_', b'\xfb\xff\xbf'),
+        ('base64url', '+/+/', Err("Data should be valid base64url, invalid character '+' at index 0")),
+        ('base64', 'aGVs!G8=', Err("Data should be valid base64, invalid character '!' at index 4")),
+        ('base64', 'aGVsbG8=x', Err('Data should be valid base64, invalid padding at index 8')),
+        ('base64', 'aGVsbG8==', Err('Data should be valid base64, invalid padding at index 7')),
+        ('base64', 'aGVs====', Err('Data should be valid base64, invalid padding at index 4')),
+        ('base64', 'aGVsbA==', b'hell'),
+        ('base64', 'aGVsbA=', Err('Data should be valid base64, invalid padding at index 6')),
+        ('base64', 'aGVsbA===', Err('Data should be valid base64, invalid padding at index 6')),
+        ('base64', 'aGVsb', Err('Data should be valid base64, invalid padding at index 5')),
+    ],
+)
+def test_base64(py_and_json: PyAndJson, encoding, input, expected):
+    v = py_and_json({'type': 'bytes', 'encoding': encoding})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input)
+    else:
+        assert v.validate_test(input) == expected
+
+
+def test_base64_length():
+    v = SchemaValidator({'type': 'bytes', 'encoding': 'base64', 'max_length': 5})
+    # length constraints apply to the decoded bytes
+    assert v.validate_json('"aGVsbG8="') == b'hello'
+    with pytest.raises(ValidationError, match='Data should have at most 5 bytes'):
+        v.validate_json('"aGVsbG8gd29ybGQ="')
+    # bytes input is never decoded
+    assert v.validate_python(b'aGVs') == b'aGVs'
@@ -60,3 +60,27 @@ def test_repr():
 
     with pytest.raises(ValidationError, match=r'callable\s+Input should be callable'):
         v.validate_python('foo')
+
+
+def test_reject_classes():
+    v = SchemaValidator({'type': 'callable', 'reject_classes': True, 'strict': True})
+    assert v.validate_python(func) == func
+    assert v.validate_python(CallableClass()) is not None
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Foo)
+    assert exc_info.value.errors() == [
+        {'kind': 'callable_type', 'loc': [], 'message': 'Input should be callable', 'input_value': Foo}
+    ]
+    assert v.isinstance_python(dict) is False
+
+
+def test_reject_classes_lax():
+    v = SchemaValidator({'type': 'callable', 'reject_classes': True})
+    # classes are only rejected in strict mode
+    assert v.validate_python(Foo) is Foo
+    assert v.isinstance_python(Foo, strict=True) is False
+
+
+def test_strict_allows_classes():
+    v = SchemaValidator({'type': 'callable', 'strict': True})
+    assert v.validate_python(Foo) is Foo
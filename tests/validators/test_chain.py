@@ -0,0 +1,72 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+
+def test_chain(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'chain',
+            'steps': [
+                {'type': 'str', 'strip_whitespace': True},
+                'int',
+                {'type': 'function', 'mode': 'plain', 'function': lambda i, **kwargs: -i},
+            ],
+        }
+    )
+    assert v.validate_test(' 42 ') == -42
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_test(' x ')
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_test(123)
+
+
+def test_steps_see_previous_output():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        return input_value * 2
+
+    step = {'type': 'function', 'mode': 'plain', 'function': f}
+    v = SchemaValidator({'type': 'chain', 'steps': ['int', step, step, {'type': 'int', 'lt': 10}]})
+    assert v.validate_python('2') == 8
+    assert calls == [2, 4]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('3')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'less_than',
+            'loc': [],
+            'message': 'Input should be less than 10',
+            'input_value': 12,
+            'context': {'lt': 10},
+        }
+    ]
+
+
+def test_in_typed_dict():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'f': {'schema': {'type': 'chain', 'steps': ['str', 'int']}}}})
+    assert v.validate_python({'f': '1'}) == {'f': 1}
+    with pytest.raises(ValidationError, match=r'f\n +Input should be a valid integer'):
+        v.validate_python({'f': 'x'})
+
+
+def test_single_step():
+    v = SchemaValidator({'type': 'chain', 'steps': ['int']})
+    assert repr(v).startswith('SchemaValidator(name="int"')
+    assert v.validate_python('1') == 1
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'chain', 'steps': ['str', 'int']})
+    assert repr(v).startswith('SchemaValidator(name="chain[str,int]"')
+
+
+def test_no_steps():
+    with pytest.raises(SchemaError, match=re.escape('One or more steps are required for a chain validator')):
+        SchemaValidator({'type': 'chain', 'steps': []})
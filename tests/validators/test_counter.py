@@ -0,0 +1,57 @@
+import re
+from collections import Counter
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+
+def test_counter(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'counter', 'keys_schema': 'str'})
+    output = v.validate_test({'a': 1, 'b': '2'})
+    assert isinstance(output, Counter)
+    assert output == Counter(a=1, b=2)
+    assert output['missing'] == 0
+
+
+def test_counter_from_counter():
+    v = SchemaValidator('counter')
+    input_value = Counter('abca')
+    output = v.validate_python(input_value)
+    assert output == Counter(a=2, b=1, c=1)
+    assert output.most_common(1) == [('a', 2)]
+    assert output is not input_value
+
+
+def test_counter_values_int():
+    v = SchemaValidator({'type': 'counter', 'keys_schema': 'int', 'max_items': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1: 'x', 2: 1.5})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': [1],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+        {
+            'kind': 'int_from_float',
+            'loc': [2],
+            'message': 'Input should be a valid integer, got a number with a fractional part',
+            'input_value': 1.5,
+        },
+    ]
+    with pytest.raises(ValidationError, match=re.escape('Input should have at most 2 items, got 3 items')):
+        v.validate_python({1: 1, 2: 2, 3: 3})
+
+
+def test_counter_values_schema():
+    with pytest.raises(SchemaError, match="Extra inputs are not permitted"):
+        SchemaValidator({'type': 'counter', 'values_schema': 'str'})
+
+
+def test_counter_repr():
+    v = SchemaValidator({'type': 'counter', 'keys_schema': 'str'})
+    assert repr(v).startswith('SchemaValidator(name="counter[str,int]", validator=Counter(')
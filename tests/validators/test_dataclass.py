@@ -0,0 +1,133 @@
+import dataclasses
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+
+@dataclasses.dataclass
+class FooDataclass:
+    a: str
+    b: int = 42
+    c: list = dataclasses.field(default_factory=list)
+
+
+def test_dataclass(py_and_json: PyAndJson):
+    v = py_and_json(
+        {'type': 'dataclass', 'dataclass_type': FooDataclass, 'fields': {'a': 'str', 'b': 'int', 'c': 'list'}}
+    )
+    foo = v.validate_test({'a': 'hello', 'b': '123'})
+    assert isinstance(foo, FooDataclass)
+    assert foo == FooDataclass(a='hello', b=123, c=[])
+
+    assert v.validate_test({'a': 'x'}) == FooDataclass(a='x')
+    assert v.validate_test({'a': 'x', 'c': [1], 'extra': 1}) == FooDataclass(a='x', c=[1])
+
+
+def test_dataclass_errors():
+    v = SchemaValidator({'type': 'dataclass', 'dataclass_type': FooDataclass, 'fields': {'a': 'str', 'b': 'int'}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'b': 'x'})
+    assert exc_info.value.errors() == [
+        {'kind': 'missing', 'loc': ['a'], 'message': 'Field required', 'input_value': {'b': 'x'}},
+        {
+            'kind': 'int_parsing',
+            'loc': ['b'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+    ]
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid dictionary [kind=dict_type,')):
+        v.validate_python([1, 2])
+
+
+def test_default_fields_any():
+    v = SchemaValidator({'type': 'dataclass', 'dataclass_type': FooDataclass})
+    assert v.validate_python({'a': 1, 'b': 'x'}) == FooDataclass(a=1, b='x')
+
+
+def test_init_false_post_init():
+    @dataclasses.dataclass
+    class MyDataclass:
+        a: int
+        factor: dataclasses.InitVar[int] = 2
+        b: int = dataclasses.field(init=False)
+
+        def __post_init__(self, factor):
+            if self.a < 0:
+                raise ValueError('a must not be negative')
+            self.b = self.a * factor
+
+    v = SchemaValidator(
+        {'type': 'dataclass', 'dataclass_type': MyDataclass, 'fields': {'a': 'int', 'factor': 'int', 'b': 'str'}}
+    )
+    m = v.validate_python({'a': '3'})
+    assert m.a == 3
+    assert m.b == 6
+
+    m = v.validate_python({'a': 3, 'factor': '10', 'b': 'ignored'})
+    assert m.b == 30
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': -1})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'value_error',
+            'loc': [],
+            'message': 'Value error, a must not be negative',
+            'input_value': {'a': -1},
+            'context': {'error': 'a must not be negative'},
+        }
+    ]
+
+
+def test_instances():
+    v = SchemaValidator({'type': 'dataclass', 'dataclass_type': FooDataclass, 'fields': {'b': 'int'}})
+    foo = FooDataclass(a='x', b='not an int')
+    assert v.validate_python(foo) is foo
+
+
+def test_revalidate_instances():
+    v = SchemaValidator(
+        {'type': 'dataclass', 'dataclass_type': FooDataclass, 'fields': {'b': 'int'}, 'revalidate_instances': True}
+    )
+    foo = FooDataclass(a='x', b='123')
+    output = v.validate_python(foo)
+    assert output is not foo
+    assert output == FooDataclass(a='x', b=123)
+
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid integer')):
+        v.validate_python(FooDataclass(a='x', b='y'))
+
+
+def test_strict():
+    v = SchemaValidator({'type': 'dataclass', 'dataclass_type': FooDataclass, 'strict': True})
+    foo = FooDataclass(a='x')
+    assert v.validate_python(foo) is foo
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'dataclass_type',
+            'loc': [],
+            'message': 'Input should be an instance of FooDataclass',
+            'input_value': {'a': 'x'},
+            'context': {'class_name': 'FooDataclass'},
+        }
+    ]
+
+
+def test_not_dataclass():
+    class NotDataclass:
+        pass
+
+    with pytest.raises(SchemaError, match='dataclass_type must be a dataclass, got NotDataclass'):
+        SchemaValidator({'type': 'dataclass', 'dataclass_type': NotDataclass})
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'dataclass', 'dataclass_type': FooDataclass})
+    assert repr(v).startswith('SchemaValidator(name="FooDataclass", validator=Dataclass(')
@@ -259,3 +259,41 @@ def test_union():
 def test_invalid_constraint():
     with pytest.raises(SchemaError, match='datetime -> gt\n  Input should be a valid datetime'):
         SchemaValidator({'type': 'datetime', 'gt': 'foobar'})
+
+
+def test_assume_tz(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'datetime', 'assume_tz': timezone(timedelta(hours=2))})
+    expected = datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone(timedelta(hours=2)))
+    assert v.validate_test('2022-06-08T12:13:14') == expected
+    # aware datetimes are unchanged
+    assert v.validate_test('2022-06-08T12:13:14Z') == datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone.utc)
+
+
+def test_assume_tz_python():
+    tz = pytz.timezone('Europe/London')
+    v = SchemaValidator({'type': 'datetime'}, {'datetime_assume_tz': timezone.utc})
+    assert v.validate_python(datetime(2022, 6, 8, 12)).tzinfo is timezone.utc
+    assert v.validate_python(datetime(2022, 6, 8, 12, tzinfo=tz)).tzinfo is tz
+
+    # the schema takes precedence over config
+    v = SchemaValidator({'type': 'datetime', 'assume_tz': tz}, {'datetime_assume_tz': timezone.utc})
+    assert v.validate_python(datetime(2022, 6, 8, 12)).tzinfo is tz
+
+
+def test_assume_tz_constraint():
+    v = SchemaValidator(
+        {
+            'type': 'datetime',
+            'assume_tz': timezone(timedelta(hours=2)),
+            'gt': datetime(2022, 6, 8, 11, tzinfo=timezone.utc),
+        }
+    )
+    assert v.validate_python('2022-06-08T13:30') == datetime(2022, 6, 8, 13, 30, tzinfo=timezone(timedelta(hours=2)))
+    # 12:30+02:00 is before 11:00 UTC
+    with pytest.raises(ValidationError, match='Input should be greater than 2022-06-08T11:00:00Z'):
+        v.validate_python('2022-06-08T12:30')
+
+
+def test_assume_tz_invalid():
+    with pytest.raises(SchemaError, match='assume_tz must be a datetime.tzinfo instance'):
+        SchemaValidator({'type': 'datetime', 'assume_tz': 'UTC'})
@@ -0,0 +1,84 @@
+import re
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Decimal('1.25'), Decimal('1.25')),
+        (2, Decimal(2)),
+        (0.1, Decimal('0.1')),
+        ('1.10', Decimal('1.10')),
+        (' -3.5 ', Decimal('-3.5')),
+        ('1e3', Decimal('1E+3')),
+        ('foobar', Err('Input should be a valid decimal, unable to parse string as a decimal [kind=decimal_parsing,')),
+        (True, Err('Input should be a valid decimal [kind=decimal_type, input_value=True, input_type=bool]')),
+        ([1], Err('Input should be a valid decimal [kind=decimal_type,')),
+    ],
+)
+def test_decimal(input_value, expected):
+    v = SchemaValidator({'type': 'decimal'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert isinstance(output, Decimal)
+
+
+def test_decimal_json(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'decimal'})
+    assert v.validate_test('1.5') == Decimal('1.5')
+    assert v.validate_test(3) == Decimal(3)
+    assert v.validate_test(0.1) == Decimal('0.1')
+
+
+def test_decimal_unchanged():
+    d = Decimal('1.5')
+    assert SchemaValidator('decimal').validate_python(d) is d
+
+
+def test_decimal_strict():
+    v = SchemaValidator({'type': 'decimal', 'strict': True})
+    assert v.validate_python(Decimal('1.5')) == Decimal('1.5')
+    with pytest.raises(ValidationError, match=r'Input should be a valid decimal \[kind=decimal_type'):
+        v.validate_python('1.5')
+    with pytest.raises(ValidationError, match=r'Input should be a valid decimal \[kind=decimal_type'):
+        v.validate_python(1)
+
+
+def test_float_policy_exact():
+    v = SchemaValidator({'type': 'decimal', 'float_policy': 'exact'})
+    assert v.validate_python(0.1) == Decimal(0.1)
+    assert v.validate_python(0.1) != Decimal('0.1')
+    assert v.validate_python(0.5) == Decimal('0.5')
+    assert v.validate_python('0.1') == Decimal('0.1')
+
+
+def test_float_policy_reject(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'decimal', 'float_policy': 'reject'})
+    assert v.validate_test('0.1') == Decimal('0.1')
+    assert v.validate_test(1) == Decimal(1)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(0.1)
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'decimal_from_float',
+            'loc': [],
+            'message': "Input should be a valid decimal, floats are not accepted with float_policy 'reject'",
+            'input_value': 0.1,
+            'context': {'float_policy': 'reject'},
+        }
+    ]
+
+
+def test_invalid_float_policy():
+    with pytest.raises(SchemaError, match="Input should be one of: 'reject', 'str', 'exact'"):
+        SchemaValidator({'type': 'decimal', 'float_policy': 'round'})
@@ -0,0 +1,63 @@
+import re
+from collections import defaultdict
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+
+def test_defaultdict(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'defaultdict', 'default_factory': list, 'keys_schema': 'str', 'values_schema': 'int'})
+    output = v.validate_test({'a': 1, 'b': '2'})
+    assert isinstance(output, defaultdict)
+    assert output == {'a': 1, 'b': 2}
+    assert output.default_factory is list
+    assert output['missing'] == []
+
+
+def test_defaultdict_from_defaultdict():
+    v = SchemaValidator({'type': 'defaultdict', 'default_factory': int, 'values_schema': 'int'})
+    input_value = defaultdict(str, {'a': '1'})
+    output = v.validate_python(input_value)
+    assert output == {'a': 1}
+    # the schema's default_factory is used
+    assert output.default_factory is int
+    assert output is not input_value
+
+
+def test_defaultdict_errors():
+    v = SchemaValidator(
+        {'type': 'defaultdict', 'default_factory': list, 'keys_schema': 'int', 'values_schema': 'int', 'max_items': 2}
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': 1, 2: 'y'})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['x', '[key]'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+        {
+            'kind': 'int_parsing',
+            'loc': [2],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'y',
+        },
+    ]
+    with pytest.raises(ValidationError, match=re.escape('Input should have at most 2 items, got 3 items')):
+        v.validate_python({1: 1, 2: 2, 3: 3})
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid dictionary [kind=dict_type,')):
+        v.validate_python([1, 2])
+
+
+def test_defaultdict_not_callable():
+    with pytest.raises(SchemaError, match='defaultdict -> default_factory\n  Input should be callable'):
+        SchemaValidator({'type': 'defaultdict', 'default_factory': 'list'})
+
+
+def test_defaultdict_repr():
+    v = SchemaValidator({'type': 'defaultdict', 'default_factory': list, 'keys_schema': 'str'})
+    assert repr(v).startswith('SchemaValidator(name="defaultdict[str,any]", validator=DefaultDict(')
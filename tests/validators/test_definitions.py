@@ -0,0 +1,110 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+
+def test_list_of_ints():
+    v = SchemaValidator(
+        {
+            'type': 'definitions',
+            'schema': {'type': 'list', 'items_schema': {'type': 'definition-ref', 'schema_ref': 'foobar'}},
+            'definitions': [{'type': 'int', 'ref': 'foobar'}],
+        }
+    )
+    assert v.validate_python([1, 2, '3']) == [1, 2, 3]
+    assert repr(v).startswith('SchemaValidator(name="list[...]"')
+    with pytest.raises(ValidationError, match=r'1\n +Input should be a valid integer'):
+        v.validate_python([1, 'x'])
+
+
+def test_mutually_recursive():
+    # 'Author' references 'Book' before it's defined
+    v = SchemaValidator(
+        {
+            'type': 'definitions',
+            'schema': {'type': 'definition-ref', 'schema_ref': 'Author'},
+            'definitions': [
+                {
+                    'type': 'typed-dict',
+                    'ref': 'Author',
+                    'fields': {
+                        'name': {'schema': 'str'},
+                        'books': {
+                            'schema': {'type': 'list', 'items_schema': {'type': 'definition-ref', 'schema_ref': 'Book'}}
+                        },
+                    },
+                },
+                {
+                    'type': 'typed-dict',
+                    'ref': 'Book',
+                    'fields': {
+                        'title': {'schema': 'str'},
+                        'author': {
+                            'schema': {'type': 'nullable', 'schema': {'type': 'definition-ref', 'schema_ref': 'Author'}}
+                        },
+                    },
+                },
+            ],
+        }
+    )
+    assert repr(v).startswith('SchemaValidator(name="typed-dict"')
+    data = {'name': 'Anna', 'books': [{'title': 'A', 'author': {'name': 'Bob', 'books': []}}, {'title': 'B'}]}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(data)
+    assert [(e['loc'], e['kind']) for e in exc_info.value.errors()] == [(['books', 1, 'author'], 'missing')]
+
+    data['books'][1]['author'] = None
+    assert v.validate_python(data) == data
+
+
+def test_cyclic_data():
+    v = SchemaValidator(
+        {
+            'type': 'definitions',
+            'schema': {'type': 'definition-ref', 'schema_ref': 'Branch'},
+            'definitions': [
+                {
+                    'type': 'typed-dict',
+                    'ref': 'Branch',
+                    'fields': {
+                        'sub': {
+                            'schema': {'type': 'nullable', 'schema': {'type': 'definition-ref', 'schema_ref': 'Branch'}}
+                        }
+                    },
+                }
+            ],
+        }
+    )
+    data = {'sub': None}
+    data['sub'] = {'sub': data}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(data)
+    assert [(e['loc'], e['kind']) for e in exc_info.value.errors()] == [(['sub', 'sub'], 'recursion_loop')]
+
+
+def test_unused_definitions():
+    v = SchemaValidator({'type': 'definitions', 'schema': 'str', 'definitions': [{'type': 'int', 'ref': 'foobar'}]})
+    assert v.validate_python('x') == 'x'
+
+
+@pytest.mark.parametrize(
+    'schema,error',
+    [
+        (
+            {'type': 'definitions', 'schema': {'type': 'definition-ref', 'schema_ref': 'missing'}, 'definitions': []},
+            "Slots Error: ref 'missing' not found",
+        ),
+        ({'type': 'definitions', 'schema': 'int', 'definitions': [{'type': 'int'}]}, 'Definitions must have a "ref"'),
+        (
+            {
+                'type': 'definitions',
+                'schema': 'int',
+                'definitions': [{'type': 'int', 'ref': 'foo'}, {'type': 'str', 'ref': 'foo'}],
+            },
+            'Duplicate ref: "foo"',
+        ),
+    ],
+)
+def test_errors(schema, error):
+    with pytest.raises(SchemaError, match=error):
+        SchemaValidator(schema)
@@ -0,0 +1,102 @@
+import re
+from collections import deque
+from typing import Any, Dict
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, 2, '3'], deque([1, 2, 3])),
+        ((1, 2, '3'), deque([1, 2, 3])),
+        (deque([1, 2, '3']), deque([1, 2, 3])),
+        ({1, 2, 3}, deque([1, 2, 3])),
+        (frozenset([1]), deque([1])),
+        ((x for x in [1, 2, '3']), deque([1, 2, 3])),
+        (range(3), deque([0, 1, 2])),
+        ({1: 10, 2: 20}.values(), deque([10, 20])),
+        ([], deque()),
+        ('123', Err('Input should be a valid deque [kind=deque_type, input_value=\'123\', input_type=str]')),
+        (b'123', Err('Input should be a valid deque [kind=deque_type,')),
+        ({1: 2}, Err('Input should be a valid deque [kind=deque_type,')),
+        (123, Err('Input should be a valid deque [kind=deque_type,')),
+        ([1, 'x'], Err('1\n  Input should be a valid integer, unable to parse string as an integer')),
+    ],
+)
+def test_deque_ints(input_value, expected):
+    v = SchemaValidator({'type': 'deque', 'items_schema': 'int'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert isinstance(output, deque)
+        assert output == expected
+
+
+def test_deque_json(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'deque', 'items_schema': 'int'})
+    assert v.validate_test([1, '2']) == deque([1, 2])
+    with pytest.raises(ValidationError, match='Input should be a valid deque'):
+        v.validate_test('[1]')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (deque([1, 2]), deque([1, 2])),
+        ([1, 2], Err('Input should be a valid deque [kind=deque_type,')),
+        ((1, 2), Err('Input should be a valid deque [kind=deque_type,')),
+    ],
+)
+def test_deque_strict(input_value, expected):
+    v = SchemaValidator({'type': 'deque', 'items_schema': 'int', 'strict': True})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_deque_strict_json():
+    v = SchemaValidator({'type': 'deque', 'items_schema': 'int', 'strict': True})
+    assert v.validate_json('[1, 2]') == deque([1, 2])
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'maxlen': 2}, [1, 2, 3], deque([2, 3], maxlen=2)),
+        ({'maxlen': 5}, [1, 2, 3], deque([1, 2, 3], maxlen=5)),
+        ({'min_items': 3}, [1, 2, 3], deque([1, 2, 3])),
+        ({'min_items': 3}, [1, 2], Err('Input should have at least 3 items, got 2 items [kind=too_short,')),
+        ({'max_items': 2}, [1, 2, 3], Err('Input should have at most 2 items, got 3 items [kind=too_long,')),
+    ],
+)
+def test_deque_kwargs(kwargs: Dict[str, Any], input_value, expected):
+    v = SchemaValidator({'type': 'deque', **kwargs})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert output.maxlen == expected.maxlen
+
+
+def test_deque_copied():
+    d = deque([1, 2, 3])
+    v = SchemaValidator('deque')
+    output = v.validate_python(d)
+    assert output == d
+    assert output is not d
+
+
+def test_deque_repr():
+    v = SchemaValidator({'type': 'deque', 'items_schema': 'str'})
+    assert repr(v).startswith('SchemaValidator(name="deque[str]", validator=Deque(')
@@ -1,6 +1,7 @@
 import re
-from collections import OrderedDict
+from collections import ChainMap, OrderedDict
 from collections.abc import Mapping
+from types import MappingProxyType
 from typing import Any, Dict
 
 import pytest
@@ -118,6 +119,22 @@ def test_mapping():
         v.validate_python(MyMapping({'1': 2, 3: '4'}))
 
 
+@pytest.mark.parametrize(
+    'input_value',
+    [ChainMap({'1': 2}, {3: '4'}), MappingProxyType({'1': 2, 3: '4'})],
+    ids=lambda v: type(v).__name__,
+)
+def test_stdlib_mappings(input_value):
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int'})
+    assert v.validate_python(input_value) == {1: 2, 3: 4}
+
+
+def test_stdlib_mappings_typed_dict():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}}})
+    assert v.validate_python(ChainMap({'a': '1'}, {'a': 2})) == {'a': 1}
+    assert v.validate_python(MappingProxyType({'a': '1'})) == {'a': 1}
+
+
 def test_key_error():
     v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'int'}})
     assert v.validate_python({'1': True}) == {1: 1}
@@ -216,3 +233,30 @@ def test_dict_length_constraints(kwargs: Dict[str, Any], input_value, expected):
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+def test_json_int_keys_in_field():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'f': {'schema': {'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int', 'max_items': 2}}},
+        }
+    )
+    assert v.validate_json('{"f": {"1": 2, "3": "4"}}') == {'f': {1: 2, 3: 4}}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"f": {"x": 2, "3": "y"}}')
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ['f', 'x', '[key]']),
+        ('int_parsing', ['f', '3']),
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"f": {"1": 1, "2": 2, "3": 3}}')
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('too_long', ['f'])]
+
+
+def test_dict_fail_fast(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int', 'fail_fast': True})
+    assert v.validate_test({'a': 1, 'b': '2'}) == {'a': 1, 'b': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': 1, 'b': 'x', 'c': 'y'})
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ['b'])]
@@ -6,7 +6,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import FunctionCheck
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -87,6 +87,19 @@ def test_float_strict(py_and_json: PyAndJson, input_value, expected):
         ({'multiple_of': 0.5}, 0.5, 0.5),
         ({'multiple_of': 0.5}, 1, 1),
         ({'multiple_of': 0.5}, 0.6, Err('Input should be a multiple of 0.5')),
+        ({'multiple_of': 0.1}, 0.3, 0.3),
+        ({'multiple_of': 0.1}, -0.7, -0.7),
+        ({'multiple_of': 0.1}, 123456.7, 123456.7),
+        ({'multiple_of': 0.1}, 0.35, Err('Input should be a multiple of 0.1')),
+        ({'multiple_of': 0.01}, 0.15000001, Err('Input should be a multiple of 0.01')),
+        ({'multiple_of': 0.1}, 98765432.1, 98765432.1),
+        ({'multiple_of': 0.1}, -98765432.1, -98765432.1),
+        ({'multiple_of': 0.1}, 98765432.15, Err('Input should be a multiple of 0.1')),
+        ({'multiple_of': 1e-10}, 3e-10, 3e-10),
+        ({'multiple_of': 1e-10}, 1.5e-10, Err('Input should be a multiple of 0.0000000001')),
+        ({'multiple_of': 1e-20}, 7e-20, 7e-20),
+        ({'multiple_of': 7}, 7e14, 7e14),
+        ({'multiple_of': 7}, 7e14 + 1, Err('Input should be a multiple of 7')),
     ],
 )
 def test_float_kwargs(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value, expected):
@@ -142,13 +155,15 @@ def test_union_float_simple(py_and_json: PyAndJson):
 
 def test_float_repr():
     v = SchemaValidator({'type': 'float'})
-    assert (
-        plain_repr(v)
-        == 'SchemaValidator(name="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true}))'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="float",validator=Float(FloatValidator{'
+        'strict:false,allow_inf_nan:true,'
+        'parsing:NumberParsing{radix_prefix:false,separators:None,percent:None,python_syntax:None,from_float:Error}'
+        '}))'
     )
     v = SchemaValidator({'type': 'float', 'strict': True})
-    assert (
-        plain_repr(v) == 'SchemaValidator(name="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true}))'
+    assert plain_repr(v).startswith(
+        'SchemaValidator(name="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true,'
     )
     v = SchemaValidator({'type': 'float', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(name="constrained-float",validator=ConstrainedFloat(')
@@ -293,3 +308,110 @@ def test_non_finite_constrained_float_values(input_value, allow_inf_nan, expecte
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'thousands_separator': ','}, '1,234.56', 1234.56),
+        ({'thousands_separator': ','}, '-1,234,567.5', -1234567.5),
+        ({'thousands_separator': ','}, '1234.5', 1234.5),
+        ({'thousands_separator': ','}, '1,5', Err('Input should be a valid number, unable to parse string')),
+        ({'thousands_separator': ','}, '12,34', Err('Input should be a valid number, unable to parse string')),
+        ({'thousands_separator': '.', 'decimal_point': ','}, '1.234,5', 1234.5),
+        ({'thousands_separator': ' ', 'decimal_point': ','}, '1 234 567,25', 1234567.25),
+        ({}, '1,234.56', Err('Input should be a valid number, unable to parse string as an number')),
+    ],
+)
+def test_float_thousands_separator(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value, expected):
+    v = py_and_json({'type': 'float', **kwargs})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_float_thousands_separator_config():
+    v = SchemaValidator({'type': 'float', 'ge': 1000}, {'number_thousands_separator': ','})
+    assert v.validate_python('1,000.5') == 1000.5
+    with pytest.raises(ValidationError, match='Input should be greater than or equal to 1000'):
+        v.validate_python('999.5')
+    with pytest.raises(ValidationError, match='Input should be a valid number'):
+        v.validate_python('1,000.5', strict=True)
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({'thousands_separator': ',,'}, '"thousands_separator" must be a single character, not ",,"'),
+        ({'decimal_point': ','}, 'decimal_point may only be used with thousands_separator'),
+        ({'thousands_separator': ',', 'decimal_point': ','}, 'thousands_separator and decimal_point must differ'),
+    ],
+)
+def test_float_thousands_separator_invalid(kwargs, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator({'type': 'float', **kwargs})
+
+
+@pytest.mark.parametrize(
+    'python_syntax,input_value,expected',
+    [
+        (True, '1_000.5', 1000.5),
+        (True, ' +1.5e1_0 ', 1.5e10),
+        (True, '1_.5', Err('Input should be a valid number, unable to parse string as an number')),
+        (False, '+1.5', Err('Input should be a valid number, unable to parse string as an number')),
+        (False, ' 1.5', Err('Input should be a valid number, unable to parse string as an number')),
+        (False, '-1.5', -1.5),
+    ],
+)
+def test_float_python_syntax(py_and_json: PyAndJson, python_syntax, input_value, expected):
+    v = py_and_json({'type': 'float', 'python_syntax': python_syntax})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_float_python_syntax_separators():
+    v = SchemaValidator({'type': 'float'}, {'number_python_syntax': True, 'number_thousands_separator': ','})
+    assert v.validate_python(' 1,000.5 ') == 1000.5
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'percent_mode': 'fraction'}, '85%', 0.85),
+        ({'percent_mode': 'fraction'}, '12.5%', 0.125),
+        ({'percent_mode': 'fraction'}, '85', 85),
+        ({'percent_mode': 'number'}, '85%', 85),
+        ({'percent_mode': 'number'}, '-2.5%', -2.5),
+        ({'percent_mode': 'fraction', 'thousands_separator': ','}, '1,250%', 12.5),
+        ({'percent_mode': 'fraction'}, '%', Err('Input should be a valid number, unable to parse string as an number')),
+        ({'percent_mode': 'fraction'}, '85%%', Err('Input should be a valid number, unable to parse string')),
+        ({}, '85%', Err('Input should be a valid number, unable to parse string as an number')),
+    ],
+)
+def test_float_percent(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value, expected):
+    v = py_and_json({'type': 'float', **kwargs})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_float_percent_config():
+    v = SchemaValidator({'type': 'float', 'le': 1}, {'float_percent_mode': 'fraction'})
+    assert v.validate_python('50%') == 0.5
+    with pytest.raises(ValidationError, match='Input should be less than or equal to 1'):
+        v.validate_python('150%')
+    with pytest.raises(ValidationError, match='Input should be a valid number'):
+        v.validate_python('50%', strict=True)
+
+
+@pytest.mark.parametrize('multiple_of', [0, -0.5, float('inf'), float('nan')])
+def test_invalid_multiple_of(multiple_of):
+    with pytest.raises(SchemaError, match='multiple_of must be a positive finite number'):
+        SchemaValidator({'type': 'float', 'multiple_of': multiple_of})
@@ -0,0 +1,102 @@
+import re
+from decimal import Decimal
+from fractions import Fraction
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Fraction(3, 7), Fraction(3, 7)),
+        (2, Fraction(2)),
+        (0.75, Fraction(3, 4)),
+        (Decimal('1.25'), Fraction(5, 4)),
+        ('3/7', Fraction(3, 7)),
+        (' -3/7 ', Fraction(-3, 7)),
+        ('1.5', Fraction(3, 2)),
+        ('2e-3', Fraction(1, 500)),
+        ('1/0', Err('Input should be a valid fraction, unable to parse string as a fraction [kind=fraction_parsing,')),
+        ('foobar', Err('Input should be a valid fraction, unable to parse string as a fraction')),
+        (True, Err('Input should be a valid fraction [kind=fraction_type, input_value=True, input_type=bool]')),
+        ([1], Err('Input should be a valid fraction [kind=fraction_type,')),
+        (float('inf'), Err('Input should be a finite number [kind=float_finite_number,')),
+        (float('nan'), Err('Input should be a finite number [kind=float_finite_number,')),
+    ],
+)
+def test_fraction(input_value, expected):
+    v = SchemaValidator({'type': 'fraction'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert isinstance(output, Fraction)
+
+
+def test_fraction_json(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'fraction'})
+    assert v.validate_test('3/4') == Fraction(3, 4)
+    assert v.validate_test(3) == Fraction(3)
+    assert v.validate_test(0.5) == Fraction(1, 2)
+
+
+def test_fraction_unchanged():
+    f = Fraction(1, 3)
+    assert SchemaValidator({'type': 'fraction'}).validate_python(f) is f
+
+
+def test_fraction_strict():
+    v = SchemaValidator({'type': 'fraction', 'strict': True})
+    assert v.validate_python(Fraction(1, 3)) == Fraction(1, 3)
+    with pytest.raises(ValidationError, match=r'Input should be a valid fraction \[kind=fraction_type'):
+        v.validate_python('1/3')
+    with pytest.raises(ValidationError, match=r'Input should be a valid fraction \[kind=fraction_type'):
+        v.validate_python(1)
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'gt': '1/3'}, '1/2', Fraction(1, 2)),
+        ({'gt': '1/3'}, '1/3', Err('Input should be greater than 1/3 [kind=greater_than,')),
+        ({'ge': Fraction(1, 3)}, '1/3', Fraction(1, 3)),
+        ({'ge': Fraction(1, 3)}, 0.25, Err('Input should be greater than or equal to 1/3 [kind=greater_than_equal,')),
+        ({'lt': 1}, '0.5', Fraction(1, 2)),
+        ({'lt': 1}, 1, Err('Input should be less than 1 [kind=less_than,')),
+        ({'le': 0.5}, '1/2', Fraction(1, 2)),
+        ({'le': 0.5}, '2/3', Err('Input should be less than or equal to 1/2 [kind=less_than_equal,')),
+    ],
+)
+def test_fraction_bounds(kwargs, input_value, expected):
+    v = SchemaValidator({'type': 'fraction', **kwargs})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_fraction_bound_context():
+    v = SchemaValidator({'type': 'fraction', 'gt': '1/3'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(0)
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'greater_than',
+            'loc': [],
+            'message': 'Input should be greater than 1/3',
+            'input_value': 0,
+            'context': {'gt': '1/3'},
+        }
+    ]
+
+
+def test_invalid_bound():
+    with pytest.raises(SchemaError, match="Invalid literal for Fraction: 'foobar'"):
+        SchemaValidator({'type': 'fraction', 'gt': 'foobar'})
@@ -147,6 +147,8 @@ def test_frozenset_multiple_errors():
         ({'min_items': 3}, {1, 2}, Err('Input should have at least 3 items, got 2 items [kind=too_short,')),
         ({'max_items': 3}, {1, 2, 3}, {1, 2, 3}),
         ({'max_items': 3}, {1, 2, 3, 4}, Err('Input should have at most 3 items, got 4 items [kind=too_long,')),
+        ({'max_items': 3}, [1, 2, 3, 3, 2], {1, 2, 3}),
+        ({'min_items': 3}, [1, 2, 2], Err('Input should have at least 3 items, got 2 items [kind=too_short,')),
     ],
 )
 def test_frozenset_kwargs_python(kwargs: Dict[str, Any], input_value, expected):
@@ -231,7 +233,8 @@ def test_repr():
         'SchemaValidator('
         'name="frozenset[any]",'
         'validator=FrozenSet(FrozenSetValidator{'
-        'strict:true,item_validator:None,size_range:Some((Some(42),None)),name:"frozenset[any]"'
+        'strict:true,item_validator:None,size_range:Some((Some(42),None)),str_delimiter:None,fail_fast:false,'
+        'name:"frozenset[any]"'
         '}))'
     )
 
@@ -277,3 +280,12 @@ def test_frozenset_from_dict_items(input_value, items_schema, expected):
     output = v.validate_python(input_value)
     assert isinstance(output, frozenset)
     assert output == expected
+
+
+def test_frozenset_unhashable():
+    v = SchemaValidator({'type': 'frozenset', 'items_schema': {'type': 'list'}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([(1, 2)])
+    assert exc_info.value.errors() == [
+        {'kind': 'set_item_not_hashable', 'loc': [0], 'message': 'Set items should be hashable', 'input_value': [1, 2]}
+    ]
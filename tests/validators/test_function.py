@@ -153,7 +153,7 @@ def test_wrong_mode():
 def test_function_after_data():
     f_kwargs = None
 
-    def f(input_value, **kwargs):
+    def f(input_value, info, **kwargs):
         nonlocal f_kwargs
         f_kwargs = deepcopy(kwargs)
         return input_value + ' Changed'
@@ -175,7 +175,7 @@ def test_function_after_data():
 def test_function_after_config():
     f_kwargs = None
 
-    def f(input_value, **kwargs):
+    def f(input_value, info, **kwargs):
         nonlocal f_kwargs
         f_kwargs = deepcopy(kwargs)
         return input_value + ' Changed'
@@ -199,7 +199,7 @@ def test_function_after_config():
 def test_config_no_model():
     f_kwargs = None
 
-    def f(input_value, **kwargs):
+    def f(input_value, info, **kwargs):
         nonlocal f_kwargs
         f_kwargs = deepcopy(kwargs)
         return input_value + ' Changed'
@@ -241,7 +241,7 @@ def test_validate_assignment():
 
     m = {'field_a': 'test', 'more': 'foobar'}
     assert v.validate_python({'field_a': 'test'}) == m
-    assert v.validate_assignment('field_a', b'abc', m) == {'field_a': 'abc', 'more': 'foobar'}
+    assert v.validate_assignment(m, 'field_a', b'abc') == {'field_a': 'abc', 'more': 'foobar'}
 
 
 def test_function_wrong_sig():
@@ -426,3 +426,153 @@ def test_pydantic_value_error_invalid_type():
 
     with pytest.raises(TypeError, match="argument 'context': 'list' object cannot be converted to 'PyDict'"):
         v.validate_python(42)
+
+
+def test_remaining_time():
+    remaining = []
+
+    def f(input_value, **kwargs):
+        remaining.append(kwargs['remaining_time'])
+        return input_value
+
+    v = SchemaValidator({'type': 'function', 'mode': 'before', 'function': f, 'schema': 'int'})
+    assert v.validate_python(1, timeout=10) == 1
+    assert v.validate_json('2', timeout=0.5) == 2
+    assert 9 < remaining[0] <= 10
+    assert 0 < remaining[1] <= 0.5
+    # once the deadline has passed, the remaining time is zero
+    assert v.validate_python(3, timeout=0) == 3
+    assert remaining[2] == 0
+
+
+def test_remaining_time_not_set():
+    f_kwargs = None
+
+    def f(input_value, **kwargs):
+        nonlocal f_kwargs
+        f_kwargs = kwargs
+        return input_value
+
+    v = SchemaValidator({'type': 'function', 'mode': 'plain', 'function': f})
+    assert v.validate_python(1) == 1
+    assert 'remaining_time' not in f_kwargs
+
+
+def test_remaining_time_wrap():
+    remaining = []
+
+    def f(input_value, *, validator, remaining_time, **kwargs):
+        remaining.append(remaining_time)
+        return validator(input_value)
+
+    inner = {
+        'type': 'function',
+        'mode': 'after',
+        'function': lambda v, remaining_time, **kwargs: remaining_time,
+        'schema': 'str',
+    }
+    v = SchemaValidator({'type': 'function', 'mode': 'wrap', 'function': f, 'schema': inner})
+    inner_remaining = v.validate_python('x', timeout=5)
+    assert 0 < inner_remaining <= remaining[0] <= 5
+
+
+@pytest.mark.parametrize('timeout', [-1, float('inf'), float('nan')])
+def test_invalid_timeout(timeout):
+    v = SchemaValidator({'type': 'function', 'mode': 'plain', 'function': lambda v, **kwargs: v})
+    with pytest.raises(ValueError, match='timeout should be a finite, non-negative number'):
+        v.validate_python(1, timeout=timeout)
+
+
+def test_info_typed_dict():
+    infos = []
+
+    def f(input_value, *, info, **kwargs):
+        infos.append((info.loc, info.field_name, info.mode, dict(info.data)))
+        return input_value
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': {'type': 'list', 'items_schema': {'type': 'function', 'mode': 'plain', 'function': f}}},
+            },
+        }
+    )
+    assert v.validate_python({'a': 1, 'b': ['x', 'y']}) == {'a': 1, 'b': ['x', 'y']}
+    assert infos == [(['b', 0], 'b', 'python', {'a': 1}), (['b', 1], 'b', 'python', {'a': 1})]
+    infos.clear()
+    assert v.validate_json('{"a": 1, "b": ["x"]}') == {'a': 1, 'b': ['x']}
+    assert infos == [(['b', 0], 'b', 'json', {'a': 1})]
+
+
+def test_info_context_config():
+    infos = []
+
+    def f(input_value, *, info, **kwargs):
+        infos.append(info)
+        return input_value
+
+    v = SchemaValidator({'type': 'function', 'mode': 'before', 'function': f, 'schema': 'int'}, {'strict': False})
+    assert v.validate_python('1', context={'foo': 'bar'}) == 1
+    info = infos[0]
+    assert info.context == {'foo': 'bar'}
+    assert info.config == {'strict': False}
+    assert info.data is None
+    assert info.loc == []
+    assert info.field_name is None
+    assert repr(info) == "ValidationInfo(loc=[], field_name=None, mode=\"python\")"
+
+
+def test_info_nested():
+    infos = []
+
+    def f(input_value, *, info, **kwargs):
+        infos.append((info.loc, info.field_name))
+        return input_value
+
+    func_schema = {'type': 'function', 'mode': 'after', 'function': f, 'schema': 'str'}
+    v = SchemaValidator(
+        {
+            'type': 'dict',
+            'keys_schema': 'str',
+            'values_schema': {'type': 'tuple', 'mode': 'positional', 'items_schema': ['int', func_schema]},
+        }
+    )
+    assert v.validate_python({'x': (1, 'a')}) == {'x': (1, 'a')}
+    assert infos == [(['x', 1], None)]
+
+
+def test_info_wrap():
+    infos = []
+
+    def inner(input_value, *, info, **kwargs):
+        infos.append((info.loc, info.field_name))
+        return input_value
+
+    def outer(input_value, *, validator, info, **kwargs):
+        infos.append((info.loc, info.field_name))
+        return validator(input_value)
+
+    inner_schema = {'type': 'function', 'mode': 'plain', 'function': inner}
+    wrap_schema = {'type': 'function', 'mode': 'wrap', 'function': outer, 'schema': inner_schema}
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'a': {'schema': wrap_schema}}})
+    assert v.validate_python({'a': 1}) == {'a': 1}
+    assert infos == [(['a'], 'a'), (['a'], 'a')]
+
+
+def test_info_validate_assignment():
+    infos = []
+
+    def f(input_value, *, info, **kwargs):
+        infos.append((info.loc, info.field_name, dict(info.data)))
+        return input_value
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'a': {'schema': 'int'}, 'b': {'schema': {'type': 'function', 'mode': 'plain', 'function': f}}},
+        }
+    )
+    assert v.validate_assignment({'a': 1, 'b': 'y'}, 'b', 'x') == {'a': 1, 'b': 'x'}
+    assert infos == [(['b'], 'b', {'a': 1, 'b': 'y'})]
@@ -0,0 +1,134 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, ValidatorIterator
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, 2, 3], [1, 2, 3]),
+        ([1, '2', 3.0], [1, 2, 3]),
+        ((1, 2), [1, 2]),
+        ({1, 2}, [1, 2]),
+        ('abc', Err('Input should be iterable [kind=iterable_type,')),
+        ({'a': 1}, Err('Input should be iterable [kind=iterable_type,')),
+        (123, Err('Input should be iterable [kind=iterable_type,')),
+    ],
+)
+def test_generator(input_value, expected):
+    v = SchemaValidator({'type': 'generator', 'items_schema': 'int'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert isinstance(output, ValidatorIterator)
+        assert list(output) == expected
+
+
+def test_generator_json(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'generator', 'items_schema': 'int'})
+    assert list(v.validate_test([1, '2', 3])) == [1, 2, 3]
+    with pytest.raises(ValidationError, match=re.escape('Input should be iterable [kind=iterable_type,')):
+        v.validate_test(1)
+
+
+def test_lazy():
+    consumed = []
+
+    def gen():
+        for i in range(3):
+            consumed.append(i)
+            yield i
+
+    v = SchemaValidator({'type': 'generator', 'items_schema': 'int'})
+    output = v.validate_python(gen())
+    assert consumed == []
+    assert next(output) == 0
+    assert consumed == [0]
+    assert output.index == 1
+    assert list(output) == [1, 2]
+    assert consumed == [0, 1, 2]
+
+
+def test_error_at_iteration():
+    v = SchemaValidator({'type': 'generator', 'items_schema': 'int'})
+    output = v.validate_python(iter([1, 'x', 3]))
+    assert next(output) == 1
+    with pytest.raises(ValidationError) as exc_info:
+        next(output)
+    assert exc_info.value.title == 'generator[int]'
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': [1],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+
+
+def test_error_at_iteration_json():
+    v = SchemaValidator({'type': 'generator', 'items_schema': 'int'})
+    output = v.validate_json('[1, 2, "x"]')
+    with pytest.raises(ValidationError, match=r"2\n  Input should be a valid integer"):
+        list(output)
+
+
+def test_strict_context():
+    def f(input_value, *, context, **kwargs):
+        return f'{input_value} {context}'
+
+    v = SchemaValidator(
+        {'type': 'generator', 'items_schema': {'type': 'function', 'mode': 'after', 'function': f, 'schema': 'str'}}
+    )
+    assert list(v.validate_python(['a'], context='ctx')) == ['a ctx']
+    output = v.validate_python([1], strict=True)
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        next(output)
+
+
+def test_any_items():
+    v = SchemaValidator('generator')
+    items = [1, 'a', None]
+    assert list(v.validate_python(items)) == items
+    assert repr(v.validate_python(items)) == 'ValidatorIterator(index=0, schema=generator[any])'
+
+
+def test_recursive():
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'ref': 'Branch',
+            'items_schema': {'type': 'generator', 'items_schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}},
+        }
+    )
+    output = v.validate_python([[[], []]])
+    assert len(output) == 1
+    assert list(output[0]) == [[], []]
+
+
+def test_replace_field_after_validation():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Node',
+            'fields': {
+                'a': {'schema': 'int'},
+                'children': {
+                    'schema': {'type': 'generator', 'items_schema': {'type': 'recursive-ref', 'schema_ref': 'Node'}}
+                },
+            },
+        }
+    )
+    output = v.validate_python({'a': '1', 'children': [{'a': '2', 'children': []}]})
+    v.replace_field('a', 'str')
+    # an iterator keeps validating with the validator it was created by
+    assert next(output['children'])['a'] == 2
+    output = v.validate_python({'a': '1', 'children': [{'a': '2', 'children': []}]})
+    assert output['a'] == '1'
+    assert next(output['children'])['a'] == '2'
@@ -4,7 +4,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -133,6 +133,9 @@ def test_int_strict(py_and_json: PyAndJson, input_value, expected):
         ({'lt': 0}, 1, Err('Input should be less than 0')),
         ({'multiple_of': 5}, 15, 15),
         ({'multiple_of': 5}, 6, Err('Input should be a multiple of 5')),
+        ({'multiple_of': 5}, -15, -15),
+        ({'multiple_of': 3}, 9_223_372_036_854_775_806, 9_223_372_036_854_775_806),
+        ({'multiple_of': 3}, -9_223_372_036_854_775_807, Err('Input should be a multiple of 3')),
     ],
     ids=repr,
 )
@@ -189,9 +192,15 @@ def test_union_int_simple(py_and_json: PyAndJson):
 
 def test_int_repr():
     v = SchemaValidator({'type': 'int'})
-    assert plain_repr(v) == 'SchemaValidator(name="int",validator=Int(IntValidator{strict:false}))'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="int",validator=Int(IntValidator{'
+        'strict:false,parsing:NumberParsing{'
+        'radix_prefix:false,separators:None,percent:None,python_syntax:None,from_float:Error'
+        '}'
+        '}))'
+    )
     v = SchemaValidator({'type': 'int', 'strict': True})
-    assert plain_repr(v) == 'SchemaValidator(name="int",validator=Int(IntValidator{strict:true}))'
+    assert plain_repr(v).startswith('SchemaValidator(name="int",validator=Int(IntValidator{strict:true,')
     v = SchemaValidator({'type': 'int', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(name="constrained-int",validator=ConstrainedInt(')
 
@@ -234,3 +243,142 @@ def test_int_key(py_and_json: PyAndJson):
     assert v.validate_test({'1': 1, '2': 2}) == {1: 1, 2: 2}
     with pytest.raises(ValidationError, match='Input should be a valid integer'):
         v.validate_test({'1': 1, '2': 2}, strict=True)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('0x1A', 26),
+        ('0X1a', 26),
+        ('-0o17', -15),
+        ('+0b1010', 10),
+        ('42', 42),
+        ('0x', Err('Input should be a valid integer, unable to parse string as an integer')),
+        ('0x-1', Err('Input should be a valid integer, unable to parse string as an integer')),
+        ('0b102', Err('Input should be a valid integer, unable to parse string as an integer')),
+    ],
+)
+def test_int_radix_prefix(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'int', 'allow_radix_prefix': True})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_int_radix_prefix_default():
+    v = SchemaValidator({'type': 'int'})
+    with pytest.raises(ValidationError, match='Input should be a valid integer, unable to parse string as an integer'):
+        v.validate_python('0x1A')
+
+
+def test_int_radix_prefix_config():
+    v = SchemaValidator({'type': 'int', 'gt': 10}, {'int_allow_radix_prefix': True})
+    assert v.validate_python('0xff') == 255
+    assert v.validate_json('"0xff"') == 255
+    with pytest.raises(ValidationError, match='Input should be greater than 10'):
+        v.validate_python('0b1')
+    # strict mode never parses strings
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('0xff', strict=True)
+
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'int'}, {'int_allow_radix_prefix': True})
+    assert v.validate_json('{"0x10": 1}') == {16: 1}
+
+
+def test_int_thousands_separator(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'int', 'thousands_separator': '.', 'decimal_point': ','})
+    assert v.validate_test('1.234') == 1234
+    assert v.validate_test('1.234.567,00') == 1234567
+    with pytest.raises(ValidationError, match='Input should be a valid integer, got a number with a fractional part'):
+        v.validate_test('1.234,5')
+    with pytest.raises(ValidationError, match='Input should be a valid integer, unable to parse string as an integer'):
+        v.validate_test('12.34')
+
+
+@pytest.mark.parametrize(
+    'python_syntax,input_value,expected',
+    [
+        (True, '1_000_000', 1_000_000),
+        (True, ' +42\n', 42),
+        (True, '-1_0', -10),
+        (True, '1__0', Err('Input should be a valid integer, unable to parse string as an integer')),
+        (True, '_1', Err('Input should be a valid integer, unable to parse string as an integer')),
+        (True, '1_', Err('Input should be a valid integer, unable to parse string as an integer')),
+        (False, '+42', Err('Input should be a valid integer, unable to parse string as an integer')),
+        (False, '1_000', Err('Input should be a valid integer, unable to parse string as an integer')),
+        (False, ' 42', Err('Input should be a valid integer, unable to parse string as an integer')),
+        (False, '-42', -42),
+        (None, '+42', 42),
+        (None, '1_000', Err('Input should be a valid integer, unable to parse string as an integer')),
+    ],
+)
+def test_int_python_syntax(py_and_json: PyAndJson, python_syntax, input_value, expected):
+    schema = {'type': 'int'}
+    if python_syntax is not None:
+        schema['python_syntax'] = python_syntax
+    v = py_and_json(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_int_python_syntax_config():
+    v = SchemaValidator({'type': 'int', 'allow_radix_prefix': True}, {'number_python_syntax': True})
+    assert v.validate_python(' 1_000 ') == 1000
+    assert v.validate_python('0x1_0') == 16
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('1_000', strict=True)
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({}, 2.5, Err('Input should be a valid integer, got a number with a fractional part')),
+        ({'from_float': 'error'}, 2.0000000001, Err('Input should be a valid integer, got a number with a fractional')),
+        ({'from_float': 'truncate'}, 2.7, 2),
+        ({'from_float': 'truncate'}, -2.7, -2),
+        ({'from_float': 'truncate'}, '2.7', 2),
+        ({'from_float': 'round-if-within-epsilon'}, 2.0000000001, 2),
+        ({'from_float': 'round-if-within-epsilon'}, 2.9999999999, 3),
+        ({'from_float': 'round-if-within-epsilon'}, -2.0000000001, -2),
+        ({'from_float': 'round-if-within-epsilon'}, 2.001, Err('Input should be a valid integer, got a number with')),
+        ({'from_float': 'round-if-within-epsilon', 'from_float_epsilon': 0.01}, 2.001, 2),
+        ({'from_float': 'truncate'}, 'inf', Err('Input should be a valid integer, got infinity')),
+    ],
+)
+def test_int_from_float(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value, expected):
+    v = py_and_json({'type': 'int', **kwargs})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_int_from_float_config():
+    v = SchemaValidator({'type': 'int', 'lt': 10}, {'int_from_float': 'truncate'})
+    assert v.validate_python(9.9) == 9
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(9.9, strict=True)
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({'from_float': 'round-if-within-epsilon', 'from_float_epsilon': 0.5}, 'from_float_epsilon must be at least 0'),
+        ({'from_float': 'round-if-within-epsilon', 'from_float_epsilon': -1}, 'from_float_epsilon must be at least 0'),
+    ],
+)
+def test_int_from_float_invalid(kwargs, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaValidator({'type': 'int', **kwargs})
+
+
+@pytest.mark.parametrize('multiple_of', [0, -5])
+def test_invalid_multiple_of(multiple_of):
+    with pytest.raises(SchemaError, match='multiple_of must be a positive integer'):
+        SchemaValidator({'type': 'int', 'multiple_of': multiple_of})
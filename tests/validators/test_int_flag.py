@@ -0,0 +1,119 @@
+import enum
+import re
+import sys
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import Err
+
+
+class Perm(enum.IntFlag):
+    R = 4
+    W = 2
+    X = 1
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Perm.R, Perm.R),
+        (Perm.R | Perm.W, Perm.R | Perm.W),
+        (6, Perm.R | Perm.W),
+        (0, Perm(0)),
+        ('6', Perm.R | Perm.W),
+        ('W', Perm.W),
+        (['R', 'W'], Perm.R | Perm.W),
+        (('R', 'X'), Perm.R | Perm.X),
+        (['R', 2, Perm.X], Perm.R | Perm.W | Perm.X),
+        ([], Perm(0)),
+        ('Q', Err("Input should be a member name of Perm: 'R', 'W', 'X' [kind=int_flag_name, input_value='Q',")),
+        (['R', 'Q'], Err("1\n  Input should be a member name of Perm: 'R', 'W', 'X' [kind=int_flag_name,")),
+        (
+            1.5,
+            Err(
+                'Input should be a valid Perm, an integer, a member name or a list of member names '
+                '[kind=int_flag_type, input_value=1.5, input_type=float]'
+            ),
+        ),
+    ],
+)
+def test_int_flag(input_value, expected):
+    v = SchemaValidator({'type': 'int-flag', 'flag_type': Perm})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert isinstance(output, Perm)
+
+
+def test_int_flag_json():
+    v = SchemaValidator({'type': 'int-flag', 'flag_type': Perm})
+    assert v.validate_json('5') == Perm.R | Perm.X
+    assert v.validate_json('"X"') == Perm.X
+    assert v.validate_json('["W", "X"]') == Perm.W | Perm.X
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Perm.W, Perm.W),
+        (3, Perm.W | Perm.X),
+        ('W', Err('Input should be a valid Perm, an integer, a member name or a list of member names')),
+        (['W'], Err('Input should be a valid Perm, an integer, a member name or a list of member names')),
+    ],
+)
+def test_int_flag_strict(input_value, expected):
+    v = SchemaValidator({'type': 'int-flag', 'flag_type': Perm, 'strict': True})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.skipif(sys.version_info < (3, 11), reason='FlagBoundary was added in python 3.11')
+def test_int_flag_boundary():
+    class StrictPerm(enum.IntFlag, boundary=enum.STRICT):
+        R = 4
+        W = 2
+
+    v = SchemaValidator({'type': 'int-flag', 'flag_type': StrictPerm})
+    assert v.validate_python(6) == StrictPerm.R | StrictPerm.W
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(9)
+    errors = exc_info.value.errors()
+    assert len(errors) == 1
+    assert errors[0]['kind'] == 'int_flag_value'
+    assert errors[0]['message'].startswith('Input should be a valid StrictPerm value, ')
+
+
+def test_int_flag_errors():
+    v = SchemaValidator({'type': 'int-flag', 'flag_type': Perm})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['R', 'Q'])
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_flag_name',
+            'loc': [1],
+            'message': "Input should be a member name of Perm: 'R', 'W', 'X'",
+            'input_value': 'Q',
+            'context': {'class_name': 'Perm', 'expected': "'R', 'W', 'X'"},
+        }
+    ]
+
+
+def test_not_int_flag():
+    class Color(enum.Enum):
+        RED = 1
+
+    with pytest.raises(SchemaError, match='flag_type must be a subclass of enum.IntFlag'):
+        SchemaValidator({'type': 'int-flag', 'flag_type': Color})
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'int-flag', 'flag_type': Perm})
+    assert 'int-flag[Perm]' in repr(v)
@@ -1,4 +1,5 @@
 import pytest
+from dirty_equals import HasRepr, IsStr
 
 from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
@@ -100,3 +101,59 @@ def test_repr():
 
     with pytest.raises(ValidationError, match=r'is-instance\[Foo\]\s+Input should be an instance of Foo'):
         v.validate_python('foo')
+
+
+def test_tuple_of_classes():
+    v = SchemaValidator({'type': 'is-instance', 'class_': (Bar, Spam)})
+    bar = Bar()
+    assert v.validate_python(bar) is bar
+    s = Spam()
+    assert v.validate_python(s) is s
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Foo())
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'is_instance_of',
+            'loc': [],
+            'message': 'Input should be an instance of Bar | Spam',
+            'input_value': HasRepr(IsStr(regex='.+Foo object at.+')),
+            'context': {'class': 'Bar | Spam'},
+        }
+    ]
+    assert repr(v).startswith('SchemaValidator(name="is-instance[Bar | Spam]"')
+
+
+@pytest.mark.parametrize(
+    'class_,error',
+    [
+        ((), 'class_ should be a type or a non-empty tuple of types'),
+        ((Foo, 'x'), "'str' object cannot be converted to 'PyType'"),
+    ],
+)
+def test_invalid_tuple(class_, error):
+    with pytest.raises(SchemaError, match=error):
+        SchemaValidator({'type': 'is-instance', 'class_': class_})
+
+
+def test_json_function():
+    def from_json(value):
+        if value == 'bad':
+            raise ValueError('not a Foo')
+        return Foo()
+
+    v = SchemaValidator({'type': 'is-instance', 'class_': Foo, 'json_function': from_json})
+    assert isinstance(v.validate_json('{"a": 1}'), Foo)
+    # python inputs aren't passed to the function
+    with pytest.raises(ValidationError, match='kind=is_instance_of'):
+        v.validate_python({'a': 1})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"bad"')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'value_error',
+            'loc': [],
+            'message': 'Value error, not a Foo',
+            'input_value': 'bad',
+            'context': {'error': 'not a Foo'},
+        }
+    ]
@@ -0,0 +1,73 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+
+class Animal:
+    pass
+
+
+class Dog(Animal):
+    pass
+
+
+class Car:
+    pass
+
+
+def test_is_subclass():
+    v = SchemaValidator({'type': 'is-subclass', 'class_': Animal})
+    assert v.validate_python(Animal) is Animal
+    assert v.validate_python(Dog) is Dog
+    assert v.isinstance_python(Car) is False
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Car)
+
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'is_subclass_of',
+            'loc': [],
+            'message': 'Input should be a subclass of Animal',
+            'input_value': Car,
+            'context': {'class': 'Animal'},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'schema_class,input_val,value',
+    [
+        (Animal, Animal, True),
+        (Animal, Dog, True),
+        (Dog, Animal, False),
+        (Animal, Dog(), False),
+        (Animal, 'Dog', False),
+        (object, int, True),
+        (int, bool, True),
+        (int, int(), False),
+        (type, type, True),
+    ],
+)
+def test_is_subclass_cases(schema_class, input_val, value):
+    v = SchemaValidator({'type': 'is-subclass', 'class_': schema_class})
+    assert v.isinstance_python(input_val) == value
+
+
+def test_json():
+    v = SchemaValidator({'type': 'is-subclass', 'class_': Animal})
+    with pytest.raises(ValidationError, match='kind=is_subclass_of'):
+        v.validate_json('"Animal"')
+
+
+def test_in_typed_dict():
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'pet_type': {'schema': {'type': 'is-subclass', 'class_': Animal}}}}
+    )
+    assert v.validate_python({'pet_type': Dog}) == {'pet_type': Dog}
+    with pytest.raises(ValidationError, match=r'pet_type\n  Input should be a subclass of Animal'):
+        v.validate_python({'pet_type': Car})
+
+
+def test_not_a_class():
+    with pytest.raises(SchemaError, match="'str' object cannot be converted to 'PyType'"):
+        SchemaValidator({'type': 'is-subclass', 'class_': 'not-a-class'})
@@ -0,0 +1,102 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('[1, 2, 3]', [1, 2, 3]),
+        ('{"a": [true, null, 1.5]}', {'a': [True, None, 1.5]}),
+        ('"foobar"', 'foobar'),
+        (' 123 ', 123),
+        ('[1, 2', Err('Invalid JSON: EOF while parsing a list at line 1 column 5 [kind=invalid_json,')),
+        ('', Err('Invalid JSON: EOF while parsing a value at line 1 column 0 [kind=invalid_json,')),
+        (123, Err('JSON input should be string, bytes, bytearray or memoryview [kind=json_type,')),
+        ([1, 2], Err('JSON input should be string, bytes, bytearray or memoryview [kind=json_type,')),
+    ],
+)
+def test_any(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'json'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (b'[1, "2"]', [1, 2]),
+        (bytearray(b'[3]'), [3]),
+        (memoryview(b'[4]'), [4]),
+        (b'[1, "x"]', Err('Input should be a valid integer, unable to parse string as an integer')),
+        (b'\xff', Err('Invalid JSON: expected value at line 1 column 1 [kind=invalid_json,')),
+    ],
+)
+def test_bytes(input_value, expected):
+    v = SchemaValidator({'type': 'json', 'schema': {'type': 'list', 'items_schema': 'int'}})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_inner_schema(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'json',
+            'schema': {
+                'type': 'list',
+                'items_schema': {'type': 'typed-dict', 'fields': {'id': {'schema': 'int'}, 'name': {'schema': 'str'}}},
+            },
+        }
+    )
+    assert v.validate_test('[{"id": 1, "name": "a"}, {"id": "2", "name": "b"}]') == [
+        {'id': 1, 'name': 'a'},
+        {'id': 2, 'name': 'b'},
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('[{"id": "x", "name": "a"}, {"id": 2}]')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': [0, 'id'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+        {'kind': 'missing', 'loc': [1, 'name'], 'message': 'Field required', 'input_value': {'id': 2}},
+    ]
+
+
+def test_strict():
+    # strict mode applies to the values parsed from JSON
+    v = SchemaValidator({'type': 'json', 'schema': {'type': 'list', 'items_schema': 'int'}})
+    assert v.validate_python('[1, 2]', strict=True) == [1, 2]
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('[1, "2"]', strict=True)
+
+
+def test_in_typed_dict():
+    json_schema = {'type': 'json', 'schema': {'type': 'set', 'items_schema': 'str'}}
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'tags': {'schema': json_schema}}})
+    assert v.validate_python({'tags': '["a", "b", "a"]'}) == {'tags': {'a', 'b'}}
+    assert v.validate_json('{"tags": "[\\"a\\"]"}') == {'tags': {'a'}}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'tags': '["a", 1]'})
+    assert exc_info.value.errors() == [
+        {'kind': 'str_type', 'loc': ['tags', 1], 'message': 'Input should be a valid string', 'input_value': 1}
+    ]
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'json', 'schema': 'int'})
+    assert repr(v).startswith('SchemaValidator(name="json[int]"')
+    v = SchemaValidator('json')
+    assert repr(v).startswith('SchemaValidator(name="json[any]"')
@@ -250,3 +250,101 @@ def test_list_from_dict_items(input_value, items_schema, expected):
     output = v.validate_python(input_value)
     assert isinstance(output, list)
     assert output == expected
+
+
+@pytest.mark.parametrize(
+    'str_delimiter,input_value,expected',
+    [
+        (',', '1,2,3', [1, 2, 3]),
+        (',', ' 1 , 2 ', [1, 2]),
+        (',', '', []),
+        (';', '1;2', [1, 2]),
+        (' ', '1  2\t3\n', [1, 2, 3]),
+        (' ', '   ', []),
+        (',', [1, '2'], [1, 2]),
+        (',', '1,,2', Err('Input should be a valid integer, unable to parse string as an integer [kind=int_parsing,')),
+    ],
+)
+def test_list_str_delimiter(py_and_json: PyAndJson, str_delimiter, input_value, expected):
+    v = py_and_json({'type': 'list', 'items_schema': 'int', 'str_delimiter': str_delimiter})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors()[0]['loc'] == [1]
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_list_str_delimiter_strict():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'str_delimiter': ','})
+    assert v.validate_python('1,2') == [1, 2]
+    with pytest.raises(ValidationError, match='Input should be a valid list/array'):
+        v.validate_python('1,2', strict=True)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, 2, 3], [1, 2, 3]),
+        ([], []),
+        (['1', 2, 3, '2'], Err('Items should be unique, this item is a duplicate of item 1 [kind=duplicate_item,')),
+        ([1, 1.0], Err('Items should be unique, this item is a duplicate of item 0 [kind=duplicate_item,')),
+    ],
+)
+def test_list_unique_items(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'list', 'items_schema': 'int', 'unique_items': True})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_list_unique_items_errors():
+    v = SchemaValidator({'type': 'list', 'unique_items': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a', 'b', 'a', 'b'])
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'duplicate_item',
+            'loc': [2],
+            'message': 'Items should be unique, this item is a duplicate of item 0',
+            'input_value': 'a',
+            'context': {'first_index': 0},
+        }
+    ]
+
+
+def test_list_unique_items_unhashable():
+    v = SchemaValidator({'type': 'list', 'unique_items': True})
+    assert v.validate_python([[1], {'a': 1}, [2], 1]) == [[1], {'a': 1}, [2], 1]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([[1], 'x', {'a': 1}, [1]])
+    assert [(e['loc'], e['context']) for e in exc_info.value.errors()] == [([3], {'first_index': 0})]
+
+
+def test_list_fail_fast(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'list', 'items_schema': 'int', 'fail_fast': True})
+    assert v.validate_test([1, '2']) == [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 'x', 'y'])
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', [1])]
+
+
+def test_list_fail_fast_stops_validating():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        return input_value
+
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': {'type': 'function', 'mode': 'after', 'function': f, 'schema': 'int'},
+            'fail_fast': True,
+        }
+    )
+    with pytest.raises(ValidationError):
+        v.validate_python([1, 'x', 3])
+    assert calls == [1]
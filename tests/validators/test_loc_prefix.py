@@ -0,0 +1,58 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+
+def test_loc_prefix(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'loc-prefix',
+            'loc': ['body'],
+            'schema': {'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'str'}}},
+        }
+    )
+    assert v.validate_test({'a': '1', 'b': 'x'}) == {'a': 1, 'b': 'x'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': 'x'})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['body', 'a'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+        {'kind': 'missing', 'loc': ['body', 'b'], 'message': 'Field required', 'input_value': {'a': 'x'}},
+    ]
+
+
+def test_loc_prefix_multiple():
+    v = SchemaValidator(
+        {'type': 'loc-prefix', 'loc': ['query', 0, 'ids'], 'schema': {'type': 'list', 'items_schema': 'int'}}
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x'])
+    assert exc_info.value.errors()[0]['loc'] == ['query', 0, 'ids', 1]
+    assert 'query -> 0 -> ids -> 1\n  Input should be a valid integer' in str(exc_info.value)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('foo')
+    assert exc_info.value.errors()[0]['loc'] == ['query', 0, 'ids']
+
+
+def test_loc_prefix_nested():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'sub': {'schema': {'type': 'loc-prefix', 'loc': ['x'], 'schema': 'int'}}},
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'sub': 'y'})
+    assert exc_info.value.errors()[0]['loc'] == ['sub', 'x']
+
+
+def test_loc_prefix_empty():
+    with pytest.raises(SchemaError, match='loc should have at least one item'):
+        SchemaValidator({'type': 'loc-prefix', 'loc': [], 'schema': 'int'})
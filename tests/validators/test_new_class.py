@@ -1,4 +1,5 @@
 import re
+import weakref
 
 import pytest
 
@@ -487,3 +488,171 @@ def test_revalidate_extra():
     assert m3 is not m2
     assert m3.__dict__ == {'field_a': 'x', 'field_b': 42, 'another': 42.5}
     assert m3.__fields_set__ == {'field_a', 'field_b', 'another'}
+
+
+def test_weakref():
+    class MyModel:
+        field_a: str
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': MyModel,
+            'schema': {'type': 'typed-dict', 'fields': {'field_a': {'schema': 'str'}}},
+            'config': {'require_weakref': True},
+        }
+    )
+    m = v.validate_python({'field_a': 'test'})
+    ref = weakref.ref(m)
+    assert ref() is m
+    del m
+    assert ref() is None
+
+
+def test_weakref_slots():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__', '__weakref__'
+        field_a: str
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': MyModel,
+            'schema': {'type': 'typed-dict', 'return_fields_set': True, 'fields': {'field_a': {'schema': 'str'}}},
+            'config': {'require_weakref': True},
+        }
+    )
+    m = v.validate_python({'field_a': 'test'})
+    assert m.__dict__ == {'field_a': 'test'}
+    assert weakref.ref(m)() is m
+
+
+def test_weakref_missing():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    schema = {'type': 'new-class', 'class_type': MyModel, 'schema': {'type': 'typed-dict', 'fields': {}}}
+    # without `require_weakref` the class is allowed
+    SchemaValidator(schema)
+
+    with pytest.raises(
+        SchemaError, match="MyModel instances don't support weak references, add '__weakref__' to its `__slots__`"
+    ):
+        SchemaValidator({**schema, 'config': {'require_weakref': True}})
+
+
+def test_validate_assignment():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+        field_a: str
+        field_b: int
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {
+                    'field_a': {'schema': 'str'},
+                    'field_b': {'schema': 'int', 'required': False, 'default': 1},
+                },
+            },
+        }
+    )
+    m = v.validate_python({'field_a': 'test'})
+    assert m.__fields_set__ == {'field_a'}
+
+    assert v.validate_assignment(m, 'field_b', '42') is m
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 42}
+    assert m.__fields_set__ == {'field_a', 'field_b'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(m, 'field_b', 'wrong')
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ['field_b'])]
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 42}
+
+
+def test_validate_assignment_nested_model():
+    class Inner:
+        x: int
+
+    class Outer:
+        inner: Inner
+
+    inner_schema = {
+        'type': 'new-class',
+        'class_type': Inner,
+        'schema': {'type': 'typed-dict', 'fields': {'x': {'schema': 'int'}}},
+    }
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': Outer,
+            'schema': {'type': 'typed-dict', 'fields': {'inner': {'schema': inner_schema}}},
+        }
+    )
+    m = v.validate_python({'inner': {'x': 1}})
+    v.validate_assignment(m, 'inner', {'x': '2'})
+    assert isinstance(m.inner, Inner)
+    assert m.inner.x == 2
+    assert not hasattr(m, '__fields_set__')
+
+
+def test_construct():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    calls = []
+
+    def factory():
+        calls.append(1)
+        return []
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {
+                    'a': {'schema': 'int'},
+                    'b': {'schema': 'str', 'default': 'x'},
+                    'c': {'schema': {'type': 'with-default', 'schema': 'list', 'default_factory': factory}},
+                    'd': {
+                        'schema': {'type': 'with-default', 'schema': 'int', 'default': 'y', 'validate_default': True}
+                    },
+                },
+            },
+        }
+    )
+    m = v.construct({'a': 'not-an-int', 'extra': 1})
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'a': 'not-an-int', 'extra': 1, 'b': 'x', 'c': [], 'd': 'y'}
+    assert m.__fields_set__ == {'a', 'extra'}
+    assert calls == [1]
+
+    m = v.construct({'b': 'z'}, {'a', 'b'})
+    assert m.__dict__ == {'b': 'z', 'c': [], 'd': 'y'}
+    assert m.__fields_set__ == {'a', 'b'}
+
+
+def test_construct_no_fields_set():
+    class MyModel:
+        pass
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': MyModel,
+            'schema': {'type': 'typed-dict', 'fields': {'a': {'schema': 'int', 'default': 1}}},
+        }
+    )
+    m = v.construct({})
+    assert m.__dict__ == {'a': 1}
+    assert not hasattr(m, '__fields_set__')
+
+    m = v.construct({}, {'a'})
+    assert m.__fields_set__ == {'a'}
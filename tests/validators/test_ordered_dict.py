@@ -0,0 +1,48 @@
+import re
+from collections import OrderedDict
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+
+def test_ordered_dict(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'ordered-dict', 'keys_schema': 'str', 'values_schema': 'int'})
+    output = v.validate_test({'b': 1, 'a': '2'})
+    assert isinstance(output, OrderedDict)
+    assert output == OrderedDict([('b', 1), ('a', 2)])
+    assert list(output) == ['b', 'a']
+
+
+def test_ordered_dict_from_ordered_dict():
+    v = SchemaValidator('ordered-dict')
+    input_value = OrderedDict([('x', 1), ('y', 2)])
+    output = v.validate_python(input_value)
+    assert output == input_value
+    assert list(output) == ['x', 'y']
+    assert output is not input_value
+
+
+def test_ordered_dict_errors():
+    v = SchemaValidator({'type': 'ordered-dict', 'values_schema': 'int', 'min_items': 1})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['a'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+    with pytest.raises(ValidationError, match=re.escape('Input should have at least 1 item, got 0 items')):
+        v.validate_python({})
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid dictionary [kind=dict_type,')):
+        v.validate_python([('a', 1)])
+
+
+def test_ordered_dict_repr():
+    v = SchemaValidator({'type': 'ordered-dict', 'keys_schema': 'str'})
+    assert repr(v).startswith('SchemaValidator(name="ordered-dict[str,any]", validator=OrderedDict(')
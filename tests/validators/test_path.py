@@ -0,0 +1,157 @@
+import os
+import re
+import sys
+from pathlib import Path, PurePosixPath, PureWindowsPath
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import Err, PyAndJson, plain_repr
+
+
+class PathLike:
+    def __fspath__(self):
+        return '/foo/bar'
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('/foo/bar', Path('/foo/bar')),
+        ('foo', Path('foo')),
+        (Path('/foo/bar'), Path('/foo/bar')),
+        (PurePosixPath('/foo/bar'), Path('/foo/bar')),
+        (PathLike(), Path('/foo/bar')),
+        (123, Err('Input should be a valid path [kind=path_type, input_value=123, input_type=int]')),
+        (b'/foo/bar', Err('Input should be a valid path')),
+    ],
+)
+def test_path(input_value, expected):
+    v = SchemaValidator({'type': 'path'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert isinstance(output, Path)
+
+
+def test_path_json(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'path'})
+    assert v.validate_test('/foo/bar') == Path('/foo/bar')
+    with pytest.raises(ValidationError, match='Input should be a valid path'):
+        v.validate_test(123)
+
+
+def test_path_strict():
+    v = SchemaValidator({'type': 'path', 'strict': True})
+    assert v.validate_python('/foo/bar') == Path('/foo/bar')
+    assert v.validate_python(PurePosixPath('/foo/bar')) == Path('/foo/bar')
+    with pytest.raises(ValidationError, match='Input should be a valid path'):
+        v.validate_python(PathLike())
+
+
+def test_path_instance_unchanged():
+    v = SchemaValidator({'type': 'path'})
+    p = Path('/foo/bar')
+    assert v.validate_python(p) is p
+
+
+@pytest.mark.parametrize('path_type,cls', [('pure-posix', PurePosixPath), ('pure-windows', PureWindowsPath)])
+def test_pure_path(path_type, cls):
+    v = SchemaValidator({'type': 'path', 'path_type': path_type})
+    output = v.validate_python('foo/bar')
+    assert output == cls('foo/bar')
+    assert type(output) is cls
+
+
+def test_fs_checks(tmp_path: Path):
+    file = tmp_path / 'file.txt'
+    file.write_text('hello')
+    missing = tmp_path / 'missing'
+
+    v = SchemaValidator({'type': 'path', 'exists': True})
+    assert v.validate_python(str(file)) == file
+    assert v.validate_python(tmp_path) == tmp_path
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(str(missing))
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'path_not_exists',
+            'loc': [],
+            'message': f'Path "{missing}" does not exist',
+            'input_value': str(missing),
+            'context': {'path': str(missing)},
+        }
+    ]
+
+    v = SchemaValidator({'type': 'path', 'is_file': True})
+    assert v.validate_python(file) == file
+    with pytest.raises(ValidationError, match='does not point to a file'):
+        v.validate_python(tmp_path)
+    with pytest.raises(ValidationError, match='does not exist'):
+        v.validate_python(missing)
+
+    v = SchemaValidator({'type': 'path', 'is_dir': True})
+    assert v.validate_python(tmp_path) == tmp_path
+    with pytest.raises(ValidationError, match='does not point to a directory'):
+        v.validate_python(file)
+
+
+class BytesPathLike:
+    def __fspath__(self):
+        return b'/foo/\xff'
+
+
+class BadPathLike:
+    def __fspath__(self):
+        raise RuntimeError('broken')
+
+
+@pytest.mark.skipif(sys.platform == 'win32', reason='surrogate escapes are only used by os.fsdecode on posix')
+def test_surrogate_escaped_path(tmp_path: Path):
+    name = os.fsdecode(b'\xff')
+    v = SchemaValidator({'type': 'path'})
+    assert v.validate_python(f'/foo/{name}') == Path(f'/foo/{name}')
+    assert v.validate_python(PurePosixPath(f'/foo/{name}')) == Path(f'/foo/{name}')
+    # bytes from `__fspath__` are decoded as `os.fsdecode` does
+    assert v.validate_python(BytesPathLike()) == Path(f'/foo/{name}')
+
+    file = tmp_path / name
+    file.write_text('hello')
+    v = SchemaValidator({'type': 'path', 'is_file': True})
+    assert v.validate_python(str(file)) == file
+    with pytest.raises(ValidationError, match='does not exist'):
+        v.validate_python(str(tmp_path / f'missing{name}'))
+
+
+def test_fspath_error():
+    v = SchemaValidator({'type': 'path'})
+    bad = BadPathLike()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(bad)
+    assert exc_info.value.errors() == [
+        {'kind': 'path_type', 'loc': [], 'message': 'Input should be a valid path', 'input_value': bad}
+    ]
+
+
+@pytest.mark.parametrize(
+    'schema,message',
+    [
+        ({'is_file': True, 'is_dir': True}, 'is_file and is_dir cannot be used together'),
+        (
+            {'path_type': 'pure-posix', 'exists': True},
+            "exists, is_file and is_dir can only be used with path_type 'path'",
+        ),
+    ],
+)
+def test_invalid_schema(schema, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator({'type': 'path', **schema})
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'path', 'path_type': 'pure-windows'})
+    assert plain_repr(v).startswith('SchemaValidator(name="path[PureWindowsPath]"')
@@ -0,0 +1,74 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import Err
+
+
+@pytest.mark.parametrize(
+    'pattern_type,input_value,expected',
+    [
+        (None, 'a+', re.compile('a+')),
+        (None, b'a+', re.compile(b'a+')),
+        (None, re.compile('a+'), re.compile('a+')),
+        (None, re.compile(b'a+'), re.compile(b'a+')),
+        (None, 123, Err('Input should be a str or bytes regular expression pattern [kind=pattern_type,')),
+        (None, '(', Err('Input should be a valid regular expression, missing ), unterminated subpattern')),
+        ('str', 'a+', re.compile('a+')),
+        ('str', re.compile('a+'), re.compile('a+')),
+        ('str', b'a+', Err('Input should be a str regular expression pattern [kind=pattern_type,')),
+        ('str', re.compile(b'a+'), Err('Input should be a str regular expression pattern [kind=pattern_type,')),
+        ('bytes', b'a+', re.compile(b'a+')),
+        ('bytes', re.compile(b'a+'), re.compile(b'a+')),
+        ('bytes', 'a+', Err('Input should be a bytes regular expression pattern [kind=pattern_type,')),
+        ('bytes', re.compile('a+'), Err('Input should be a bytes regular expression pattern [kind=pattern_type,')),
+    ],
+)
+def test_pattern(pattern_type, input_value, expected):
+    schema = {'type': 'pattern'}
+    if pattern_type:
+        schema['pattern_type'] = pattern_type
+    v = SchemaValidator(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_pattern_unchanged():
+    v = SchemaValidator({'type': 'pattern'})
+    pattern = re.compile('a+', re.IGNORECASE)
+    assert v.validate_python(pattern) is pattern
+
+
+def test_pattern_json():
+    assert SchemaValidator({'type': 'pattern'}).validate_json('"^a$"') == re.compile('^a$')
+    assert SchemaValidator({'type': 'pattern', 'pattern_type': 'bytes'}).validate_json('"^a$"') == re.compile(b'^a$')
+
+
+def test_pattern_error_context():
+    v = SchemaValidator({'type': 'pattern'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('[')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'pattern_regex',
+            'loc': [],
+            'message': 'Input should be a valid regular expression, unterminated character set at position 0',
+            'input_value': '[',
+            'context': {'error': 'unterminated character set at position 0'},
+        }
+    ]
+
+
+def test_pattern_repr():
+    v = SchemaValidator({'type': 'pattern', 'pattern_type': 'str'})
+    assert repr(v).startswith('SchemaValidator(name="pattern[str]"')
+
+
+def test_invalid_pattern_type():
+    with pytest.raises(SchemaError, match="Input should be one of: 'str', 'bytes'"):
+        SchemaValidator({'type': 'pattern', 'pattern_type': 'other'})
@@ -0,0 +1,92 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+
+def test_predicate(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'predicate',
+            'schema': 'int',
+            'predicate': lambda x: x % 2 == 0,
+            'error_kind': 'not_even',
+            'message_template': 'Input should be an even number',
+        }
+    )
+    assert v.validate_test('4') == 4
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('3')
+    assert exc_info.value.errors() == [
+        {'kind': 'not_even', 'loc': [], 'message': 'Input should be an even number', 'input_value': 3}
+    ]
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_test('x')
+
+
+def test_context():
+    v = SchemaValidator(
+        {
+            'type': 'predicate',
+            'schema': 'str',
+            'predicate': lambda x: x.startswith('foo'),
+            'error_kind': 'wrong_prefix',
+            'message_template': 'Input should start with "{prefix}"',
+            'context': {'prefix': 'foo'},
+        }
+    )
+    assert v.validate_python('foobar') == 'foobar'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('bar')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'wrong_prefix',
+            'loc': [],
+            'message': 'Input should start with "foo"',
+            'input_value': 'bar',
+            'context': {'prefix': 'foo'},
+        }
+    ]
+
+
+def test_no_schema():
+    v = SchemaValidator(
+        {'type': 'predicate', 'predicate': bool, 'error_kind': 'falsy', 'message_template': 'Input should be truthy'}
+    )
+    assert repr(v).startswith('SchemaValidator(name="predicate[any]"')
+    assert v.validate_python([1]) == [1]
+    with pytest.raises(ValidationError, match='Input should be truthy'):
+        v.validate_python([])
+
+
+def test_in_list():
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': {
+                'type': 'predicate',
+                'schema': 'int',
+                'predicate': lambda x: x > 0,
+                'error_kind': 'not_positive',
+                'message_template': 'Input should be positive',
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, -1, 2, 0])
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('not_positive', [1]), ('not_positive', [3])]
+
+
+def test_predicate_error():
+    def predicate(x):
+        raise RuntimeError('broken')
+
+    v = SchemaValidator({'type': 'predicate', 'predicate': predicate, 'error_kind': 'x', 'message_template': 'x'})
+    with pytest.raises(RuntimeError, match='broken'):
+        v.validate_python(1)
+
+
+def test_missing_error_kind():
+    with pytest.raises(SchemaError, match='error_kind'):
+        SchemaValidator({'type': 'predicate', 'predicate': bool, 'message_template': 'x'})
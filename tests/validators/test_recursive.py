@@ -647,3 +647,72 @@ def test_many_uses_of_ref():
 
     long_input = {'name': 'Anne', 'other_names': [f'p-{i}' for i in range(300)]}
     assert v.validate_python(long_input) == long_input
+
+
+def test_ref_defined_later():
+    # 'other_names' references 'limited-string' before the schema defining it
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'other_names': {
+                    'schema': {
+                        'type': 'list',
+                        'items_schema': {'type': 'recursive-ref', 'schema_ref': 'limited-string'},
+                    }
+                },
+                'name': {'schema': {'type': 'str', 'max_length': 8, 'ref': 'limited-string'}},
+            },
+        }
+    )
+    assert v.validate_python({'name': 'Anne', 'other_names': ['Bob']}) == {'name': 'Anne', 'other_names': ['Bob']}
+    with pytest.raises(ValidationError, match=r'other_names -> 0\s+String should have at most 8 characters'):
+        v.validate_python({'name': 'Anne', 'other_names': ['Daveeeeee']})
+
+
+def test_mutual_recursion_defined_later():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Author',
+            'fields': {
+                'name': {'schema': 'str'},
+                'books': {
+                    'schema': {
+                        'type': 'list',
+                        'items_schema': {
+                            'type': 'typed-dict',
+                            'ref': 'Book',
+                            'fields': {
+                                'title': {'schema': 'str'},
+                                'author': {
+                                    'schema': {
+                                        'type': 'nullable',
+                                        'schema': {'type': 'recursive-ref', 'schema_ref': 'Author'},
+                                    }
+                                },
+                            },
+                        },
+                    }
+                },
+                'favourite': {
+                    'schema': {
+                        'type': 'new-class',
+                        'class_type': Cls,
+                        'schema': {
+                            'type': 'typed-dict',
+                            'fields': {'book': {'schema': {'type': 'recursive-ref', 'schema_ref': 'Book'}}},
+                        },
+                    }
+                },
+            },
+        }
+    )
+    assert repr(v).startswith('SchemaValidator(name="typed-dict"')
+    bob = {'name': 'Bob', 'books': [], 'favourite': {'book': {'title': 'B', 'author': None}}}
+    favourite = {'book': {'title': 'C', 'author': None}}
+    output = v.validate_python({'name': 'Anna', 'books': [{'title': 'A', 'author': bob}], 'favourite': favourite})
+    assert output['favourite'].book == {'title': 'C', 'author': None}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'Anna', 'books': [], 'favourite': {'book': {'title': 'B'}}})
+    assert [(e['loc'], e['kind']) for e in exc_info.value.errors()] == [(['favourite', 'book', 'author'], 'missing')]
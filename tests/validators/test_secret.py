@@ -0,0 +1,125 @@
+import pickle
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, SecretBytes, SecretStr, ValidationError
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('foobar', 'foobar'),
+        ('  foobar  ', 'foobar'),
+        ('foo', Err('String should have at least 5 characters')),
+        (123, Err('Input should be a valid string')),
+    ],
+)
+def test_secret_str(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'secret-str', 'min_length': 5, 'strip_whitespace': True})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert isinstance(output, SecretStr)
+        assert output.get_secret_value() == expected
+
+
+def test_secret_str_from_bytes():
+    v = SchemaValidator({'type': 'secret-str'})
+    assert v.validate_python(b'foobar').get_secret_value() == 'foobar'
+
+
+def test_secret_bytes(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'secret-bytes', 'max_length': 5})
+    output = v.validate_test('foo')
+    assert isinstance(output, SecretBytes)
+    assert output.get_secret_value() == b'foo'
+    with pytest.raises(ValidationError, match='Data should have at most 5 bytes'):
+        v.validate_test('foobar')
+
+
+def test_secret_repr():
+    v = SchemaValidator('secret-str')
+    secret = v.validate_python('hunter2')
+    assert repr(secret) == "SecretStr('**********')"
+    assert str(secret) == '**********'
+    assert len(secret) == 7
+    assert secret == SecretStr('hunter2')
+    assert secret != SecretStr('other')
+    assert secret != 'hunter2'
+    assert hash(secret) == hash('hunter2')
+
+    secret = SchemaValidator('secret-bytes').validate_python(b'hunter2')
+    assert repr(secret) == "SecretBytes(b'**********')"
+    assert str(secret) == '**********'
+
+
+def test_secret_input_masked():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'password': {'schema': {'type': 'secret-str', 'min_length': 10}},
+                'token': {'schema': 'secret-bytes'},
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'password': 'hunter2', 'token': 42})
+    assert 'hunter2' not in str(exc_info.value)
+    assert '42' not in str(exc_info.value)
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'too_short',
+            'loc': ['password'],
+            'message': 'String should have at least 10 characters',
+            'input_value': '**********',
+            'context': {'min_length': 10},
+        },
+        {
+            'kind': 'bytes_type',
+            'loc': ['token'],
+            'message': 'Input should be a valid bytes',
+            'input_value': '**********',
+        },
+    ]
+
+
+def test_secret_revalidate():
+    v = SchemaValidator({'type': 'secret-str', 'max_length': 5})
+    output = v.validate_python(SecretStr('foo'))
+    assert output.get_secret_value() == 'foo'
+    with pytest.raises(ValidationError, match='String should have at most 5 characters'):
+        v.validate_python(SecretStr('foobar'))
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(SecretBytes(b'foo'))
+
+
+def test_secret_pickle():
+    secret = pickle.loads(pickle.dumps(SecretStr('hunter2')))
+    assert secret.get_secret_value() == 'hunter2'
+
+
+def test_secret_error_details_masked():
+    v = SchemaValidator({'type': 'secret-bytes', 'encoding': 'ascii'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('hunter2é')
+    exc = exc_info.value
+    assert 'hunter2' not in str(exc)
+    assert 'é' not in str(exc)
+    assert exc.errors() == [
+        {
+            'kind': 'secret_invalid',
+            'loc': [],
+            'message': 'Input should be a valid secret value',
+            'input_value': '**********',
+        }
+    ]
+    assert 'hunter2' not in repr(exc.errors())
+    assert 'é' not in repr(exc.errors())
+    assert 'hunter2' not in repr(exc.structured_errors())
+    assert 'é' not in repr(exc.structured_errors())
@@ -130,6 +130,10 @@ def test_set_multiple_errors():
         ({'min_items': 3}, {1, 2}, Err('Input should have at least 3 items, got 2 items [kind=too_short,')),
         ({'max_items': 3}, {1, 2, 3}, {1, 2, 3}),
         ({'max_items': 3}, {1, 2, 3, 4}, Err('Input should have at most 3 items, got 4 items [kind=too_long,')),
+        # length constraints apply after duplicates are collapsed
+        ({'max_items': 3}, [1, 2, 3, 3, 2], {1, 2, 3}),
+        ({'min_items': 3}, [1, 2, 2], Err('Input should have at least 3 items, got 2 items [kind=too_short,')),
+        ({'max_items': 1, 'items_schema': 'int'}, ['1', 1, 1.0], {1}),
     ],
 )
 def test_set_kwargs(kwargs: Dict[str, Any], input_value, expected):
@@ -245,3 +249,34 @@ def test_set_from_dict_items(input_value, items_schema, expected):
     output = v.validate_python(input_value)
     assert isinstance(output, set)
     assert output == expected
+
+
+def test_set_str_delimiter(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'set', 'items_schema': 'str', 'str_delimiter': ' '})
+    assert v.validate_test('a b  c a') == {'a', 'b', 'c'}
+    assert v.validate_test(['a', 'b']) == {'a', 'b'}
+    assert v.validate_test('') == set()
+
+
+def test_set_unhashable(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'set'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, [2], 3, {'a': 4}])
+    assert exc_info.value.errors() == [
+        {'kind': 'set_item_not_hashable', 'loc': [1], 'message': 'Set items should be hashable', 'input_value': [2]},
+        {
+            'kind': 'set_item_not_hashable',
+            'loc': [3],
+            'message': 'Set items should be hashable',
+            'input_value': {'a': 4},
+        },
+    ]
+
+
+@pytest.mark.parametrize('schema_type', ['set', 'frozenset'])
+def test_set_fail_fast(py_and_json: PyAndJson, schema_type):
+    v = py_and_json({'type': schema_type, 'items_schema': 'int', 'fail_fast': True})
+    assert v.validate_test([1, '2']) == {1, 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 'x', 'y'])
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', [1])]
@@ -78,11 +78,14 @@ def test_str_not_json(input_value, expected):
         ({'pattern': r'^\d+$'}, '12345', '12345'),
         ({'pattern': r'\d+$'}, 'foobar 123', 'foobar 123'),
         ({'pattern': r'^\d+$'}, '12345a', Err("String should match pattern '^\\d+$' [kind=str_pattern_mismatch")),
-        # strip comes after length check
-        ({'max_length': 5, 'strip_whitespace': True}, '1234  ', Err('String should have at most 5 characters')),
-        # to_upper and strip comes after pattern check
-        ({'to_upper': True, 'pattern': 'abc'}, 'abc', 'ABC'),
-        ({'strip_whitespace': True, 'pattern': r'\d+$'}, 'foobar 123 ', Err("String should match pattern '\\d+$'")),
+        # strip comes before length check
+        ({'max_length': 5, 'strip_whitespace': True}, '1234  ', '1234'),
+        ({'min_length': 5, 'strip_whitespace': True}, ' 1234  ', Err('String should have at least 5 characters')),
+        # to_upper, to_lower and strip come before pattern check
+        ({'to_upper': True, 'pattern': 'abc'}, 'abc', Err("String should match pattern 'abc'")),
+        ({'to_upper': True, 'pattern': 'ABC'}, 'abc', 'ABC'),
+        ({'to_lower': True, 'pattern': '^[a-z]+$'}, 'FooBar', 'foobar'),
+        ({'strip_whitespace': True, 'pattern': r'\d+$'}, 'foobar 123 ', 'foobar 123'),
         ({'min_length': 1}, '🐈 Hello', '🐈 Hello'),
     ],
 )
@@ -177,3 +180,86 @@ def test_regex_error():
             'context': {'pattern': '11'},
         }
     ]
+
+
+@pytest.mark.parametrize('regex_engine', ['python-re', 'auto'])
+def test_python_re_pattern(regex_engine):
+    v = SchemaValidator({'type': 'str', 'pattern': r'^(?!foo)\w+$', 'regex_engine': regex_engine})
+    assert v.validate_python('bar') == 'bar'
+    with pytest.raises(ValidationError, match=r"String should match pattern '\^\(\?!foo\)\\w\+\$'"):
+        v.validate_python('foobar')
+    assert v.resolved_schema()['regex_engine'] == 'python-re'
+
+
+@pytest.mark.parametrize('regex_engine', [None, 'rust-regex', 'auto'])
+def test_rust_regex_engine_selected(regex_engine):
+    schema = {'type': 'str', 'pattern': r'\d+$'}
+    if regex_engine:
+        schema['regex_engine'] = regex_engine
+    v = SchemaValidator(schema)
+    assert v.resolved_schema() == {'type': 'str', 'pattern': r'\d+$', 'regex_engine': 'rust-regex'}
+    assert v.validate_python('foobar 123') == 'foobar 123'
+
+
+def test_python_re_backreference_config():
+    v = SchemaValidator({'type': 'str', 'pattern': r'^(\w)\1$'}, {'str_regex_engine': 'python-re'})
+    assert v.validate_python('aa') == 'aa'
+    with pytest.raises(ValidationError, match='String should match pattern'):
+        v.validate_python('ab')
+    assert v.resolved_schema() == {'type': 'str', 'pattern': r'^(\w)\1$', 'regex_engine': 'python-re'}
+
+
+def test_resolved_schema_without_pattern():
+    assert SchemaValidator({'type': 'str', 'regex_engine': 'auto'}).resolved_schema() == {
+        'type': 'str',
+        'regex_engine': 'auto',
+    }
+
+
+def test_rust_regex_unsupported():
+    with pytest.raises(SchemaError, match='look-around, including look-ahead and look-behind, is not supported') as e:
+        SchemaValidator({'type': 'str', 'pattern': r'^(?!foo)\w+$', 'regex_engine': 'rust-regex'})
+    assert e.value.args[0].endswith(
+        "The pattern uses features not supported by the rust-regex engine, "
+        "set regex_engine to 'python-re' or 'auto' to use Python's re module"
+    )
+
+
+def test_rust_regex_invalid_pattern():
+    # a syntax error rather than an unsupported feature, so there's no hint about other engines
+    with pytest.raises(SchemaError) as e:
+        SchemaValidator({'type': 'str', 'pattern': '(abc', 'regex_engine': 'rust-regex'})
+    assert 'unclosed group' in e.value.args[0]
+    assert 'rust-regex engine' not in e.value.args[0]
+
+
+def test_python_re_invalid():
+    with pytest.raises(SchemaError, match='Invalid regular expression: missing \\), unterminated subpattern'):
+        SchemaValidator({'type': 'str', 'pattern': '(abc', 'regex_engine': 'auto'})
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        {'type': 'str', 'min_length': 1, 'max_length': 100},
+        {'type': 'str', 'strip_whitespace': True},
+        {'type': 'str', 'pattern': r'^\w+$'},
+        {'type': 'str', 'pattern': r'^(?!foo)\w+$', 'regex_engine': 'python-re'},
+    ],
+)
+def test_constrained_str_unmodified_not_copied(schema):
+    v = SchemaValidator(schema)
+    input_value = ''.join(['x'] * 50)
+    assert v.validate_python(input_value) is input_value
+
+
+def test_python_re_transformed():
+    v = SchemaValidator({'type': 'str', 'pattern': r'^(?!FOO)\w+$', 'regex_engine': 'python-re', 'to_upper': True})
+    assert v.validate_python('bar') == 'BAR'
+    with pytest.raises(ValidationError, match='String should match pattern'):
+        v.validate_python('foobar')
+
+    v = SchemaValidator({'type': 'str', 'pattern': r'^\w+$', 'regex_engine': 'python-re', 'strip_whitespace': True})
+    assert v.validate_json('"  bar  "') == 'bar'
+    with pytest.raises(ValidationError, match='String should match pattern'):
+        v.validate_json('" b a r "')
@@ -428,3 +428,109 @@ def test_frozenset_from_dict_items(input_value, items_schema, expected):
     output = v.validate_python(input_value)
     assert isinstance(output, tuple)
     assert output == expected
+
+
+def test_positional_variadic_tail(py_and_json: PyAndJson):
+    # equivalent to `Tuple[int, str, *Tuple[float, ...]]`
+    v = py_and_json({'type': 'tuple', 'mode': 'positional', 'items_schema': ['int', 'str'], 'extra_schema': 'float'})
+    assert v.validate_test([1, 'a']) == (1, 'a')
+    assert v.validate_test(['1', 'a', 1.5, '2']) == (1, 'a', 1.5, 2.0)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(['x', 'a', 1, 'y', 3, 'z'])
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': [0],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+        {
+            'kind': 'float_parsing',
+            'loc': [3],
+            'message': 'Input should be a valid number, unable to parse string as an number',
+            'input_value': 'y',
+        },
+        {
+            'kind': 'float_parsing',
+            'loc': [5],
+            'message': 'Input should be a valid number, unable to parse string as an number',
+            'input_value': 'z',
+        },
+    ]
+
+
+def test_positional_missing_and_invalid(py_and_json: PyAndJson):
+    v = py_and_json(
+        {'type': 'tuple', 'mode': 'positional', 'items_schema': ['int', 'str', 'str'], 'extra_schema': 'int'}
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(['x'])
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': [0],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+        {'kind': 'missing', 'loc': [1], 'message': 'Field required', 'input_value': ['x']},
+        {'kind': 'missing', 'loc': [2], 'message': 'Field required', 'input_value': ['x']},
+    ]
+
+
+def test_positional_variadic_tail_name():
+    v = SchemaValidator(
+        {'type': 'tuple', 'mode': 'positional', 'items_schema': ['int', 'str'], 'extra_schema': 'float'}
+    )
+    assert repr(v).startswith(
+        'SchemaValidator(name="tuple[int, str, *tuple[float, ...]]", validator=TuplePositional('
+    )
+
+
+def test_positional_variadic_tail_recursive():
+    v = SchemaValidator(
+        {
+            'type': 'tuple',
+            'mode': 'positional',
+            'ref': 'Branch',
+            'items_schema': ['str'],
+            'extra_schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'},
+        }
+    )
+    assert v.validate_python(('a', ('b',), ('c', ('d',)))) == ('a', ('b',), ('c', ('d',)))
+
+
+def test_variable_unique_items(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'tuple', 'items_schema': 'int', 'unique_items': True})
+    assert v.validate_test([1, 2, 3]) == (1, 2, 3)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 2, '1'])
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('duplicate_item', [2])]
+
+
+def test_positional_unique_items():
+    v = SchemaValidator(
+        {
+            'type': 'tuple',
+            'mode': 'positional',
+            'items_schema': ['int', 'str'],
+            'extra_schema': 'int',
+            'unique_items': True,
+        }
+    )
+    assert v.validate_python((1, '1', 2)) == (1, '1', 2)
+    with pytest.raises(ValidationError, match=r'2\n +Items should be unique, this item is a duplicate of item 0'):
+        v.validate_python((1, '1', '1'))
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        {'type': 'tuple', 'items_schema': 'int', 'fail_fast': True},
+        {'type': 'tuple', 'mode': 'positional', 'items_schema': ['int', 'int', 'int', 'int'], 'fail_fast': True},
+    ],
+)
+def test_tuple_fail_fast(py_and_json: PyAndJson, schema):
+    v = py_and_json(schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 'x', 'y'])
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', [1])]
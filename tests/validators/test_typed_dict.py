@@ -1,4 +1,6 @@
+import copy
 import math
+import pickle
 import re
 import sys
 from dataclasses import dataclass
@@ -155,6 +157,46 @@ def test_ignore_extra():
     )
 
 
+def test_fields_set_defaults():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'return_fields_set': True,
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': 'int', 'default': 2},
+                'c': {'schema': {'type': 'with-default', 'schema': 'int', 'default': 3}, 'required': False},
+                'd': {'schema': 'int', 'default': 4, 'on_error': 'fallback_on_default'},
+                'e': {'schema': 'int', 'required': False},
+            },
+        }
+    )
+    # fields filled from defaults aren't in fields set, including when an invalid value is replaced by the default
+    assert v.validate_python({'a': 1, 'd': 'x'}) == ({'a': 1, 'b': 2, 'c': 3, 'd': 4}, {'a'})
+    assert v.validate_json('{"a": 1, "b": 2, "c": 3, "e": 5}') == (
+        {'a': 1, 'b': 2, 'c': 3, 'd': 4, 'e': 5},
+        {'a', 'b', 'c', 'e'},
+    )
+
+    class Model:
+        __slots__ = '__dict__', '__fields_set__'
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': Model,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'int', 'default': 2}},
+            },
+        }
+    )
+    m = v.validate_python({'a': 1})
+    assert m.__dict__ == {'a': 1, 'b': 2}
+    assert m.__fields_set__ == {'a'}
+
+
 def test_forbid_extra():
     v = SchemaValidator(
         {
@@ -246,7 +288,7 @@ def test_validate_assignment():
 
     assert v.validate_python({'field_a': 'test'}) == ({'field_a': 'test'}, {'field_a'})
 
-    assert v.validate_assignment('field_a', b'abc', {'field_a': 'test'}) == ({'field_a': 'abc'}, {'field_a'})
+    assert v.validate_assignment({'field_a': 'test'}, 'field_a', b'abc') == ({'field_a': 'abc'}, {'field_a'})
 
 
 def test_validate_assignment_strict_field():
@@ -261,7 +303,7 @@ def test_validate_assignment_strict_field():
     assert v.validate_python({'field_a': 'test'}) == ({'field_a': 'test'}, {'field_a'})
 
     with pytest.raises(ValidationError) as exc_info:
-        v.validate_assignment('field_a', b'abc', {'field_a': 'test'})
+        v.validate_assignment({'field_a': 'test'}, 'field_a', b'abc')
     assert exc_info.value.errors() == [
         {'input_value': b'abc', 'kind': 'str_type', 'loc': ['field_a'], 'message': 'Input should be a valid string'}
     ]
@@ -301,7 +343,7 @@ def test_validate_assignment_functions():
     assert calls == ['func_a', 'func_b']
     calls.clear()
 
-    assert v.validate_assignment('field_a', 'new-val', {'field_a': 'testtest', 'field_b': 6}) == (
+    assert v.validate_assignment({'field_a': 'testtest', 'field_b': 6}, 'field_a', 'new-val') == (
         {'field_a': 'new-valnew-val', 'field_b': 6},
         {'field_a'},
     )
@@ -316,7 +358,7 @@ def test_validate_assignment_ignore_extra():
     assert v.validate_python({'field_a': 'test'}) == ({'field_a': 'test'}, {'field_a'})
 
     with pytest.raises(ValidationError) as exc_info:
-        v.validate_assignment('other_field', 456, {'field_a': 'test'})
+        v.validate_assignment({'field_a': 'test'}, 'other_field', 456)
 
     assert exc_info.value.errors() == [
         {
@@ -335,7 +377,7 @@ def test_validate_assignment_allow_extra():
 
     assert v.validate_python({'field_a': 'test'}) == {'field_a': 'test'}
 
-    assert v.validate_assignment('other_field', 456, {'field_a': 'test'}) == {'field_a': 'test', 'other_field': 456}
+    assert v.validate_assignment({'field_a': 'test'}, 'other_field', 456) == {'field_a': 'test', 'other_field': 456}
 
 
 def test_validate_assignment_allow_extra_validate():
@@ -348,10 +390,10 @@ def test_validate_assignment_allow_extra_validate():
         }
     )
 
-    assert v.validate_assignment('other_field', '456', {'field_a': 'test'}) == {'field_a': 'test', 'other_field': 456}
+    assert v.validate_assignment({'field_a': 'test'}, 'other_field', '456') == {'field_a': 'test', 'other_field': 456}
 
     with pytest.raises(ValidationError) as exc_info:
-        assert v.validate_assignment('other_field', 'xyz', {'field_a': 'test'})
+        assert v.validate_assignment({'field_a': 'test'}, 'other_field', 'xyz')
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
@@ -370,10 +412,10 @@ def test_validate_assignment_with_strict():
     r = v.validate_python({'x': 'a', 'y': '123'})
     assert r == {'x': 'a', 'y': 123}
 
-    assert v.validate_assignment('y', '124', r) == {'x': 'a', 'y': 124}
+    assert v.validate_assignment(r, 'y', '124') == {'x': 'a', 'y': 124}
 
     with pytest.raises(ValidationError) as exc_info:
-        v.validate_assignment('y', '124', r, True)
+        v.validate_assignment(r, 'y', '124', True)
 
     assert exc_info.value.errors() == [
         {'kind': 'int_type', 'loc': ['y'], 'message': 'Input should be a valid integer', 'input_value': '124'}
@@ -639,6 +681,9 @@ def test_paths_allow_by_name(py_and_json: PyAndJson, input_value):
         ({'alias': [123]}, "TypeError: 'int' object cannot be converted to 'PyList'"),
         ({'alias': [[[]]]}, 'Input should be a valid string'),
         ({'alias': [[1, 'foo']]}, 'TypeError: The first item in an alias path should be a string'),
+        ({'alias': [[{'regex': 'x'}, 'foo']]}, 'TypeError: The first item in an alias path should be a string'),
+        ({'alias': ['foo', {'pattern': 'x'}]}, r'-> typed-dict -> regex\n  Field required'),
+        ({'alias': ['foo', {'regex': '('}]}, 'Invalid regex in alias path: regex parse error'),
     ],
     ids=repr,
 )
@@ -647,6 +692,76 @@ def test_alias_build_error(alias_schema, error):
         SchemaValidator({'type': 'typed-dict', 'fields': {'field_a': {'schema': 'int', **alias_schema}}})
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'user': 'user:1234'}, {'user_id': 1234}),
+        ({'user': 'user:x'}, Err(r'user_id\n +Field required \[kind=missing,')),
+        ({'user': 1234}, Err(r'user_id\n +Field required \[kind=missing,')),
+        ({'users': ['admin:1', 'user:42']}, {'user_id': 42}),
+    ],
+    ids=repr,
+)
+def test_alias_path_regex(input_value, expected):
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'user_id': {
+                    'alias': [['user', {'regex': r'^user:(\d+)$'}], ['users', 1, {'regex': r':(\d+)'}]],
+                    'schema': 'int',
+                }
+            },
+        }
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=expected.message):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_alias_path_regex_no_group():
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'code': {'alias': ['ref', {'regex': r'[A-Z]{3}'}], 'schema': 'str'}}}
+    )
+    assert v.validate_python({'ref': 'ticket ABC-123'}) == {'code': 'ABC'}
+
+
+def test_alias_path_regex_from_attributes():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'user_id': {'alias': ['user', {'regex': r'user:(\d+)'}], 'schema': 'int'}},
+            'from_attributes': True,
+        }
+    )
+    assert v.validate_python(Cls(user='user:99')) == {'user_id': 99}
+
+
+def test_alias_path_regex_multiple_values():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'user_id': {'alias': [['user', {'regex': r'user:(\d+)'}], ['id']], 'schema': 'int'}},
+            'forbid_multiple_values': True,
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'user': 'user:1', 'id': 2})
+    assert exc_info.value.errors()[0]['message'] == (
+        "Multiple values provided for the field, via 'user'./user:(\\d+)/, 'id'"
+    )
+
+
+def test_alias_path_regex_json():
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'user_id': {'alias': ['user', {'regex': r'user:(\d+)'}], 'schema': 'int'}}}
+    )
+    with pytest.raises(ValidationError, match='Regex alias path items are only supported for python input'):
+        v.validate_json('{"user": "user:1"}')
+
+
 def test_empty_model():
     v = SchemaValidator({'type': 'typed-dict', 'fields': {}, 'return_fields_set': True})
     assert v.validate_python({}) == ({}, set())
@@ -1034,6 +1149,69 @@ def test_from_attributes_path_error():
     ]
 
 
+class LazyLoadError(Exception):
+    pass
+
+
+class BrokenAttributes:
+    a = 1
+
+    @property
+    def b(self):
+        raise LazyLoadError('not loaded')
+
+    @property
+    def c(self):
+        raise KeyError('c')
+
+
+@pytest.mark.parametrize(
+    'schema_extra,config',
+    [
+        ({'attribute_missing_errors': [LazyLoadError, KeyError]}, None),
+        ({}, {'typed_dict_attribute_missing_errors': [LazyLoadError, KeyError]}),
+    ],
+)
+def test_from_attributes_missing_errors(schema_extra, config):
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': 'int', 'default': 2},
+                'c': {'schema': 'int', 'alias': [['c'], ['a']]},
+            },
+            'from_attributes': True,
+            **schema_extra,
+        },
+        config,
+    )
+    # 'c' raises KeyError so the second alias path is used
+    assert v.validate_python(BrokenAttributes()) == {'a': 1, 'b': 2, 'c': 1}
+
+
+def test_from_attributes_missing_errors_other_error():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'int'}, 'c': {'schema': 'int', 'required': False}},
+            'from_attributes': True,
+            'attribute_missing_errors': [LazyLoadError],
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(BrokenAttributes())
+    assert [(e['kind'], e['loc'], e['message']) for e in exc_info.value.errors()] == [
+        ('missing', ['b'], 'Field required'),
+        ('get_attribute_error', ['c'], "Error extracting attribute: KeyError: 'c'"),
+    ]
+
+
+def test_from_attributes_missing_errors_invalid():
+    with pytest.raises(SchemaError, match='attribute_missing_errors should only contain exception types'):
+        SchemaValidator({'type': 'typed-dict', 'fields': {}, 'attribute_missing_errors': [int]})
+
+
 def test_alias_extra(py_and_json: PyAndJson):
     v = py_and_json(
         {
@@ -1270,10 +1448,598 @@ def test_frozen_field():
     )
     r1 = v.validate_python({'name': 'Samuel', 'age': '36'})
     assert r1 == {'name': 'Samuel', 'age': 36, 'is_developer': True}
-    r2 = v.validate_assignment('age', '35', r1)
+    r2 = v.validate_assignment(r1, 'age', '35')
     assert r2 == {'name': 'Samuel', 'age': 35, 'is_developer': True}
     with pytest.raises(ValidationError) as exc_info:
-        v.validate_assignment('is_developer', False, r2)
+        v.validate_assignment(r2, 'is_developer', False)
     assert exc_info.value.errors() == [
         {'kind': 'frozen', 'loc': ['is_developer'], 'message': 'Field is frozen', 'input_value': False}
     ]
+
+
+def test_validate_with_coercions():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': 'bool'},
+                'c': {'schema': 'str'},
+                'd': {'schema': {'type': 'typed-dict', 'fields': {'x': {'schema': 'float'}, 'y': {'schema': 'int'}}}},
+            },
+        }
+    )
+    assert v.validate_python_with_coercions({'a': 1, 'b': True, 'c': 'x', 'd': {'x': 1.5, 'y': 2}}) == (
+        {'a': 1, 'b': True, 'c': 'x', 'd': {'x': 1.5, 'y': 2}},
+        [],
+    )
+    output, coercions = v.validate_python_with_coercions({'a': '01', 'b': 'yes', 'c': b'x', 'd': {'x': 3, 'y': 2}})
+    assert output == {'a': 1, 'b': True, 'c': 'x', 'd': {'x': 3.0, 'y': 2}}
+    assert coercions == [
+        {'loc': ['a'], 'from_type': 'str', 'to_type': 'int', 'lossy': True},
+        {'loc': ['b'], 'from_type': 'str', 'to_type': 'bool', 'lossy': True},
+        # str can't be converted back to bytes without an encoding
+        {'loc': ['c'], 'from_type': 'bytes', 'to_type': 'str', 'lossy': None},
+        {'loc': ['d', 'x'], 'from_type': 'int', 'to_type': 'float', 'lossy': False},
+    ]
+
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python_with_coercions({'a': 'x', 'b': True, 'c': 'x', 'd': {'x': 1.5, 'y': 2}})
+
+
+def test_coercions_in_containers():
+    inner = {'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}}}
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'list': {'schema': {'type': 'list', 'items_schema': inner}},
+                'tuple': {'schema': {'type': 'tuple', 'mode': 'positional', 'items_schema': [inner]}},
+                'dict': {'schema': {'type': 'dict', 'keys_schema': 'str', 'values_schema': inner}},
+            },
+        }
+    )
+    _, coercions = v.validate_python_with_coercions(
+        {'list': [{'a': 1}, {'a': '2'}], 'tuple': ({'a': '3'},), 'dict': {'k': {'a': '4'}}}
+    )
+    assert [c['loc'] for c in coercions] == [['list', 1, 'a'], ['tuple', 0, 'a'], ['dict', 'k', 'a']]
+
+
+def test_coercions_in_arguments():
+    inner = {'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}}}
+    v = SchemaValidator(
+        {
+            'type': 'arguments',
+            'arguments_schema': [
+                {'name': 'x', 'mode': 'positional_only', 'schema': inner},
+                {'name': 'y', 'mode': 'keyword_only', 'schema': inner},
+            ],
+        }
+    )
+    _, coercions = v.validate_python_with_coercions((({'a': '1'},), {'y': {'a': '2'}}))
+    assert [c['loc'] for c in coercions] == [[0, 'a'], ['y', 'a']]
+
+
+def test_coercions_discarded_for_failed_union_choice():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'x': {
+                    'schema': {
+                        'type': 'union',
+                        'choices': [
+                            {'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'int'}}},
+                            {'type': 'typed-dict', 'fields': {'a': {'schema': 'str'}, 'b': {'schema': 'str'}}},
+                        ],
+                    }
+                }
+            },
+        }
+    )
+    output, coercions = v.validate_python_with_coercions({'x': {'a': '1', 'b': 'x'}})
+    assert output == {'x': {'a': '1', 'b': 'x'}}
+    assert coercions == []
+
+
+def test_coercions_lossy_not_checked_for_custom_types():
+    calls = []
+
+    class MyInt(int):
+        def __new__(cls, *args):
+            calls.append(args)
+            return super().__new__(cls, *args)
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'a': {'schema': {'type': 'function', 'mode': 'plain', 'function': lambda v, **kwargs: int(v)}}},
+        }
+    )
+    output, coercions = v.validate_python_with_coercions({'a': MyInt(3)})
+    assert output == {'a': 3}
+    assert len(coercions) == 1
+    assert coercions[0]['from_type'].endswith('MyInt')
+    assert coercions[0]['lossy'] is None
+    # only the call above, the coercion record didn't call `MyInt(3)`
+    assert calls == [(3,)]
+
+
+def test_field_description_in_errors():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'error_descriptions': True,
+            'fields': {
+                'age': {'schema': 'int', 'description': 'age in years'},
+                'name': {'schema': 'str'},
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'age': 'x'})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['age'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer (age in years)',
+            'input_value': 'x',
+        },
+        {'kind': 'missing', 'loc': ['name'], 'message': 'Field required', 'input_value': {'age': 'x'}},
+    ]
+    assert 'unable to parse string as an integer (age in years) [kind=int_parsing' in str(exc_info.value)
+
+    with pytest.raises(ValidationError, match=r'Field required \(age in years\) \[kind=missing'):
+        v.validate_python({'name': 'x'})
+
+
+def test_field_description_nested():
+    inner_schema = {
+        'type': 'typed-dict',
+        'fields': {'a': {'schema': 'int', 'description': 'inner'}, 'b': {'schema': 'int'}},
+    }
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'sub': {'schema': inner_schema, 'description': 'outer'}}},
+        {'typed_dict_error_descriptions': True},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'sub': {'a': 'x'}})
+    # the description of the innermost field is used
+    assert [(e['loc'], e['message']) for e in exc_info.value.errors()] == [
+        (['sub', 'a'], 'Input should be a valid integer, unable to parse string as an integer (inner)'),
+        (['sub', 'b'], 'Field required (outer)'),
+    ]
+
+
+def test_field_description_not_in_errors():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'age': {'schema': 'int', 'description': 'age in years'}}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'age': 'x'})
+    message = exc_info.value.errors()[0]['message']
+    assert message == 'Input should be a valid integer, unable to parse string as an integer'
+    assert v.dump_schema()['fields']['age'] == {'schema': {'type': 'int'}, 'description': 'age in years'}
+
+
+def test_field_description_assignment():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'error_descriptions': True,
+            'fields': {'age': {'schema': 'int', 'description': 'age in years'}},
+        }
+    )
+    with pytest.raises(ValidationError, match=r'unable to parse string as an integer \(age in years\)'):
+        v.validate_assignment({'age': 1}, 'age', 'x')
+
+
+def test_forbid_multiple_values(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'populate_by_name': True,
+            'forbid_multiple_values': True,
+            'fields': {'field_a': {'alias': 'FieldA', 'schema': 'int'}, 'field_b': {'schema': 'int'}},
+        }
+    )
+    assert v.validate_test({'FieldA': '1', 'field_b': 2}) == {'field_a': 1, 'field_b': 2}
+    assert v.validate_test({'field_a': '1', 'field_b': 2}) == {'field_a': 1, 'field_b': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'FieldA': '1', 'field_a': '2', 'field_b': 'x'})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'multiple_values',
+            'loc': ['field_a'],
+            'message': "Multiple values provided for the field, via 'FieldA', 'field_a'",
+            'input_value': {'FieldA': '1', 'field_a': '2', 'field_b': 'x'},
+            'context': {'keys': "'FieldA', 'field_a'"},
+        },
+        {
+            'kind': 'int_parsing',
+            'loc': ['field_b'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+    ]
+
+
+def test_forbid_multiple_values_paths():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'extra_behavior': 'forbid',
+            'fields': {'field_a': {'alias': [['foo', 'bar'], ['spam']], 'schema': 'int'}},
+        },
+        {'typed_dict_forbid_multiple_values': True},
+    )
+    assert v.validate_python({'foo': {'bar': 1}}) == {'field_a': 1}
+    assert v.validate_python({'spam': 2}) == {'field_a': 2}
+    # 'foo' doesn't provide a value for the field here, so only 'spam' is used
+    with pytest.raises(ValidationError, match=r'foo\n +Extra inputs are not permitted'):
+        v.validate_python({'foo': 1, 'spam': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'foo': {'bar': 1}, 'spam': 2})
+    # both keys count as used, so there's no extra_forbidden error
+    assert [(e['kind'], e['loc'], e['message']) for e in exc_info.value.errors()] == [
+        ('multiple_values', ['field_a'], "Multiple values provided for the field, via 'foo'.'bar', 'spam'")
+    ]
+
+
+def test_multiple_values_allowed_by_default():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'populate_by_name': True,
+            'fields': {'field_a': {'alias': 'FieldA', 'schema': 'int'}},
+        }
+    )
+    assert v.validate_python({'FieldA': 1, 'field_a': 2}) == {'field_a': 1}
+
+
+def test_nested_delimiter():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'extra_behavior': 'forbid',
+            'fields': {
+                'debug': {'alias': 'DEBUG', 'schema': 'bool'},
+                'db': {
+                    'alias': 'DB',
+                    'schema': {
+                        'type': 'typed-dict',
+                        'fields': {
+                            'host': {'alias': 'HOST', 'schema': 'str'},
+                            'port': {'alias': 'PORT', 'schema': 'int', 'default': 5432},
+                            'auth': {
+                                'alias': 'AUTH',
+                                'schema': {
+                                    'type': 'typed-dict',
+                                    'fields': {'user': {'alias': 'USER', 'schema': 'str'}},
+                                },
+                            },
+                        },
+                    },
+                },
+            },
+        },
+        {'typed_dict_nested_delimiter': '__'},
+    )
+    assert v.validate_python({'DEBUG': 'true', 'DB__HOST': 'localhost', 'DB__AUTH__USER': 'admin'}) == {
+        'debug': True,
+        'db': {'host': 'localhost', 'port': 5432, 'auth': {'user': 'admin'}},
+    }
+    # values given as a nested dict are used as is
+    assert v.validate_python({'DEBUG': 'false', 'DB': {'HOST': 'example.com', 'PORT': '1', 'AUTH': {'USER': 'x'}}}) == {
+        'debug': False,
+        'db': {'host': 'example.com', 'port': 1, 'auth': {'user': 'x'}},
+    }
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'DEBUG': 'true', 'DB__PORT': 'x', 'DB__AUTH__USER': 'admin', 'OTHER__THING': 1})
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [
+        ('missing', ['db', 'host']),
+        ('int_parsing', ['db', 'port']),
+        ('extra_forbidden', ['OTHER__THING']),
+    ]
+
+
+def test_nested_delimiter_schema():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'nested_delimiter': '.',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': {'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'}},
+            },
+        }
+    )
+    assert v.validate_python({'a': 1, 'b.x': '2', 'b.y': 3}) == {'a': 1, 'b': {'x': 2, 'y': 3}}
+    assert v.validate_python({'a': 1, 'b': {'x': 2}, 'b.y': 3}) == {'a': 1, 'b': {'x': 2}}
+    # only python dicts are nested
+    with pytest.raises(ValidationError, match=r'b\n +Field required'):
+        v.validate_json('{"a": 1, "b.x": 2}')
+
+
+def test_nested_delimiter_empty():
+    with pytest.raises(SchemaError, match='nested_delimiter cannot be an empty string'):
+        SchemaValidator({'type': 'typed-dict', 'nested_delimiter': '', 'fields': {}})
+
+
+def test_construct():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Branch',
+            'fields': {
+                'name': {'schema': 'str', 'default': 'root'},
+                'sub': {
+                    'schema': {
+                        'type': 'with-default',
+                        'schema': {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}},
+                        'default': None,
+                    }
+                },
+            },
+        }
+    )
+    assert v.construct({'name': 123}) == {'name': 123, 'sub': None}
+    assert v.construct({}) == {'name': 'root', 'sub': None}
+
+
+def test_construct_fields_set():
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'return_fields_set': True, 'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'int'}}}
+    )
+    assert v.construct({'a': 'x'}) == ({'a': 'x'}, {'a'})
+
+
+def test_construct_not_supported():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    with pytest.raises(TypeError, match='construct is not supported by "list\\[int\\]" validators'):
+        v.construct({})
+
+
+def test_title():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'title': 'UserProfile',
+            'fields': {
+                'name': {'schema': 'str'},
+                'address': {
+                    'schema': {
+                        'type': 'union',
+                        'choices': [
+                            {'type': 'typed-dict', 'title': 'Address', 'fields': {'city': {'schema': 'str'}}},
+                            'str',
+                        ],
+                    }
+                },
+            },
+        }
+    )
+    assert repr(v).startswith('SchemaValidator(name="UserProfile"')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'address': {}})
+    assert exc_info.value.title == 'UserProfile'
+    assert str(exc_info.value).startswith('3 validation errors for UserProfile\n')
+    assert [e['loc'] for e in exc_info.value.errors()] == [['name'], ['address', 'Address', 'city'], ['address', 'str']]
+
+    v = SchemaValidator({'type': 'typed-dict', 'title': 'UserProfile', 'fields': {}}, {'title': 'Override'})
+    with pytest.raises(ValidationError, match='^1 validation error for Override\n'):
+        v.validate_python(1)
+
+
+def test_wrap_handler_title():
+    def f(input_value, validator, **kwargs):
+        try:
+            return validator(input_value)
+        except ValidationError as e:
+            return e.title
+
+    v = SchemaValidator(
+        {
+            'type': 'function',
+            'mode': 'wrap',
+            'function': f,
+            'schema': {'type': 'typed-dict', 'title': 'Inner', 'fields': {'a': {'schema': 'int'}}},
+        }
+    )
+    assert v.validate_python({}) == 'Inner'
+
+
+def test_get_default_value():
+    calls = []
+
+    def factory():
+        calls.append(1)
+        return ['1']
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Branch',
+            'fields': {
+                'name': {'schema': 'str', 'default': 'root'},
+                'count': {'schema': 'int', 'default': '1'},
+                'tags': {'schema': {'type': 'list', 'items_schema': 'int'}, 'default_factory': factory},
+                'required': {'schema': 'int'},
+                'sub': {
+                    'schema': {
+                        'type': 'with-default',
+                        'schema': {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}},
+                        'default': None,
+                    }
+                },
+            },
+        }
+    )
+    assert v.get_default_value('name') == ('root',)
+    assert v.get_default_value(['count']) == ('1',)
+    assert v.get_default_value('count', validate=True) == (1,)
+    assert v.get_default_value('tags', validate=True) == ([1],)
+    assert len(calls) == 1
+    assert v.get_default_value('required') is None
+    assert v.get_default_value('sub') == (None,)
+    assert v.get_default_value(('sub', 'sub', 'name')) == ('root',)
+
+    with pytest.raises(KeyError, match='Field not found: sub -> missing'):
+        v.get_default_value(('sub', 'missing'))
+    with pytest.raises(KeyError, match='Field not found: name -> sub'):
+        v.get_default_value(('name', 'sub'))
+
+
+def test_get_default_value_model():
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': Cls,
+            'schema': {
+                'type': 'typed-dict',
+                'fields': {'a': {'schema': {'type': 'with-default', 'schema': 'int', 'default': 'x'}}},
+            },
+        }
+    )
+    assert v.get_default_value('a') == ('x',)
+    with pytest.raises(ValidationError, match=r'a\n +Input should be a valid integer'):
+        v.get_default_value('a', validate=True)
+    with pytest.raises(KeyError, match='Field not found: b'):
+        v.get_default_value('b')
+
+    v = SchemaValidator('int')
+    with pytest.raises(KeyError, match='Field not found: a'):
+        v.get_default_value('a')
+
+
+def test_replace_field():
+    schema = {
+        'type': 'new-class',
+        'class_type': Cls,
+        'schema': {
+            'type': 'typed-dict',
+            'fields': {
+                'color': {'schema': {'type': 'literal', 'expected': ['red', 'green']}},
+                'sub': {
+                    'schema': {
+                        'type': 'typed-dict',
+                        'fields': {'size': {'schema': {'type': 'literal', 'expected': ['S', 'M']}}},
+                    }
+                },
+            },
+        },
+    }
+    v = SchemaValidator(schema)
+    v_copy = copy.copy(v)
+    with pytest.raises(ValidationError, match='color\n +Input should be one of'):
+        v.validate_python({'color': 'blue', 'sub': {'size': 'S'}})
+
+    v.replace_field('color', {'type': 'literal', 'expected': ['red', 'green', 'blue']})
+    v.replace_field(('sub', 'size'), {'type': 'literal', 'expected': ['S', 'M', 'L']})
+    m = v.validate_python({'color': 'blue', 'sub': {'size': 'L'}})
+    assert m.color == 'blue'
+    assert m.sub == {'size': 'L'}
+
+    # the schema is updated, but the original schema and copies of the validator aren't
+    fields = v.dump_schema()['schema']['fields']
+    assert fields['sub']['schema']['fields']['size']['schema']['expected'] == ['S', 'M', 'L']
+    assert schema['schema']['fields']['color']['schema']['expected'] == ['red', 'green']
+    assert v != v_copy
+    assert not v_copy.isinstance_python({'color': 'blue', 'sub': {'size': 'S'}})
+    v_pickle = pickle.loads(pickle.dumps(v))
+    assert v_pickle == v
+    assert v_pickle.validate_python({'color': 'blue', 'sub': {'size': 'L'}}).color == 'blue'
+
+
+def test_replace_field_recursive():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Branch',
+            'fields': {
+                'width': {'schema': 'int'},
+                'sub_branch': {
+                    'schema': {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}},
+                    'default': None,
+                },
+            },
+        }
+    )
+    with pytest.raises(ValidationError, match='width\n +Input should be a valid integer'):
+        v.validate_python({'width': 1.5})
+    v.replace_field(['sub_branch', 'width'], 'float')
+    assert v.validate_python({'width': 1.5, 'sub_branch': {'width': 2.5}}) == {
+        'width': 1.5,
+        'sub_branch': {'width': 2.5, 'sub_branch': None},
+    }
+    assert v.dump_schema()['fields']['width']['schema'] == {'type': 'float'}
+
+
+def test_replace_field_errors():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}}})
+    with pytest.raises(KeyError, match='Field not found: b'):
+        v.replace_field('b', 'str')
+    with pytest.raises(KeyError, match='Field not found: a -> b'):
+        v.replace_field(['a', 'b'], 'str')
+    with pytest.raises(SchemaError, match="Input tag 'wrong' found using self-schema does not match any of the"):
+        v.replace_field('a', {'type': 'wrong'})
+    with pytest.raises(SchemaError, match="^Replacement field schemas can't define refs$"):
+        v.replace_field('a', {'type': 'int', 'ref': 'Int'})
+    with pytest.raises(SchemaError, match="Slots Error: ref 'Other' not found"):
+        v.replace_field('a', {'type': 'recursive-ref', 'schema_ref': 'Other'})
+    assert v.validate_python({'a': '1'}) == {'a': 1}
+
+
+def test_allow_partial():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': 'str', 'default': 'x'},
+                'c': {
+                    'schema': {
+                        'type': 'typed-dict',
+                        'fields': {'d': {'schema': 'int'}, 'e': {'schema': 'int'}},
+                    }
+                },
+            },
+        }
+    )
+    assert v.validate_python({'c': {'d': '1'}}, allow_partial=True) == {'c': {'d': 1}}
+    assert v.validate_json('{"a": 1}', allow_partial=True) == {'a': 1}
+    assert v.validate_python({}, allow_partial=True) == {}
+
+    # invalid values are still errors
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'}, allow_partial=True)
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ['a'])]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'c': {'d': '1'}})
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [
+        ('missing', ['a']),
+        ('missing', ['c', 'e']),
+    ]
+
+
+def test_allow_partial_function():
+    def f(input_value, validator, **kwargs):
+        return validator(input_value)
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {
+                    'schema': {
+                        'type': 'function',
+                        'mode': 'wrap',
+                        'function': f,
+                        'schema': {'type': 'typed-dict', 'fields': {'b': {'schema': 'int'}}},
+                    }
+                }
+            },
+        }
+    )
+    assert v.validate_python({'a': {}}, allow_partial=True) == {'a': {}}
+    with pytest.raises(ValidationError, match=r'a -> b\n +Field required'):
+        v.validate_python({'a': {}})
@@ -244,3 +244,31 @@ def test_strict_union():
         {'kind': 'bool_type', 'loc': ['bool'], 'message': 'Input should be a valid boolean', 'input_value': '123'},
         {'kind': 'int_type', 'loc': ['int'], 'message': 'Input should be a valid integer', 'input_value': '123'},
     ]
+
+
+@pytest.mark.parametrize(
+    'mode,input_value,expected',
+    [
+        ('smart', '123', '123'),
+        ('smart', 123, 123),
+        ('smart', 1.0, 1),
+        ('left_to_right', '123', 123),
+        ('left_to_right', 123, 123),
+        ('left_to_right', 'abc', 'abc'),
+    ],
+)
+def test_union_mode(mode, input_value, expected):
+    v = SchemaValidator({'type': 'union', 'mode': mode, 'choices': [{'type': 'int'}, {'type': 'str'}]})
+    output = v.validate_python(input_value)
+    assert output == expected
+    assert type(output) == type(expected)
+
+
+def test_union_mode_default_smart():
+    v = SchemaValidator({'type': 'union', 'choices': [{'type': 'int'}, {'type': 'str'}]})
+    assert v.validate_python('123') == '123'
+
+
+def test_union_mode_invalid():
+    with pytest.raises(SchemaError, match="Input should be one of: 'smart', 'left_to_right'"):
+        SchemaValidator({'type': 'union', 'mode': 'random', 'choices': [{'type': 'int'}]})
@@ -0,0 +1,71 @@
+import base64
+import re
+from uuid import UUID
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+from ..conftest import Err, PyAndJson
+
+EXAMPLE = UUID('12345678-1234-5678-1234-567812345678')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (EXAMPLE, EXAMPLE),
+        ('12345678-1234-5678-1234-567812345678', EXAMPLE),
+        ('{12345678-1234-5678-1234-567812345678}', EXAMPLE),
+        ('12345678123456781234567812345678', EXAMPLE),
+        (EXAMPLE.bytes, EXAMPLE),
+        (b'\x00' * 16, UUID(int=0)),
+        ('foobar', Err('Input should be a valid UUID, badly formed hexadecimal UUID string [kind=uuid_parsing,')),
+        (b'1234', Err('Input should be a valid UUID, expected 16 bytes, got 4 [kind=uuid_parsing,')),
+        (1234, Err('Input should be a valid UUID, a string or 16 bytes [kind=uuid_type,')),
+        (None, Err('Input should be a valid UUID, a string or 16 bytes [kind=uuid_type,')),
+    ],
+)
+def test_uuid(input_value, expected):
+    v = SchemaValidator({'type': 'uuid'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert isinstance(output, UUID)
+
+
+def test_uuid_unchanged():
+    assert SchemaValidator({'type': 'uuid'}).validate_python(EXAMPLE) is EXAMPLE
+
+
+def test_uuid_json(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'uuid'})
+    assert v.validate_test('12345678-1234-5678-1234-567812345678') == EXAMPLE
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid UUID, badly formed hexadecimal')):
+        v.validate_test('foobar')
+
+
+def test_uuid_json_base64():
+    v = SchemaValidator({'type': 'uuid'})
+    encoded = base64.b64encode(EXAMPLE.bytes).decode()
+    assert v.validate_json(f'"{encoded}"') == EXAMPLE
+    # base64 strings are only decoded from JSON
+    with pytest.raises(ValidationError, match='uuid_parsing'):
+        v.validate_python(encoded)
+    # base64 data which isn't 16 bytes is a parsing error
+    with pytest.raises(ValidationError, match='uuid_parsing'):
+        v.validate_json(f'"{base64.b64encode(b"1234").decode()}"')
+
+
+def test_uuid_strict():
+    v = SchemaValidator({'type': 'uuid', 'strict': True})
+    assert v.validate_python(EXAMPLE) == EXAMPLE
+    with pytest.raises(ValidationError, match=r'Input should be a valid UUID, a string or 16 bytes \[kind=uuid_type'):
+        v.validate_python('12345678-1234-5678-1234-567812345678')
+    with pytest.raises(ValidationError, match=r'\[kind=uuid_type'):
+        v.validate_python(EXAMPLE.bytes)
+    # JSON has no UUID type, so strings are accepted in strict mode
+    assert v.validate_json('"12345678-1234-5678-1234-567812345678"') == EXAMPLE
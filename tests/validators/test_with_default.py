@@ -0,0 +1,145 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+
+def test_typed_dict_default(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'x': {'schema': 'str'},
+                'y': {'schema': {'type': 'with-default', 'schema': 'str', 'default': '[default]'}},
+            },
+        }
+    )
+    assert v.validate_test({'x': 'x', 'y': 'y'}) == {'x': 'x', 'y': 'y'}
+    assert v.validate_test({'x': 'x'}) == {'x': 'x', 'y': '[default]'}
+
+
+def test_default_factory():
+    calls = []
+
+    def factory():
+        calls.append(1)
+        return []
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'x': {'schema': {'type': 'with-default', 'schema': 'list', 'default_factory': factory}}},
+        }
+    )
+    first = v.validate_python({})
+    second = v.validate_python({})
+    assert first == second == {'x': []}
+    assert first['x'] is not second['x']
+    assert len(calls) == 2
+    assert v.validate_python({'x': [1]}) == {'x': [1]}
+    assert len(calls) == 2
+
+
+def test_validate_default():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'x': {'schema': {'type': 'with-default', 'schema': 'int', 'default': '42', 'validate_default': True}}
+            },
+        }
+    )
+    assert v.validate_python({}) == {'x': 42}
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'x': {'schema': {'type': 'with-default', 'schema': 'int', 'default': 'x', 'validate_default': True}}
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['x'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+
+
+def test_default_not_validated():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'x': {'schema': {'type': 'with-default', 'schema': 'int', 'default': 'not-an-int'}}},
+        }
+    )
+    assert v.validate_python({}) == {'x': 'not-an-int'}
+
+
+def test_on_error_default(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'fields': {'x': {'schema': {'type': 'with-default', 'schema': 'int', 'default': 1, 'on_error': 'default'}}},
+        }
+    )
+    assert v.validate_test({'x': 2}) == {'x': 2}
+    assert v.validate_test({'x': 'wrong'}) == {'x': 1}
+    assert v.validate_test({}) == {'x': 1}
+
+
+def test_on_error_raise():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'x': {'schema': {'type': 'with-default', 'schema': 'int', 'default': 1, 'on_error': 'raise'}}},
+        }
+    )
+    with pytest.raises(ValidationError, match=r'x\n +Input should be a valid integer'):
+        v.validate_python({'x': 'wrong'})
+
+
+def test_list_items():
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': {'type': 'with-default', 'schema': 'int', 'default': 0, 'on_error': 'default'},
+        }
+    )
+    assert v.validate_python([1, 'x', '3']) == [1, 0, 3]
+
+
+def test_no_default():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'x': {'schema': {'type': 'with-default', 'schema': 'int'}}}})
+    assert v.validate_python({'x': 1}) == {'x': 1}
+    with pytest.raises(ValidationError, match=r'x\n +Field required'):
+        v.validate_python({})
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'with-default', 'schema': 'int', 'default': 1})
+    assert repr(v).startswith('SchemaValidator(name="with-default[int]"')
+
+
+@pytest.mark.parametrize(
+    'schema,error',
+    [
+        (
+            {'type': 'with-default', 'schema': 'int', 'default': 1, 'default_factory': list},
+            "'default' and 'default_factory' cannot be used together",
+        ),
+        (
+            {'type': 'with-default', 'schema': 'int', 'on_error': 'default'},
+            "'on_error = default' requires a `default` or `default_factory`",
+        ),
+    ],
+)
+def test_errors(schema, error):
+    with pytest.raises(SchemaError, match=error):
+        SchemaValidator(schema)
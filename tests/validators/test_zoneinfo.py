@@ -0,0 +1,59 @@
+import re
+import sys
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import Err, PyAndJson
+
+if sys.version_info >= (3, 9):
+    from zoneinfo import ZoneInfo
+
+pytestmark = pytest.mark.skipif(sys.version_info < (3, 9), reason='zoneinfo requires python 3.9+')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('Europe/London', 'Europe/London'),
+        ('UTC', 'UTC'),
+        ('America/New_York', 'America/New_York'),
+        ('Mars/Olympus_Mons', Err('Input should be a valid IANA timezone name, unable to find timezone')),
+        ('../etc/passwd', Err('unable to find timezone [kind=timezone_not_found,')),
+        ('', Err('unable to find timezone [kind=timezone_not_found,')),
+        (1, Err('Input should be a valid timezone, a ZoneInfo instance or timezone name [kind=timezone_type,')),
+    ],
+)
+def test_zoneinfo(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'zoneinfo'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert isinstance(output, ZoneInfo)
+        assert output.key == expected
+
+
+def test_zoneinfo_instance():
+    v = SchemaValidator({'type': 'zoneinfo', 'strict': True})
+    tz = ZoneInfo('Europe/Paris')
+    assert v.validate_python(tz) is tz
+    with pytest.raises(ValidationError, match=r'\[kind=timezone_type'):
+        v.validate_python('Europe/Paris')
+    # JSON has no timezone type, so names are accepted in strict mode
+    assert v.validate_json('"Europe/Paris"') is tz
+
+
+def test_zoneinfo_cached():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'zoneinfo'})
+    first, second = v.validate_python(['Asia/Tokyo', 'Asia/Tokyo'])
+    assert first is second
+    assert v.validate_python(['Asia/Tokyo'])[0] is first
+
+
+def test_zoneinfo_not_available(monkeypatch):
+    monkeypatch.setitem(sys.modules, 'zoneinfo', None)
+    with pytest.raises(SchemaError, match='zoneinfo validators require python 3.9 or later'):
+        SchemaValidator({'type': 'zoneinfo'})